@@ -1,10 +1,36 @@
 use ariadne::ReportBuilder;
-use ariadne::{ColorGenerator, Label, Report, ReportKind, Source};
-use damasc_lang::runtime::evaluation::EvalErrorReason;
+use ariadne::{Color, ColorGenerator, Label, Report, ReportKind, Source};
+use chumsky::error::Rich;
+use damasc_lang::analysis::exhaustiveness::{MatchWarning, MatchWarningReason};
+use damasc_lang::analysis::lint::{LintWarning, LintWarningReason};
 use damasc_lang::runtime::matching::PatternFailReason;
+use damasc_lang::syntax::location::Location;
 use damasc_repl::io::ReplError;
 use std::ops::Range;
 
+pub(crate) fn print_parse_errors(name: &str, input: &str, errors: Vec<Rich<'_, char>>) {
+    let mut colors = ColorGenerator::new();
+
+    for e in errors {
+        Report::build(ReportKind::Error, name, e.span().start)
+            .with_code("Parse Error")
+            .with_message(e.to_string())
+            .with_label(
+                Label::new((name, e.span().into_range()))
+                    .with_message(e.reason().to_string())
+                    .with_color(Color::Red),
+            )
+            .with_labels(e.contexts().map(|(label, span)| {
+                Label::new((name, span.into_range()))
+                    .with_message(format!("while parsing this {}", label))
+                    .with_color(colors.next())
+            }))
+            .finish()
+            .print((name, Source::from(input)))
+            .unwrap()
+    }
+}
+
 pub(crate) fn print_error(input: &str, e: ReplError) {
     let mut colors = ColorGenerator::new();
     let a = colors.next();
@@ -21,52 +47,7 @@ pub(crate) fn print_error(input: &str, e: ReplError) {
 
             let builder = builder.with_code("Evaluation");
 
-            let builder = builder.with_message(match eval_error.reason {
-                EvalErrorReason::KindError(actual) => {
-                    format!("Expected a type, but found {}.", actual)
-                }
-                EvalErrorReason::TypeError(expected_type, value) => format!(
-                    "Expected a value of type {} but found {} of type {}.",
-                    expected_type,
-                    value,
-                    value.get_type()
-                ),
-                EvalErrorReason::CollectionTypeError(val) => format!(
-                    "Value must be an Array, Object or String, but was {} of type {}.",
-                    val,
-                    val.get_type()
-                ),
-                EvalErrorReason::CastError(expected_type, val) => format!(
-                    "Value {} of type {} can not be converted into {}..",
-                    val,
-                    val.get_type(),
-                    expected_type
-                ),
-                EvalErrorReason::UnknownIdentifier(identifier) => {
-                    format!("Unknown identifier {}.", identifier)
-                }
-                EvalErrorReason::InvalidNumber(lit) => {
-                    format!("Literal {} is not a valid number.", lit)
-                }
-                EvalErrorReason::MathDivisionByZero => "Division By Zero".to_string(),
-                EvalErrorReason::KeyNotDefined(key, val) => {
-                    format!("Object {} has no key {}.", val, key)
-                }
-                EvalErrorReason::OutOfBound(expected_lengnth, actual_length) => format!(
-                    "Tried to access index {} of value that has a length of {}.",
-                    actual_length, expected_lengnth,
-                ),
-                EvalErrorReason::IntegerOverflow => "Integer overflow".to_string(),
-                EvalErrorReason::UnknownFunction(fun) => {
-                    format!("Function of name {} does not exist.", fun)
-                }
-                EvalErrorReason::PatternError(_e) => {
-                    "A pattern failed to match during evaluation.".to_string()
-                }
-                EvalErrorReason::PatternExhaustionError(val) => {
-                    format!("None of the provided cases was a match for value {}.", val)
-                }
-            });
+            let builder = builder.with_message(eval_error.reason.to_string());
 
             let builder = builder.with_label(
                 Label::new(("REPL", source_location.start..(source_location.end)))
@@ -99,6 +80,11 @@ pub(crate) fn print_error(input: &str, e: ReplError) {
                 PatternFailReason::LiteralMismatch => "The value does not match the expected literal.".to_string(),
                 PatternFailReason::ExpressionMissmatch { expected, actual } => format!("The value is expected to be {} but actually was {}.", expected, actual),
                 PatternFailReason::EvalError(_eval_error) => "During the pattern matching an evaulation error occured.".to_string(),
+                PatternFailReason::AlternativesMismatch(failures) => format!("The value matches none of the {} alternatives.", failures.len()),
+                PatternFailReason::OutOfRange { actual } => format!("The value {} is not within the expected range.", actual),
+                PatternFailReason::RegexMismatch { actual } => format!("The value {} does not match the regular expression.", actual),
+                PatternFailReason::PrefixMismatch { expected, actual } => format!("The value {} does not start with \"{}\".", actual, expected),
+                PatternFailReason::SuffixMismatch { expected, actual } => format!("The value {} does not end with \"{}\".", actual, expected),
             });
 
             let builder = builder.with_label(
@@ -132,6 +118,97 @@ pub(crate) fn print_error(input: &str, e: ReplError) {
                 .print(("REPL", Source::from(input)))
                 .unwrap();
         }
+        ReplError::TypeErrors(type_errors) => {
+            for type_error in type_errors {
+                let Some(source_location) = type_error.location else {
+                    eprintln!("Type Error: {}", type_error.reason);
+                    continue;
+                };
+
+                let builder = Report::build(ReportKind::Error, "REPL", source_location.start);
+
+                let builder = builder.with_code("Typing");
+
+                let builder = builder.with_message(type_error.reason.to_string());
+
+                let builder = builder.with_label(
+                    Label::new(("REPL", source_location.start..(source_location.end)))
+                        .with_message("This is ill-typed.")
+                        .with_color(a),
+                );
+
+                builder
+                    .finish()
+                    .print(("REPL", Source::from(input)))
+                    .unwrap();
+            }
+        }
         ReplError::TransformError => eprintln!("Error During Transformation"),
     }
 }
+
+pub(crate) fn print_warnings(input: &str, warnings: &[MatchWarning]) {
+    for warning in warnings {
+        print_warning(
+            input,
+            "Matching",
+            &warning.reason,
+            warning.location,
+            match warning.reason {
+                MatchWarningReason::NonExhaustive(_) => "This match is not exhaustive.",
+                MatchWarningReason::UnreachableCase(_) => "This case is never reached.",
+            },
+        );
+    }
+}
+
+pub(crate) fn print_lint_warnings(input: &str, warnings: &[LintWarning]) {
+    for warning in warnings {
+        print_warning(
+            input,
+            "Lint",
+            &warning.reason,
+            warning.location,
+            match warning.reason {
+                LintWarningReason::UnusedBinding(_) => "This binding is never used.",
+                LintWarningReason::Shadowing(_) => "This binding shadows another one.",
+                LintWarningReason::SamePatternReference(_) => {
+                    "This expression is evaluated before the pattern binds."
+                }
+            },
+        );
+    }
+}
+
+fn print_warning(
+    input: &str,
+    code: &str,
+    reason: &dyn std::fmt::Display,
+    location: Option<Location>,
+    label: &str,
+) {
+    let mut colors = ColorGenerator::new();
+    let a = colors.next();
+
+    let Some(source_location) = location else {
+        eprintln!("Warning: {}", reason);
+        return;
+    };
+
+    let builder = Report::build(ReportKind::Warning, "REPL", source_location.start);
+
+    let builder = builder.with_code(code);
+
+    let builder = builder.with_message(reason.to_string());
+
+    let builder = builder.with_label(
+        Label::new(("REPL", source_location.start..(source_location.end)))
+            .with_message(label)
+            .with_color(a),
+    );
+
+    builder
+        .finish()
+        .print(("REPL", Source::from(input)))
+        .unwrap();
+}
@@ -0,0 +1,74 @@
+use std::io::Read;
+
+use chumsky::prelude::just;
+use chumsky::Parser;
+use damasc_grammar::expression::expression_set_non_empty;
+use damasc_lang::syntax::pretty::PrettyPrinter;
+
+use crate::error::print_parse_errors;
+
+const USAGE: &str = "Usage: damasc fmt [--width N] [--indent N] [FILE]";
+
+/// Formats the expressions of the given file, or of stdin, and prints them.
+/// Returns the exit code.
+pub(crate) fn run(mut args: impl Iterator<Item = String>) -> i32 {
+    let mut printer = PrettyPrinter::default();
+    let mut file = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--width" | "--indent" => {
+                let Some(value) = args.next().and_then(|v| v.parse().ok()) else {
+                    eprintln!("{arg} expects a number.\n{USAGE}");
+                    return 2;
+                };
+                printer = if arg == "--width" {
+                    printer.with_width(value)
+                } else {
+                    printer.with_indent(value)
+                };
+            }
+            "--help" => {
+                println!("{USAGE}");
+                return 0;
+            }
+            _ if file.is_none() && !arg.starts_with("--") => file = Some(arg),
+            _ => {
+                eprintln!("Unexpected argument {arg}.\n{USAGE}");
+                return 2;
+            }
+        }
+    }
+
+    let (name, input) = match file {
+        Some(path) => match std::fs::read_to_string(&path) {
+            Ok(input) => (path, input),
+            Err(e) => {
+                eprintln!("Could not read {path}: {e}");
+                return 1;
+            }
+        },
+        None => {
+            let mut input = String::new();
+            if let Err(e) = std::io::stdin().read_to_string(&mut input) {
+                eprintln!("Could not read stdin: {e}");
+                return 1;
+            }
+            ("stdin".to_string(), input)
+        }
+    };
+
+    let parser = expression_set_non_empty()
+        .then_ignore(just(";").or_not())
+        .padded();
+    match parser.parse(&input).into_result() {
+        Ok(expressions) => {
+            print!("{}", printer.print_expression_set(&expressions));
+            0
+        }
+        Err(errs) => {
+            print_parse_errors(&name, &input, errs);
+            1
+        }
+    }
+}
@@ -1,11 +1,5 @@
 #![feature(iter_intersperse)]
-use crate::error::print_error;
-use ariadne::Color;
-use ariadne::ColorGenerator;
-use ariadne::Label;
-use ariadne::Report;
-use ariadne::ReportKind;
-use ariadne::Source;
+use crate::error::{print_error, print_lint_warnings, print_parse_errors, print_warnings};
 use chumsky::Parser;
 use damasc_grammar::repl::single_command;
 
@@ -13,10 +7,16 @@ use damasc_repl::{io::ReplOutput, state::State};
 use rustyline::{error::ReadlineError, Editor};
 
 mod error;
+mod fmt;
 
 const HISTORY_FILE: &str = "history.txt";
 
 fn main() -> rustyline::Result<()> {
+    let mut args = std::env::args().skip(1);
+    if args.next().as_deref() == Some("fmt") {
+        std::process::exit(fmt::run(args));
+    }
+
     let mut repl = State::default();
     let mut rl = Editor::<()>::new()?;
 
@@ -34,25 +34,7 @@ fn main() -> rustyline::Result<()> {
                 let cmd = match repl_parser.parse(&line).into_result() {
                     Ok(cmd) => cmd,
                     Err(errs) => {
-                        let mut colors = ColorGenerator::new();
-                        errs.into_iter().for_each(|e| {
-                            Report::build(ReportKind::Error, "Inline", e.span().start)
-                                .with_code("Parse Error")
-                                .with_message(e.to_string())
-                                .with_label(
-                                    Label::new(("Inline", e.span().into_range()))
-                                        .with_message(e.reason().to_string())
-                                        .with_color(Color::Red),
-                                )
-                                .with_labels(e.contexts().map(|(label, span)| {
-                                    Label::new(("Inline", span.into_range()))
-                                        .with_message(format!("while parsing this {}", label))
-                                        .with_color(colors.next())
-                                }))
-                                .finish()
-                                .print(("Inline", Source::from(&line)))
-                                .unwrap()
-                        });
+                        print_parse_errors("Inline", &line, errs);
                         continue;
                     }
                 };
@@ -64,6 +46,16 @@ fn main() -> rustyline::Result<()> {
                     Ok(ReplOutput::Bindings(e)) => {
                         println!("{e}")
                     }
+                    Ok(output @ ReplOutput::Types(..)) => {
+                        print_warnings(line.as_str(), output.match_warnings().0);
+                        print!("{output}")
+                    }
+                    Ok(ReplOutput::Lints(warnings)) if warnings.is_empty() => {
+                        print!("{}", ReplOutput::Lints(warnings))
+                    }
+                    Ok(ReplOutput::Lints(warnings)) => {
+                        print_lint_warnings(line.as_str(), &warnings);
+                    }
                     Ok(ReplOutput::Write(msg)) => eprintln!("{msg}"),
                     Err(e) => {
                         print_error(line.as_str(), e);
@@ -14,6 +14,7 @@ use damasc_lang::syntax::expression::LogicalOperator;
 use damasc_lang::syntax::expression::MemberExpression;
 use damasc_lang::syntax::expression::ObjectComprehension;
 use damasc_lang::syntax::expression::ObjectProperty;
+use damasc_lang::syntax::expression::PipeExpression;
 use damasc_lang::syntax::expression::PropertyKey;
 use damasc_lang::syntax::expression::StringTemplate;
 use damasc_lang::syntax::expression::StringTemplatePart;
@@ -32,7 +33,13 @@ use damasc_lang::syntax::expression::ExpressionBody;
 use damasc_lang::syntax::expression::IfElseExpression;
 use damasc_lang::syntax::expression::LambdaAbstraction;
 use damasc_lang::syntax::expression::MatchCase;
+use damasc_lang::syntax::assignment::Assignment;
+use damasc_lang::syntax::assignment::AssignmentSet;
+use damasc_lang::syntax::expression::LetExpression;
 use damasc_lang::syntax::expression::MatchExpression;
+use damasc_lang::syntax::expression::RangeExpression;
+use damasc_lang::syntax::expression::SliceExpression;
+use damasc_lang::syntax::expression::TryCatchExpression;
 use damasc_lang::syntax::expression::Property;
 
 use crate::literal::single_literal;
@@ -94,7 +101,7 @@ pub(crate) fn decl_single_expression<'s>() -> ExpressionParserDelc<'s, 's,'s> {
         let matching_case = pattern_declaration
             .clone()
             .recover_with(skip_then_retry_until(
-                any().ignored(),
+                none_of("}").ignored(),
                 choice((just("if").padded(), just("=>").padded())).ignored(),
             ))
             .labelled("case_pattern")
@@ -200,42 +207,82 @@ pub(crate) fn decl_single_expression<'s>() -> ExpressionParserDelc<'s, 's,'s> {
             })
             .boxed();
 
-        let abstraction_param = pattern_declaration
-            .clone()
-            .delimited_by(
-                just("("),
-                just(")")
-                    .ignored()
-                    .recover_with(via_parser(end()))
-                    .recover_with(skip_then_retry_until(any().ignored(), end())),
-            )
-            .or(pattern_declaration.clone());
-
-        let abstraction = just("fn")
+        let try_catch = just("try")
             .padded()
             .ignore_then(
-                abstraction_param
+                boxed_expression
                     .clone()
-                    .recover_with(skip_then_retry_until(
-                        any().ignored(),
-                        choice((just("=>").padded(),)).ignored(),
-                    )),
+                    .delimited_by(
+                        just('{').padded(),
+                        just('}')
+                            .padded()
+                            .ignored()
+                            .recover_with(via_parser(end()))
+                            .recover_with(skip_then_retry_until(any().ignored(), end())),
+                    )
+                    .labelled("try_body")
+                    .as_context(),
             )
+            .then_ignore(just("catch").padded())
             .then(
-                just("=>")
+                pattern_declaration
+                    .clone()
                     .padded()
-                    .ignore_then(boxed_expression.clone().labelled("lamba_body").as_context()),
+                    .delimited_by(just('('), just(')').padded())
+                    .labelled("catch_pattern"),
             )
-            .map_with(|(arguments, body), meta| {
+            .then(
+                boxed_expression
+                    .clone()
+                    .delimited_by(
+                        just('{').padded(),
+                        just('}')
+                            .ignored()
+                            .recover_with(via_parser(end()))
+                            .recover_with(skip_then_retry_until(any().ignored(), end())),
+                    )
+                    .labelled("catch_body")
+                    .as_context(),
+            )
+            .map_with(|((body, pattern), fallback), meta| {
                 Expression::new_with_location(
-                    ExpressionBody::Abstraction(LambdaAbstraction { arguments, body }),
+                    ExpressionBody::TryCatch(TryCatchExpression {
+                        body,
+                        pattern,
+                        fallback,
+                    }),
                     meta_to_location(meta),
                 )
             })
-            .labelled("lambda")
-            .as_context()
             .boxed();
 
+        let abstraction_param = pattern_declaration
+            .clone()
+            .separated_by(just(',').padded())
+            .allow_trailing()
+            .collect()
+            .padded()
+            .delimited_by(
+                just("("),
+                just(")")
+                    .ignored()
+                    .recover_with(via_parser(end()))
+                    .recover_with(skip_then_retry_until(any().ignored(), end())),
+            )
+            .boxed();
+
+        let abstraction_head = choice((
+            single_identifier()
+                .padded()
+                .map(Some)
+                .then(abstraction_param.clone()),
+            abstraction_param.clone().map(|params| (None, params)),
+            pattern_declaration
+                .clone()
+                .map(|p| (None, vec![p])),
+        ))
+        .boxed();
+
         let matching_abstraction = just("fn")
             .padded()
             .then(just("match").padded())
@@ -262,7 +309,8 @@ pub(crate) fn decl_single_expression<'s>() -> ExpressionParserDelc<'s, 's,'s> {
             .map_with(|cases, meta| {
                 let local_identifier = Identifier::new("___local");
                 ExpressionBody::Abstraction(LambdaAbstraction {
-                    arguments: Pattern::new(PatternBody::Identifier(local_identifier.clone())),
+                    name: None,
+                    arguments: vec![Pattern::new(PatternBody::Identifier(local_identifier.clone()))],
                     body: Box::new(Expression::new_with_location(
                         ExpressionBody::Match(MatchExpression {
                             subject: Box::new(Expression::new(ExpressionBody::Identifier(
@@ -440,9 +488,9 @@ pub(crate) fn decl_single_expression<'s>() -> ExpressionParserDelc<'s, 's,'s> {
             .as_context()
             .boxed();
 
-        let string_template_part_static = (none_of("$")
+        let string_template_part_static = (none_of("$`")
             .to_slice()
-            .or(just("$").then(none_of("{")).to_slice()))
+            .or(just("$").then(none_of("{`")).to_slice()))
         .repeated()
         .to_slice();
         let string_template_part_dynamic = boxed_expression.clone().delimited_by(
@@ -472,6 +520,21 @@ pub(crate) fn decl_single_expression<'s>() -> ExpressionParserDelc<'s, 's,'s> {
             .map_with(|body, meta| Expression::new_with_location(body, meta_to_location(meta)))
             .boxed();
 
+        let argument_list = expression
+            .clone()
+            .separated_by(just(',').padded())
+            .allow_trailing()
+            .collect()
+            .padded()
+            .delimited_by(
+                just('('),
+                just(')')
+                    .ignored()
+                    .recover_with(via_parser(end()))
+                    .recover_with(skip_then_retry_until(any().ignored(), end())),
+            )
+            .boxed();
+
         let parenthesis = expression.clone().delimited_by(
             just('('),
             just(')')
@@ -481,280 +544,435 @@ pub(crate) fn decl_single_expression<'s>() -> ExpressionParserDelc<'s, 's,'s> {
         );
 
         enum PathSegment<'a> {
-            Application(Expression<'a>),
-            Index(Expression<'a>),
-            Prop(Expression<'a>),
+            Application(Vec<Expression<'a>>),
+            Index(Expression<'a>, bool),
+            Slice(Option<Expression<'a>>, Option<Expression<'a>>),
+            Prop(Expression<'a>, bool),
         }
 
-        let path_indexed = expression
-            .clone()
-            .delimited_by(
-                just('['),
-                just(']')
-                    .ignored()
-                    .recover_with(via_parser(end()))
-                    .recover_with(skip_then_retry_until(any().ignored(), end())),
+        let path_indexed = just("?.")
+            .or_not()
+            .map(|optional| optional.is_some())
+            .then(
+                expression.clone().delimited_by(
+                    just('['),
+                    just(']')
+                        .ignored()
+                        .recover_with(via_parser(end()))
+                        .recover_with(skip_then_retry_until(any().ignored(), end())),
+                ),
             )
-            .map(PathSegment::Index)
+            .map(|(optional, index)| PathSegment::Index(index, optional))
+            .boxed();
+
+        let path_sliced = expression
+            .clone()
+            .or_not()
+            .then_ignore(just(':').padded())
+            .then(expression.clone().or_not())
+            .delimited_by(just('['), just(']'))
+            .map(|(start, end)| PathSegment::Slice(start, end))
             .boxed();
 
-        let path_property = just(".")
-            .ignore_then(single_identifier())
-            .map_with(|ident, meta| {
+        let path_property = choice((just("?.").to(true), just(".").to(false)))
+            .then(single_identifier().map_with(|ident, meta| {
                 Expression::new_with_location(
                     ExpressionBody::Literal(Literal::String(ident.name)),
                     meta_to_location(meta),
                 )
-            })
-            .map(PathSegment::Prop)
+            }))
+            .map(|(optional, property)| PathSegment::Prop(property, optional))
             .boxed();
         let path_apply = just(".")
-            .ignore_then(parenthesis.clone())
+            .ignore_then(argument_list.clone())
             .map(PathSegment::Application)
             .boxed();
 
         let call = single_identifier()
-            .then(parenthesis.clone())
-            .map_with(|(function, argument), meta| {
+            .then(argument_list.clone())
+            .map_with(|(function, arguments), meta| {
                 Expression::new_with_location(
                     ExpressionBody::Call(CallExpression {
                         function,
-                        argument: Box::new(argument),
+                        arguments,
                     }),
                     meta_to_location(meta),
                 )
             })
             .boxed();
 
-        let path_base = choice((
-            matching_abstraction,
-            abstraction,
-            matching,
-            condition,
-            literal,
-            array,
-            object,
-            string_template,
-            call,
-            ident,
-            parenthesis,
-        ))
-        .boxed();
+        let mut let_value = Recursive::declare();
 
-        let path = path_base.clone().foldl_with(
-            choice((path_property, path_apply, path_indexed)).repeated(),
-            |expr, segment, meta| {
-                Expression::new_with_location(
-                    match segment {
-                        PathSegment::Application(param) => {
-                            ExpressionBody::Application(LambdaApplication {
-                                lambda: Box::new(expr),
-                                parameter: Box::new(param),
-                            })
-                        }
-                        PathSegment::Index(index) => ExpressionBody::Member(MemberExpression {
-                            object: Box::new(expr),
-                            property: Box::new(index),
-                        }),
-                        PathSegment::Prop(property) => ExpressionBody::Member(MemberExpression {
-                            object: Box::new(expr),
-                            property: Box::new(property),
-                        }),
-                    },
-                    meta_to_location(meta),
-                )
-            },
-        );
-
-        // TODO: Prefer Pratt-Parser
-
-        let unary_operator = choice((
-            just("!").padded().map(|_| UnaryOperator::Not),
-            just("+").padded().map(|_| UnaryOperator::Plus),
-            just("-").padded().map(|_| UnaryOperator::Minus),
-        ));
+        let let_binding = pattern_declaration
+            .clone()
+            .padded()
+            .then_ignore(just('=').padded())
+            .then(let_value.clone())
+            .map(|(pattern, expression)| Assignment {
+                pattern,
+                expression,
+            });
 
-        let unary_op = unary_operator
-            .then(boxed_expression.clone().labelled("operand").as_context())
-            .map_with(|(operator, argument), meta| {
-                Expression::new_with_location(
-                    ExpressionBody::Unary(UnaryExpression { operator, argument }),
-                    meta_to_location(meta),
+        // Binding values of a `let` end at its `in` keyword, so they are parsed
+        // by a chain without the `in` operator, in which lambda bodies, `let`
+        // bodies and unary operands end at the `in` as well.
+        let operator_chain = |allow_in: bool, trailing: Boxed<'s, 's, &'s str, Box<Expression<'s>>, extra::Err<Rich<'s, char>>>| {
+            let abstraction = just("fn")
+                .padded()
+                .ignore_then(
+                    abstraction_head
+                        .clone()
+                        .recover_with(skip_then_retry_until(
+                            any().ignored(),
+                            choice((just("=>").padded(),)).ignored(),
+                        )),
                 )
-            })
-            .boxed();
-
-        let binary_atom = choice((unary_op, path)).boxed();
-
-        let num_exponential = binary_atom
-            .clone()
-            .foldl_with(
-                choice((just("^").padded().map(|_| BinaryOperator::PowerOf),))
-                    .then(binary_atom.clone().labelled("operand").as_context())
-                    .repeated(),
-                |lhs, (operator, rhs), meta| {
+                .then(
+                    just("=>")
+                        .padded()
+                        .ignore_then(trailing.clone().labelled("lamba_body").as_context()),
+                )
+                .map_with(|((name, arguments), body), meta| {
                     Expression::new_with_location(
-                        ExpressionBody::Binary(BinaryExpression {
-                            operator,
-                            left: Box::new(lhs),
-                            right: Box::new(rhs),
+                        ExpressionBody::Abstraction(LambdaAbstraction {
+                            name,
+                            arguments,
+                            body,
                         }),
                         meta_to_location(meta),
                     )
-                },
-            )
-            .boxed();
+                })
+                .labelled("lambda")
+                .as_context()
+                .boxed();
 
-        let num_mul = num_exponential
-            .clone()
-            .foldl_with(
-                choice((
-                    just("*").padded().map(|_| BinaryOperator::Times),
-                    just("/").padded().map(|_| BinaryOperator::Over),
-                ))
-                .then(num_exponential.clone().labelled("operand").as_context())
-                .repeated(),
-                |lhs, (operator, rhs), meta| {
+            let let_in = just("let")
+                .padded()
+                .ignore_then(
+                    let_binding
+                        .clone()
+                        .separated_by(just(';').padded())
+                        .allow_trailing()
+                        .at_least(1)
+                        .collect()
+                        .labelled("let_bindings")
+                        .as_context(),
+                )
+                .then_ignore(just("in").padded())
+                .then(trailing.clone())
+                .map_with(|(assignments, body), meta| {
                     Expression::new_with_location(
-                        ExpressionBody::Binary(BinaryExpression {
-                            operator,
-                            left: Box::new(lhs),
-                            right: Box::new(rhs),
+                        ExpressionBody::Let(LetExpression {
+                            assignments: AssignmentSet { assignments },
+                            body,
                         }),
                         meta_to_location(meta),
                     )
-                },
-            )
+                })
+                .boxed();
+
+            let path_base = choice((
+                matching_abstraction.clone(),
+                abstraction,
+                matching.clone(),
+                condition.clone(),
+                try_catch.clone(),
+                let_in,
+                literal.clone(),
+                array.clone(),
+                object.clone(),
+                string_template.clone(),
+                call.clone(),
+                ident.clone(),
+                parenthesis.clone(),
+            ))
             .boxed();
 
-        let num_add = num_mul
-            .clone()
-            .foldl_with(
-                choice((
-                    just("+").padded().map(|_| BinaryOperator::Plus),
-                    just("-").padded().map(|_| BinaryOperator::Minus),
-                ))
-                .then(num_mul.clone().labelled("operand").as_context())
-                .repeated(),
-                |lhs, (operator, rhs), meta| {
+            let path = path_base.clone().foldl_with(
+                choice((path_property.clone(), path_apply.clone(), path_sliced.clone(), path_indexed.clone())).repeated(),
+                |expr, segment, meta| {
                     Expression::new_with_location(
-                        ExpressionBody::Binary(BinaryExpression {
-                            operator,
-                            left: Box::new(lhs),
-                            right: Box::new(rhs),
-                        }),
+                        match segment {
+                            PathSegment::Application(parameters) => {
+                                ExpressionBody::Application(LambdaApplication {
+                                    lambda: Box::new(expr),
+                                    parameters,
+                                })
+                            }
+                            PathSegment::Index(index, optional) => ExpressionBody::Member(MemberExpression {
+                                object: Box::new(expr),
+                                property: Box::new(index),
+                                optional,
+                            }),
+                            PathSegment::Slice(start, end) => ExpressionBody::Slice(SliceExpression {
+                                object: Box::new(expr),
+                                start: start.map(Box::new),
+                                end: end.map(Box::new),
+                            }),
+                            PathSegment::Prop(property, optional) => ExpressionBody::Member(MemberExpression {
+                                object: Box::new(expr),
+                                property: Box::new(property),
+                                optional,
+                            }),
+                        },
                         meta_to_location(meta),
                     )
                 },
-            )
-            .boxed();
+            );
 
-        let num_pred = num_add
-            .clone()
-            .foldl_with(
-                choice((
-                    just(">=")
-                        .padded()
-                        .map(|_| BinaryOperator::GreaterThanEqual),
-                    just("<=").padded().map(|_| BinaryOperator::LessThanEqual),
-                    just("==").padded().map(|_| BinaryOperator::StrictEqual),
-                    just("!=").padded().map(|_| BinaryOperator::StrictNotEqual),
-                    just(">").padded().map(|_| BinaryOperator::GreaterThan),
-                    just("<").padded().map(|_| BinaryOperator::LessThan),
-                    just("in").padded().map(|_| BinaryOperator::In),
-                ))
-                .then(num_add.clone().labelled("operand").as_context())
-                .repeated(),
-                |lhs, (operator, rhs), meta| {
-                    Expression::new_with_location(
-                        ExpressionBody::Binary(BinaryExpression {
-                            operator,
-                            left: Box::new(lhs),
-                            right: Box::new(rhs),
-                        }),
-                        meta_to_location(meta),
-                    )
-                },
-            )
-            .boxed();
+            // TODO: Prefer Pratt-Parser
 
-        let type_add = num_pred
-            .clone()
-            .foldl_with(
-                choice((just("as").padded().map(|_| BinaryOperator::Cast),))
-                    .then(num_pred.clone().labelled("operand").as_context())
-                    .repeated(),
-                |lhs, (operator, rhs), meta| {
+            let unary_operator = choice((
+                just("!").padded().map(|_| UnaryOperator::Not),
+                just("+").padded().map(|_| UnaryOperator::Plus),
+                just("-").padded().map(|_| UnaryOperator::Minus),
+            ));
+
+            let unary_op = unary_operator
+                .then(trailing.clone().labelled("operand").as_context())
+                .map_with(|(operator, argument), meta| {
                     Expression::new_with_location(
-                        ExpressionBody::Binary(BinaryExpression {
-                            operator,
-                            left: Box::new(lhs),
-                            right: Box::new(rhs),
-                        }),
+                        ExpressionBody::Unary(UnaryExpression { operator, argument }),
                         meta_to_location(meta),
                     )
-                },
-            )
-            .boxed();
+                })
+                .boxed();
 
-        let type_pred = type_add
-            .clone()
-            .foldl_with(
-                choice((just("is").padded().map(|_| BinaryOperator::Is),))
-                    .then(type_add.clone().labelled("operand").as_context())
+            let binary_atom = choice((unary_op, path)).boxed();
+
+            let num_exponential = binary_atom
+                .clone()
+                .foldl_with(
+                    choice((just("^").padded().map(|_| BinaryOperator::PowerOf),))
+                        .then(binary_atom.clone().labelled("operand").as_context())
+                        .repeated(),
+                    |lhs, (operator, rhs), meta| {
+                        Expression::new_with_location(
+                            ExpressionBody::Binary(BinaryExpression {
+                                operator,
+                                left: Box::new(lhs),
+                                right: Box::new(rhs),
+                            }),
+                            meta_to_location(meta),
+                        )
+                    },
+                )
+                .boxed();
+
+            let num_mul = num_exponential
+                .clone()
+                .foldl_with(
+                    choice((
+                        just("*").padded().map(|_| BinaryOperator::Times),
+                        just("/").padded().map(|_| BinaryOperator::Over),
+                    ))
+                    .then(num_exponential.clone().labelled("operand").as_context())
                     .repeated(),
-                |lhs, (operator, rhs), meta| {
-                    Expression::new_with_location(
-                        ExpressionBody::Binary(BinaryExpression {
-                            operator,
-                            left: Box::new(lhs),
-                            right: Box::new(rhs),
-                        }),
-                        meta_to_location(meta),
-                    )
-                },
-            )
-            .boxed();
+                    |lhs, (operator, rhs), meta| {
+                        Expression::new_with_location(
+                            ExpressionBody::Binary(BinaryExpression {
+                                operator,
+                                left: Box::new(lhs),
+                                right: Box::new(rhs),
+                            }),
+                            meta_to_location(meta),
+                        )
+                    },
+                )
+                .boxed();
 
-        let logic_mul = type_pred
-            .clone()
-            .foldl_with(
-                choice((just("&&").padded().map(|_| LogicalOperator::And),))
-                    .then(type_pred.clone().labelled("operand").as_context())
+            let num_add = num_mul
+                .clone()
+                .foldl_with(
+                    choice((
+                        just("+").padded().map(|_| BinaryOperator::Plus),
+                        just("-").padded().map(|_| BinaryOperator::Minus),
+                    ))
+                    .then(num_mul.clone().labelled("operand").as_context())
                     .repeated(),
-                |lhs, (operator, rhs), meta| {
-                    Expression::new_with_location(
-                        ExpressionBody::Logical(LogicalExpression {
-                            operator,
-                            left: Box::new(lhs),
-                            right: Box::new(rhs),
+                    |lhs, (operator, rhs), meta| {
+                        Expression::new_with_location(
+                            ExpressionBody::Binary(BinaryExpression {
+                                operator,
+                                left: Box::new(lhs),
+                                right: Box::new(rhs),
+                            }),
+                            meta_to_location(meta),
+                        )
+                    },
+                )
+                .boxed();
+
+            let range = num_add
+                .clone()
+                .then(
+                    just("..")
+                        .padded()
+                        .ignore_then(num_add.clone().labelled("range_end").as_context())
+                        .or_not(),
+                )
+                .map_with(|(start, end), meta| match end {
+                    None => start,
+                    Some(end) => Expression::new_with_location(
+                        ExpressionBody::Range(RangeExpression {
+                            start: Box::new(start),
+                            end: Box::new(end),
                         }),
                         meta_to_location(meta),
-                    )
-                },
-            )
-            .boxed();
+                    ),
+                })
+                .boxed();
 
-        let logic_add = logic_mul
-            .clone()
-            .foldl_with(
-                choice((just("||").padded().map(|_| LogicalOperator::Or),))
-                    .then(logic_mul.clone().labelled("operand").as_context())
+            let num_pred = range
+                .clone()
+                .foldl_with(
+                    choice((
+                        just(">=")
+                            .padded()
+                            .map(|_| BinaryOperator::GreaterThanEqual),
+                        just("<=").padded().map(|_| BinaryOperator::LessThanEqual),
+                        just("==").padded().map(|_| BinaryOperator::StrictEqual),
+                        just("!=").padded().map(|_| BinaryOperator::StrictNotEqual),
+                        just(">").padded().map(|_| BinaryOperator::GreaterThan),
+                        just("<").padded().map(|_| BinaryOperator::LessThan),
+                        just("in").padded().map(|_| BinaryOperator::In),
+                    ))
+                    .filter(move |operator| allow_in || *operator != BinaryOperator::In)
+                    .then(range.clone().labelled("operand").as_context())
                     .repeated(),
-                |lhs, (operator, rhs), meta| {
-                    Expression::new_with_location(
-                        ExpressionBody::Logical(LogicalExpression {
-                            operator,
-                            left: Box::new(lhs),
-                            right: Box::new(rhs),
-                        }),
-                        meta_to_location(meta),
-                    )
-                },
-            )
-            .boxed();
+                    |lhs, (operator, rhs), meta| {
+                        Expression::new_with_location(
+                            ExpressionBody::Binary(BinaryExpression {
+                                operator,
+                                left: Box::new(lhs),
+                                right: Box::new(rhs),
+                            }),
+                            meta_to_location(meta),
+                        )
+                    },
+                )
+                .boxed();
+
+            let type_add = num_pred
+                .clone()
+                .foldl_with(
+                    choice((just("as").padded().map(|_| BinaryOperator::Cast),))
+                        .then(num_pred.clone().labelled("operand").as_context())
+                        .repeated(),
+                    |lhs, (operator, rhs), meta| {
+                        Expression::new_with_location(
+                            ExpressionBody::Binary(BinaryExpression {
+                                operator,
+                                left: Box::new(lhs),
+                                right: Box::new(rhs),
+                            }),
+                            meta_to_location(meta),
+                        )
+                    },
+                )
+                .boxed();
+
+            let type_pred = type_add
+                .clone()
+                .foldl_with(
+                    choice((just("is").padded().map(|_| BinaryOperator::Is),))
+                        .then(type_add.clone().labelled("operand").as_context())
+                        .repeated(),
+                    |lhs, (operator, rhs), meta| {
+                        Expression::new_with_location(
+                            ExpressionBody::Binary(BinaryExpression {
+                                operator,
+                                left: Box::new(lhs),
+                                right: Box::new(rhs),
+                            }),
+                            meta_to_location(meta),
+                        )
+                    },
+                )
+                .boxed();
+
+            let logic_mul = type_pred
+                .clone()
+                .foldl_with(
+                    choice((just("&&").padded().map(|_| LogicalOperator::And),))
+                        .then(type_pred.clone().labelled("operand").as_context())
+                        .repeated(),
+                    |lhs, (operator, rhs), meta| {
+                        Expression::new_with_location(
+                            ExpressionBody::Logical(LogicalExpression {
+                                operator,
+                                left: Box::new(lhs),
+                                right: Box::new(rhs),
+                            }),
+                            meta_to_location(meta),
+                        )
+                    },
+                )
+                .boxed();
+
+            let logic_add = logic_mul
+                .clone()
+                .foldl_with(
+                    choice((just("||").padded().map(|_| LogicalOperator::Or),))
+                        .then(logic_mul.clone().labelled("operand").as_context())
+                        .repeated(),
+                    |lhs, (operator, rhs), meta| {
+                        Expression::new_with_location(
+                            ExpressionBody::Logical(LogicalExpression {
+                                operator,
+                                left: Box::new(lhs),
+                                right: Box::new(rhs),
+                            }),
+                            meta_to_location(meta),
+                        )
+                    },
+                )
+                .boxed();
+
+            let null_coalesce = logic_add
+                .clone()
+                .foldl_with(
+                    choice((just("??").padded().map(|_| BinaryOperator::NullCoalesce),))
+                        .then(logic_add.clone().labelled("operand").as_context())
+                        .repeated(),
+                    |lhs, (operator, rhs), meta| {
+                        Expression::new_with_location(
+                            ExpressionBody::Binary(BinaryExpression {
+                                operator,
+                                left: Box::new(lhs),
+                                right: Box::new(rhs),
+                            }),
+                            meta_to_location(meta),
+                        )
+                    },
+                )
+                .boxed();
+
+            let pipe = null_coalesce
+                .clone()
+                .foldl_with(
+                    just("|>")
+                        .padded()
+                        .ignore_then(null_coalesce.clone().labelled("function").as_context())
+                        .repeated(),
+                    |value, function, meta| {
+                        Expression::new_with_location(
+                            ExpressionBody::Pipe(PipeExpression {
+                                value: Box::new(value),
+                                function: Box::new(function),
+                            }),
+                            meta_to_location(meta),
+                        )
+                    },
+                )
+                .boxed();
+
+            pipe
+        };
+
+        let_value.define(operator_chain(false, let_value.clone().map(Box::new).boxed()));
 
-        logic_add.padded()
+        operator_chain(true, boxed_expression.clone().boxed()).padded()
     }).boxed();
 
     (expression_declaration, pattern_declaration)
@@ -15,7 +15,7 @@ pub fn single_identifier<'s, 'x>(
         .try_map(move |c: &'s str, span| {
             if matches!(
                 c,
-                "where" | "into" | "limit" | "with" | "fn" | "match" | "if" | "else" | "for" | "in"
+                "where" | "into" | "limit" | "with" | "fn" | "match" | "if" | "else" | "try" | "catch" | "let" | "for" | "in"
             ) {
                 Err(Error::<&'s str>::expected_found(None, None, span))
             } else {
@@ -23,7 +23,6 @@ pub fn single_identifier<'s, 'x>(
             }
         })
         .or(just("#").ignore_then(ident()))
-        .to_slice()
         .map(|ident:&str| Identifier::new_owned(ident.to_string()))
         .or(just("#").ignore_then(single_string_literal()).map(|ident| Identifier::new_owned(ident.to_string())))
         
@@ -51,6 +51,7 @@ pub fn single_type_literal<'s>() -> impl Parser<'s, &'s str, ValueType, extra::E
         just("Null").to(ValueType::Null).labelled("Null"),
         just("Boolean").to(ValueType::Boolean).labelled("Boolean"),
         just("Integer").to(ValueType::Integer).labelled("Integer"),
+        just("Float").to(ValueType::Float).labelled("Float"),
         just("Array").to(ValueType::Array).labelled("Array"),
         just("Object").to(ValueType::Object).labelled("Object"),
         just("String").to(ValueType::String).labelled("String"),
@@ -67,6 +68,24 @@ pub fn single_literal<'s, 'x>() -> impl Parser<'s, &'s str, Literal<'x>, extra::
         .map(|s: &str| Cow::Owned(s.to_string()))
         .map(Literal::Number);
 
+    let exponent = one_of("eE")
+        .then(one_of("+-").or_not())
+        .then(text::digits(10));
+
+    let float = just('-')
+        .or_not()
+        .then(text::int(10))
+        .then(choice((
+            just('.')
+                .then(text::digits(10))
+                .then(exponent.or_not())
+                .ignored(),
+            exponent.ignored(),
+        )))
+        .to_slice()
+        .map(|s: &str| Cow::Owned(s.to_string()))
+        .map(Literal::Float);
+
     let boolean = choice((
         just("true").to(true).labelled("true"),
         just("false").to(false).labelled("false"),
@@ -81,6 +100,7 @@ pub fn single_literal<'s, 'x>() -> impl Parser<'s, &'s str, Literal<'x>, extra::
     choice((
         null,
         boolean,
+        float,
         integer,
         value_type,
         single_string_literal().map(Literal::String),
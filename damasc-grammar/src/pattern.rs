@@ -14,7 +14,12 @@ use damasc_lang::syntax::pattern::ObjectPropertyPattern;
 use damasc_lang::syntax::pattern::Pattern;
 use damasc_lang::syntax::pattern::PatternBody;
 use damasc_lang::syntax::pattern::PropertyPattern;
+use damasc_lang::syntax::pattern::RangePattern;
+use damasc_lang::syntax::pattern::RegexPattern;
+use damasc_lang::syntax::pattern::RelationalOperator;
 use damasc_lang::syntax::pattern::Rest;
+use damasc_lang::syntax::pattern::StringPattern;
+use std::borrow::Cow;
 
 use crate::literal::single_literal;
 
@@ -89,7 +94,8 @@ pub(crate) fn decl_single_pattern<'s>() -> ExpressionParserDelc<'s,'s> {
             .boxed();
 
         let capture = single_identifier()
-            .then(just("@").ignore_then(pattern.clone()).or_not())
+            .filter(|id| id.name != "_")
+            .then(just("@").padded().ignore_then(pattern.clone()).or_not())
             .boxed()
             .map_with(move |(id, pat), meta| {
                 if let Some(p) = pat {
@@ -139,7 +145,7 @@ pub(crate) fn decl_single_pattern<'s>() -> ExpressionParserDelc<'s,'s> {
             })
             .boxed();
 
-        let array = pattern
+        let array_items = pattern
             .clone()
             .map(ArrayPatternItem::Pattern)
             .separated_by(just(',').padded().recover_with(skip_then_retry_until(
@@ -147,7 +153,11 @@ pub(crate) fn decl_single_pattern<'s>() -> ExpressionParserDelc<'s,'s> {
                 choice((just("]"), just(","))).ignored(),
             )))
             .allow_trailing()
-            .collect()
+            .collect::<Vec<_>>()
+            .boxed();
+
+        let array = array_items
+            .clone()
             .then(
                 just("...")
                     .padded()
@@ -159,6 +169,13 @@ pub(crate) fn decl_single_pattern<'s>() -> ExpressionParserDelc<'s,'s> {
                             .or_not()
                             .map(move |s| s.unwrap_or(Rest::Discard)),
                     )
+                    .then(
+                        just(',')
+                            .padded()
+                            .ignore_then(array_items.clone())
+                            .or_not()
+                            .map(Option::unwrap_or_default),
+                    )
                     .or_not(),
             )
             .padded()
@@ -170,8 +187,9 @@ pub(crate) fn decl_single_pattern<'s>() -> ExpressionParserDelc<'s,'s> {
                     .recover_with(skip_then_retry_until(any().ignored(), end())),
             )
             .map_with(move |(elements, rest), meta| {
+                let (rest, suffix) = rest.unwrap_or((Rest::Exact, vec![]));
                 Pattern::new_with_location(
-                    PatternBody::Array(elements, rest.unwrap_or(Rest::Exact)),
+                    PatternBody::Array(elements, rest, Box::new(suffix)),
                     meta_to_location(meta),
                 )
             })
@@ -194,8 +212,25 @@ pub(crate) fn decl_single_pattern<'s>() -> ExpressionParserDelc<'s,'s> {
         .as_context()
         .then_ignore(just(':').padded())
         .then(pattern.clone().labelled("value").as_context())
-        .map(move |(key, value)| PropertyPattern { key, value })
+        .then(
+            just('=')
+                .padded()
+                .ignore_then(expression_declaration.clone())
+                .or_not(),
+        )
+        .map(move |((key, value), default)| PropertyPattern {
+            key,
+            value,
+            default,
+        })
         .map(ObjectPropertyPattern::Match)
+        .or(single_identifier()
+            .then_ignore(just('=').padded())
+            .then(expression_declaration.clone())
+            .map(|(id, default)| ObjectPropertyPattern::Default(id, default)))
+        .or(single_identifier()
+            .then_ignore(just('?').padded())
+            .map(ObjectPropertyPattern::Optional))
         .or(single_identifier().map(ObjectPropertyPattern::Single))
         .boxed();
 
@@ -238,15 +273,130 @@ pub(crate) fn decl_single_pattern<'s>() -> ExpressionParserDelc<'s,'s> {
             .as_context()
             .boxed();
 
-        choice((
+        let range = single_literal()
+            .then(choice((just("..=").to(true), just("..").to(false))).padded())
+            .then(single_literal())
+            .map_with(move |((start, inclusive), end), meta| {
+                Pattern::new_with_location(
+                    PatternBody::Range(Box::new(RangePattern {
+                        start,
+                        end,
+                        inclusive,
+                    })),
+                    meta_to_location(meta),
+                )
+            })
+            .labelled("range")
+            .as_context()
+            .boxed();
+
+        let relational = choice((
+            just(">=").to(RelationalOperator::GreaterThanEqual),
+            just("<=").to(RelationalOperator::LessThanEqual),
+            just(">").to(RelationalOperator::GreaterThan),
+            just("<").to(RelationalOperator::LessThan),
+        ))
+        .padded()
+        .then(single_literal())
+        .map_with(move |(operator, bound), meta| {
+            Pattern::new_with_location(
+                PatternBody::Relational(operator, bound),
+                meta_to_location(meta),
+            )
+        })
+        .boxed();
+
+        let regex = none_of("\\/")
+            .ignored()
+            .or(just('\\').then(any()).ignored())
+            .repeated()
+            .to_slice()
+            .delimited_by(just('/'), just('/'))
+            .try_map(|source: &str, span| {
+                RegexPattern::new(Cow::Owned(source.to_string()))
+                    .map_err(|e| Rich::custom(span, e))
+            })
+            .map_with(move |regex, meta| {
+                Pattern::new_with_location(PatternBody::Regex(regex), meta_to_location(meta))
+            })
+            .labelled("regex")
+            .as_context()
+            .boxed();
+
+        let parenthesized = pattern
+            .clone()
+            .delimited_by(just('(').padded(), just(')').padded())
+            .boxed();
+
+        let single = choice((
+            range,
+            relational,
+            regex,
             literal,
+            typed_identifier,
             capture,
             discard,
-            typed_identifier,
             pinned,
             array,
             object,
+            parenthesized,
         ))
+        .boxed();
+
+        let concat = recursive(|concat| {
+            let prefix = single_string_literal()
+                .padded()
+                .then_ignore(just("++").padded())
+                .then(concat)
+                .map_with(move |(prefix, rest), meta| {
+                    Pattern::new_with_location(
+                        PatternBody::String(StringPattern::Prefix(prefix, Box::new(rest))),
+                        meta_to_location(meta),
+                    )
+                })
+                .boxed();
+
+            let suffix = single.clone().foldl_with(
+                just("++")
+                    .padded()
+                    .ignore_then(single_string_literal().padded())
+                    .repeated(),
+                move |rest, suffix, meta| {
+                    Pattern::new_with_location(
+                        PatternBody::String(StringPattern::Suffix(Box::new(rest), suffix)),
+                        meta_to_location(meta),
+                    )
+                },
+            )
+            .boxed();
+
+            prefix.or(suffix)
+        })
+        .boxed();
+
+        concat
+            .separated_by(just('|').then_ignore(one_of("|>").not()).padded())
+            .at_least(1)
+            .collect::<Vec<_>>()
+            .try_map(|alternatives, span| {
+                if Pattern::alternatives_bind_same_identifiers(&alternatives) {
+                    Ok(alternatives)
+                } else {
+                    Err(Rich::custom(span, "all alternatives must bind the same identifiers"))
+                }
+            })
+            .map_with(move |mut alternatives, meta| {
+                if alternatives.len() == 1 {
+                    alternatives.remove(0)
+                } else {
+                    Pattern::new_with_location(
+                        PatternBody::Alternative(alternatives),
+                        meta_to_location(meta),
+                    )
+                }
+            })
+            .labelled("alternatives")
+            .as_context()
     })
     .padded()
     .boxed();
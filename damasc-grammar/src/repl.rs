@@ -2,8 +2,10 @@
 use crate::query::single_transformation;
 use crate::assignment::assignment_set_non_empty;
 use damasc_lang::syntax::assignment::AssignmentSet;
+use damasc_lang::syntax::expression::ExpressionSet;
 use crate::expression::expression_set_non_empty;
 use chumsky::prelude::choice;
+use chumsky::prelude::end;
 use chumsky::prelude::just;
 use damasc_repl::command::Command;
 use chumsky::extra;
@@ -22,7 +24,24 @@ pub fn single_command<'s,'a,'b>() -> impl Parser<'s, &'s str, Command<'a,'b>, ex
     	just(".clearenv").map(|_| Command::ClearEnv),
     	just(".ce").map(|_| Command::ClearEnv),
     	just(".pipe").padded().ignore_then(single_transformation()).map(Command::Transform),
+    	just(".check").padded().ignore_then(choice((
+    		assignment_set_non_empty().map(|assgns| Command::Check(assgns, ExpressionSet { expressions: vec![] })),
+    		expression_set_non_empty().then(just("with").ignore_then(assignment_set_non_empty().padded()).or_not()).map(|(exprs, assgns)| {
+    			Command::Check(assgns.unwrap_or_default(), exprs)
+    		}),
+    	))),
+    	just(".lint").padded().ignore_then(choice((
+    		assignment_set_non_empty().map(|assgns| Command::Lint(assgns, ExpressionSet { expressions: vec![] })),
+    		expression_set_non_empty().then(just("with").ignore_then(assignment_set_non_empty().padded()).or_not()).map(|(exprs, assgns)| {
+    			Command::Lint(assgns.unwrap_or_default(), exprs)
+    		}),
+    	))),
 
+    	// `let x = a in b` is a let expression rather than an assignment of
+    	// `a in b`, which has to be written as `let x = (a in b)`.
+    	just("let").padded().rewind().ignore_then(expression_set_non_empty()).then(just("with").ignore_then(assignment_set_non_empty().padded()).or_not()).then_ignore(end()).map(|(exprs, assgns)| {
+    		Command::Eval(assgns.unwrap_or_default(), exprs)
+    	}),
     	just("let").padded().ignore_then(assignment_set_non_empty().then(just("with").padded().ignore_then(assignment_set_non_empty()).or_not()).map(|(assignments, locals)| {
     		Command::Assign(assignments, locals)
     	})),
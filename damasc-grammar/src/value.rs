@@ -10,6 +10,7 @@ use std::borrow::Cow;
 
 use chumsky::Parser;
 
+use damasc_lang::value::Float;
 use damasc_lang::value::Value;
 
 use chumsky::prelude::*;
@@ -75,6 +76,11 @@ pub fn single_value<'s,'a:'s+'b,'b:'s>() -> impl Parser<'s, &'s str, Value<'a, '
                         .parse()
                         .map(Value::Integer)
                         .map_err(move |_| Error::<&str>::expected_found(None, None, span)),
+                    Literal::Float(num) => num
+                        .parse()
+                        .map(Float)
+                        .map(Value::Float)
+                        .map_err(move |_| Error::<&str>::expected_found(None, None, span)),
                     Literal::Boolean(b) => Ok(Value::Boolean(b)),
                     Literal::Type(t) => Ok(Value::Type(t)),
                 })
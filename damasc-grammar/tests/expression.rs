@@ -2,6 +2,12 @@
 use chumsky::Parser;
 use core::assert_matches::assert_matches;
 use damasc_grammar::expression::single_expression;
+use damasc_lang::identifier::Identifier;
+use damasc_lang::syntax::expression::{
+    BinaryExpression, BinaryOperator, Expression, ExpressionBody, MatchExpression,
+};
+use damasc_lang::syntax::pattern::PatternBody;
+use damasc_lang::value_type::ValueType;
 
 #[test]
 fn expression_parsing() {
@@ -14,3 +20,97 @@ fn expression_parsing() {
         assert_matches!(parsed, Ok(_), "Failed to parse line {}: {}", ln + 1, line);
     }
 }
+
+fn parse(source: &str) -> Expression<'static> {
+    let Ok(expression) = single_expression().parse(source).into_result() else {
+        unreachable!("Failed to parse: {source}");
+    };
+    expression
+}
+
+fn case_pattern(source: &str) -> PatternBody<'static> {
+    let ExpressionBody::Match(MatchExpression { mut cases, .. }) = parse(source).body else {
+        unreachable!("Not a match: {source}");
+    };
+    cases.remove(0).pattern.body
+}
+
+#[test]
+fn test_raw_identifiers() {
+    assert_eq!(
+        parse("#match").body,
+        ExpressionBody::Identifier(Identifier::new("match"))
+    );
+    assert_eq!(
+        parse("#\"a b\"").body,
+        ExpressionBody::Identifier(Identifier::new("a b"))
+    );
+}
+
+#[test]
+fn test_discard_and_typed_patterns() {
+    assert_eq!(case_pattern("match (x) { _ => 1 }"), PatternBody::Discard);
+    assert_eq!(
+        case_pattern("match (x) { a is String => 1 }"),
+        PatternBody::TypedIdentifier(Identifier::new("a"), ValueType::String)
+    );
+    assert_matches!(
+        case_pattern("match (x) { a @ [_] => 1 }"),
+        PatternBody::Capture(_, _)
+    );
+    assert_matches!(
+        single_expression()
+            .parse("match (x) { _ @ [1] => 1 }")
+            .into_result(),
+        Err(_)
+    );
+}
+
+#[test]
+fn test_nested_match_trailing_comma() {
+    let ExpressionBody::Match(MatchExpression { cases, .. }) =
+        parse("match (x) { _ => match (y) { _ => 1, }, _ => 2 }").body
+    else {
+        unreachable!("Not a match");
+    };
+    assert_eq!(cases.len(), 2);
+}
+
+#[test]
+fn test_template_parts_end_at_backtick() {
+    assert_matches!(
+        parse("`a` + `${b}`").body,
+        ExpressionBody::Binary(BinaryExpression {
+            operator: BinaryOperator::Plus,
+            ..
+        })
+    );
+}
+
+#[test]
+fn test_let_values_end_at_in() {
+    let cases = [
+        (
+            "let f = fn x => x + 1 in f.(2)",
+            "let f = (fn x => x + 1) in f.(2)",
+        ),
+        (
+            "let f = fn (x) => x + 1 in 2 |> f",
+            "let f = (fn (x) => x + 1) in 2 |> f",
+        ),
+        (
+            "let x = let y = 1 in y in x",
+            "let x = (let y = 1 in y) in x",
+        ),
+        (
+            "let f = fn x => fn y => x + y in f.(1).(2)",
+            "let f = (fn x => fn y => x + y) in f.(1).(2)",
+        ),
+        ("let x = -a in x", "let x = (-a) in x"),
+        ("fn x => x in y", "fn x => (x in y)"),
+    ];
+
+    for (unparenthesized, parenthesized) in cases {
+        assert_eq!(parse(unparenthesized), parse(parenthesized), "{unparenthesized}");
+    }
+}
@@ -0,0 +1,419 @@
+use std::borrow::Cow;
+
+use chumsky::Parser;
+use damasc_grammar::expression::single_expression;
+use damasc_lang::identifier::Identifier;
+use damasc_lang::literal::Literal;
+use damasc_lang::parser::expression::expression_all_consuming;
+use damasc_lang::syntax::assignment::{Assignment, AssignmentSet};
+use damasc_lang::syntax::expression::{
+    ArrayComprehension, ArrayItem, BinaryExpression, BinaryOperator, CallExpression,
+    ComprehensionSource, Expression, ExpressionBody, IfElseExpression, LambdaAbstraction,
+    LambdaApplication, LetExpression, LogicalExpression, LogicalOperator, MatchCase,
+    MatchExpression, MemberExpression, ObjectProperty, PipeExpression, Property, PropertyKey,
+    RangeExpression, StringTemplate, StringTemplatePart, TryCatchExpression, UnaryExpression,
+    UnaryOperator,
+};
+use damasc_lang::syntax::pattern::{
+    ArrayPatternItem, ObjectPropertyPattern, Pattern, PatternBody, PropertyPattern, Rest,
+};
+use damasc_lang::syntax::pretty::PrettyPrinter;
+use damasc_lang::syntax::visit::{fold_expression, Fold};
+use damasc_lang::value_type::ValueType;
+use proptest::prelude::*;
+
+fn identifier() -> impl Strategy<Value = Identifier<'static>> {
+    prop_oneof![
+        "[a-z][a-z0-9_]{0,6}".prop_filter("keyword", |name| {
+            ![
+                "if", "in", "fn", "for", "let", "try", "match", "null", "true", "false",
+            ]
+            .iter()
+            .any(|k| name.starts_with(k))
+        }),
+        Just("match".to_string()),
+        Just("nullable".to_string()),
+    ]
+    .prop_map(Identifier::new_owned)
+}
+
+fn literal() -> impl Strategy<Value = Literal<'static>> {
+    prop_oneof![
+        Just(Literal::Null),
+        any::<bool>().prop_map(Literal::Boolean),
+        (0..10000u32).prop_map(|n| Literal::Number(Cow::Owned(n.to_string()))),
+        "[a-z ]{0,8}".prop_map(|s| Literal::String(Cow::Owned(s))),
+        Just(Literal::Type(ValueType::Integer)),
+    ]
+}
+
+fn expression_of(body: ExpressionBody<'static>) -> Expression<'static> {
+    Expression::new(body)
+}
+
+fn pattern() -> impl Strategy<Value = Pattern<'static>> {
+    let leaf = prop_oneof![
+        Just(PatternBody::Discard),
+        identifier().prop_map(PatternBody::Identifier),
+        literal().prop_map(PatternBody::Literal),
+        identifier().prop_map(|id| PatternBody::TypedIdentifier(id, ValueType::String)),
+    ]
+    .prop_map(Pattern::new);
+
+    leaf.prop_recursive(3, 12, 3, |inner| {
+        prop_oneof![
+            (prop::collection::vec(inner.clone(), 0..3), any::<bool>()).prop_map(
+                |(items, discard)| {
+                    PatternBody::Array(
+                        items.into_iter().map(ArrayPatternItem::Pattern).collect(),
+                        if discard { Rest::Discard } else { Rest::Exact },
+                        Box::default(),
+                    )
+                }
+            ),
+            (
+                prop::collection::vec(
+                    prop_oneof![
+                        identifier().prop_map(ObjectPropertyPattern::Single),
+                        identifier().prop_map(ObjectPropertyPattern::Optional),
+                        (identifier(), inner.clone()).prop_map(|(key, value)| {
+                            ObjectPropertyPattern::Match(PropertyPattern {
+                                key: PropertyKey::Identifier(key),
+                                value,
+                                default: None,
+                            })
+                        }),
+                    ],
+                    0..3
+                ),
+                any::<bool>()
+            )
+                .prop_map(|(properties, discard)| {
+                    PatternBody::Object(
+                        properties,
+                        if discard { Rest::Discard } else { Rest::Exact },
+                    )
+                }),
+            (
+                identifier(),
+                prop::collection::vec(inner, 1..3).prop_map(|items| {
+                    PatternBody::Array(
+                        items.into_iter().map(ArrayPatternItem::Pattern).collect(),
+                        Rest::Exact,
+                        Box::default(),
+                    )
+                })
+            )
+                .prop_map(|(id, inner)| PatternBody::Capture(id, Box::new(Pattern::new(inner)))),
+        ]
+        .prop_map(Pattern::new)
+    })
+}
+
+fn binary_operator() -> impl Strategy<Value = BinaryOperator> {
+    prop_oneof![
+        Just(BinaryOperator::StrictEqual),
+        Just(BinaryOperator::StrictNotEqual),
+        Just(BinaryOperator::LessThan),
+        Just(BinaryOperator::GreaterThanEqual),
+        Just(BinaryOperator::Plus),
+        Just(BinaryOperator::Minus),
+        Just(BinaryOperator::Times),
+        Just(BinaryOperator::Over),
+        Just(BinaryOperator::PowerOf),
+        Just(BinaryOperator::In),
+        Just(BinaryOperator::Is),
+        Just(BinaryOperator::Cast),
+        Just(BinaryOperator::NullCoalesce),
+    ]
+}
+
+fn expression() -> impl Strategy<Value = Expression<'static>> {
+    let leaf = prop_oneof![
+        literal().prop_map(ExpressionBody::Literal),
+        identifier().prop_map(ExpressionBody::Identifier),
+    ]
+    .prop_map(expression_of);
+
+    leaf.prop_recursive(4, 32, 4, |inner| {
+        let boxed = inner.clone().prop_map(Box::new);
+        prop_oneof![
+            (binary_operator(), boxed.clone(), boxed.clone()).prop_map(
+                |(operator, left, right)| {
+                    ExpressionBody::Binary(BinaryExpression {
+                        operator,
+                        left,
+                        right,
+                    })
+                }
+            ),
+            (any::<bool>(), boxed.clone(), boxed.clone()).prop_map(|(or, left, right)| {
+                ExpressionBody::Logical(LogicalExpression {
+                    operator: if or {
+                        LogicalOperator::Or
+                    } else {
+                        LogicalOperator::And
+                    },
+                    left,
+                    right,
+                })
+            }),
+            (
+                prop_oneof![
+                    Just(UnaryOperator::Not),
+                    Just(UnaryOperator::Minus),
+                    Just(UnaryOperator::Plus)
+                ],
+                boxed.clone()
+            )
+                .prop_map(|(operator, argument)| {
+                    ExpressionBody::Unary(UnaryExpression { operator, argument })
+                }),
+            prop::collection::vec(
+                prop_oneof![
+                    inner.clone().prop_map(ArrayItem::Single),
+                    inner.clone().prop_map(ArrayItem::Spread),
+                ],
+                0..4
+            )
+            .prop_map(ExpressionBody::Array),
+            prop::collection::vec(
+                prop_oneof![
+                    identifier().prop_map(ObjectProperty::Single),
+                    (identifier(), inner.clone()).prop_map(|(key, value)| {
+                        ObjectProperty::Property(Property {
+                            key: PropertyKey::Identifier(key),
+                            value,
+                        })
+                    }),
+                    (inner.clone(), inner.clone()).prop_map(|(key, value)| {
+                        ObjectProperty::Property(Property {
+                            key: PropertyKey::Expression(key),
+                            value,
+                        })
+                    }),
+                    inner.clone().prop_map(ObjectProperty::Spread),
+                ],
+                0..4
+            )
+            .prop_map(ExpressionBody::Object),
+            (boxed.clone(), "[a-z]{1,6}", any::<bool>()).prop_map(|(object, name, optional)| {
+                ExpressionBody::Member(MemberExpression {
+                    object,
+                    property: Box::new(expression_of(ExpressionBody::Literal(Literal::String(
+                        Cow::Owned(name),
+                    )))),
+                    optional,
+                })
+            }),
+            (boxed.clone(), boxed.clone()).prop_map(|(object, property)| {
+                ExpressionBody::Member(MemberExpression {
+                    object,
+                    property,
+                    optional: false,
+                })
+            }),
+            (identifier(), prop::collection::vec(inner.clone(), 0..3)).prop_map(
+                |(function, arguments)| {
+                    ExpressionBody::Call(CallExpression {
+                        function,
+                        arguments,
+                    })
+                }
+            ),
+            (boxed.clone(), prop::collection::vec(inner.clone(), 0..3)).prop_map(
+                |(lambda, parameters)| {
+                    ExpressionBody::Application(LambdaApplication { lambda, parameters })
+                }
+            ),
+            (prop::collection::vec(pattern(), 0..3), boxed.clone()).prop_map(
+                |(arguments, body)| {
+                    ExpressionBody::Abstraction(LambdaAbstraction {
+                        name: None,
+                        arguments,
+                        body,
+                    })
+                }
+            ),
+            (
+                boxed.clone(),
+                boxed.clone(),
+                prop::option::of(boxed.clone())
+            )
+                .prop_map(|(condition, true_branch, false_branch)| {
+                    ExpressionBody::Condition(IfElseExpression {
+                        condition,
+                        true_branch,
+                        false_branch,
+                    })
+                }),
+            (
+                boxed.clone(),
+                prop::collection::vec(
+                    (pattern(), prop::option::of(inner.clone()), inner.clone()),
+                    1..3
+                )
+            )
+                .prop_map(|(subject, cases)| {
+                    ExpressionBody::Match(MatchExpression {
+                        subject,
+                        cases: cases
+                            .into_iter()
+                            .map(|(pattern, guard, body)| MatchCase {
+                                pattern,
+                                guard: guard.map(Box::new),
+                                body: Box::new(body),
+                            })
+                            .collect(),
+                    })
+                }),
+            (boxed.clone(), boxed.clone()).prop_map(|(value, function)| ExpressionBody::Pipe(
+                PipeExpression { value, function }
+            )),
+            (
+                prop::collection::vec(("[a-z ]{0,4}", inner.clone()), 0..3),
+                "[a-z ]{0,4}"
+            )
+                .prop_map(|(parts, suffix)| {
+                    ExpressionBody::Template(StringTemplate {
+                        parts: parts
+                            .into_iter()
+                            .map(|(fixed_start, dynamic_end)| StringTemplatePart {
+                                fixed_start: Cow::Owned(fixed_start),
+                                dynamic_end: Box::new(dynamic_end),
+                            })
+                            .collect(),
+                        suffix: Cow::Owned(suffix),
+                    })
+                }),
+            (
+                prop::collection::vec((pattern(), inner.clone()), 1..3),
+                boxed.clone()
+            )
+                .prop_map(|(assignments, body)| {
+                    ExpressionBody::Let(LetExpression {
+                        assignments: AssignmentSet {
+                            assignments: assignments
+                                .into_iter()
+                                .map(|(pattern, expression)| Assignment {
+                                    pattern,
+                                    expression,
+                                })
+                                .collect(),
+                        },
+                        body,
+                    })
+                }),
+            (boxed.clone(), pattern(), boxed.clone()).prop_map(|(body, pattern, fallback)| {
+                ExpressionBody::TryCatch(TryCatchExpression {
+                    body,
+                    pattern,
+                    fallback,
+                })
+            }),
+            (boxed.clone(), boxed.clone())
+                .prop_map(|(start, end)| ExpressionBody::Range(RangeExpression { start, end })),
+            (
+                prop::collection::vec(inner.clone().prop_map(ArrayItem::Single), 1..3),
+                prop::collection::vec(
+                    (
+                        boxed.clone(),
+                        pattern(),
+                        any::<bool>(),
+                        prop::option::of(boxed.clone())
+                    ),
+                    1..3
+                )
+            )
+                .prop_map(|(projection, sources)| {
+                    ExpressionBody::ArrayComp(ArrayComprehension {
+                        projection,
+                        sources: sources
+                            .into_iter()
+                            .map(|(collection, pattern, strong_pattern, predicate)| {
+                                ComprehensionSource {
+                                    collection,
+                                    pattern,
+                                    strong_pattern,
+                                    predicate,
+                                }
+                            })
+                            .collect(),
+                    })
+                }),
+        ]
+        .prop_map(expression_of)
+    })
+}
+
+/// Turns number literals with a sign, which the nom parser reads instead of
+/// a unary sign operator, back into the operator.
+struct UnarySigns;
+
+impl<'s> Fold<'s> for UnarySigns {
+    fn fold_expression(&mut self, expression: Expression<'s>) -> Expression<'s> {
+        let expression = fold_expression(self, expression);
+        let ExpressionBody::Literal(Literal::Number(n) | Literal::Float(n)) = &expression.body
+        else {
+            return expression;
+        };
+        let operator = match n.chars().next() {
+            Some('-') => UnaryOperator::Minus,
+            Some('+') => UnaryOperator::Plus,
+            _ => return expression,
+        };
+        let digits = Cow::Owned(n[1..].to_string());
+        let argument = match expression.body {
+            ExpressionBody::Literal(Literal::Float(_)) => Literal::Float(digits),
+            _ => Literal::Number(digits),
+        };
+
+        expression_of(ExpressionBody::Unary(UnaryExpression {
+            operator,
+            argument: Box::new(expression_of(ExpressionBody::Literal(argument))),
+        }))
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(512))]
+
+    #[test]
+    fn test_pretty_round_trip(expression in expression(), width in 1..100usize) {
+        let printed = PrettyPrinter::default().with_width(width).print_expression(&expression);
+
+        let nom = expression_all_consuming(&printed).map(|e| UnarySigns.fold_expression(e));
+        prop_assert_eq!(
+            nom.as_ref(),
+            Some(&expression),
+            "nom parser on:\n{}",
+            printed
+        );
+        let chumsky = single_expression().parse(&printed).into_result().ok();
+        prop_assert_eq!(
+            chumsky.as_ref(),
+            Some(&expression),
+            "chumsky parser on:\n{}",
+            printed
+        );
+    }
+}
+
+#[test]
+fn test_pretty_signed_numbers() {
+    let cases = [
+        ("-1", "-1"),
+        ("2 ^ -1", "2 ^ -1"),
+        ("(-2) ^ 2", "(-2) ^ 2"),
+        ("-2 ^ 2", "-(2 ^ 2)"),
+        ("-1.5", "-1.5"),
+    ];
+
+    let printer = PrettyPrinter::single_line();
+    for (input, expected) in cases {
+        let Ok(expression) = single_expression().parse(input).into_result() else {
+            unreachable!("Parse error: {input}");
+        };
+        assert_eq!(printer.print_expression(&expression), expected);
+    }
+}
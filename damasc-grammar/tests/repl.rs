@@ -0,0 +1,25 @@
+use chumsky::Parser;
+use damasc_grammar::repl::single_command;
+use damasc_repl::command::Command;
+
+#[test]
+fn test_let_command_and_expression() {
+    let parser = single_command();
+
+    assert!(matches!(
+        parser.parse("let x = 1").into_result(),
+        Ok(Command::Assign(_, None))
+    ));
+    assert!(matches!(
+        parser.parse("let x = (a in b)").into_result(),
+        Ok(Command::Assign(_, None))
+    ));
+    assert!(matches!(
+        parser.parse("let x = a in b").into_result(),
+        Ok(Command::Eval(_, _))
+    ));
+    assert!(matches!(
+        parser.parse("let x = 1 in x + y with y = 2").into_result(),
+        Ok(Command::Eval(_, _))
+    ));
+}
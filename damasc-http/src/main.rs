@@ -12,6 +12,7 @@ use actix_web::{
 };
 use askama::Template;
 use damasc_lang::identifier::Identifier;
+use damasc_lang::runtime::budget::EvalLimits;
 use damasc_repl::parser;
 use damasc_repl::state::State;
 
@@ -84,7 +85,7 @@ async fn eval(
     match parser::command_all_consuming(&repl.statement) {
         Ok(stmt) => {
             let (output, error) = match repl_state.eval(stmt) {
-                Ok(r) => (Some(format!("{r}")), None),
+                Ok(r) => (Some(format!("{r}{}", r.match_warnings())), None),
                 Err(e) => (None, Some(format!("{e:?}"))),
             };
 
@@ -155,22 +156,39 @@ async fn not_found() -> HttpResponse {
 struct Configuration {
     ip: String,
     port: u16,
+    max_steps: usize,
+    max_call_depth: usize,
+    max_collection_size: usize,
+}
+
+fn env_limit(name: &str, default: usize) -> usize {
+    env::var(name)
+        .ok()
+        .and_then(|s| s.parse::<usize>().ok())
+        .unwrap_or(default)
 }
 
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
-    let repl = State::new();
-    let repl_mutex = Arc::new(Mutex::new(repl));
-    let repl_mutex_data = Data::new(repl_mutex.clone());
-
     let conf = Configuration {
         ip: env::var("DAMASC_HOST").unwrap_or("127.0.0.1".into()),
         port: env::var("DAMASC_PORT")
             .ok()
             .and_then(|s| s.parse::<u16>().ok())
             .unwrap_or(8080),
+        max_steps: env_limit("DAMASC_MAX_STEPS", 100_000),
+        max_call_depth: env_limit("DAMASC_MAX_CALL_DEPTH", 100),
+        max_collection_size: env_limit("DAMASC_MAX_COLLECTION_SIZE", 10_000),
     };
 
+    let repl = State::new().with_limits(EvalLimits::new(
+        conf.max_steps,
+        conf.max_call_depth,
+        conf.max_collection_size,
+    ));
+    let repl_mutex = Arc::new(Mutex::new(repl));
+    let repl_mutex_data = Data::new(repl_mutex.clone());
+
     let server = HttpServer::new(move || {
         App::new()
             .app_data(repl_mutex_data.clone())
@@ -1,14 +1,14 @@
-use damasc_lang::value::{Value, ValueBag};
+use damasc_lang::value::{owned::OwnedValue, Value, ValueBag};
 
 use crate::identity::{IdSequence, IdentifiedValue, ValueId};
 
 #[derive(Default, Debug, Clone)]
-pub struct Bag<'s, 'v> {
+pub struct Bag {
     sequence: IdSequence,
-    pub(crate) values: Vec<IdentifiedValue<'s, 'v>>,
+    pub(crate) values: Vec<IdentifiedValue<OwnedValue>>,
 }
 
-impl<'s, 'v> Bag<'s, 'v> {
+impl Bag {
     pub fn new() -> Self {
         Self {
             sequence: IdSequence::default(),
@@ -16,11 +16,19 @@ impl<'s, 'v> Bag<'s, 'v> {
         }
     }
 
-    pub fn insert(&mut self, value: &Value<'s, 'v>) {
+    pub fn insert(&mut self, value: &Value) {
+        self.insert_owned(&OwnedValue::from(value));
+    }
+
+    pub fn insert_owned(&mut self, value: &OwnedValue) {
         self.values
             .push(IdentifiedValue::new(self.sequence.next(), value.clone()));
     }
 
+    pub fn owned_values(&self) -> Vec<OwnedValue> {
+        self.values.iter().map(|v| v.value.clone()).collect()
+    }
+
     pub fn remove(&mut self, value_id: ValueId) {
         self.values.retain(|v| v.id != value_id)
     }
@@ -34,8 +42,8 @@ impl<'s, 'v> Bag<'s, 'v> {
     }
 }
 
-impl<'s, 'v: 's> From<ValueBag<'s, 'v>> for Bag<'s, 'v> {
-    fn from(value_bag: ValueBag<'s, 'v>) -> Self {
+impl From<ValueBag<'_, '_>> for Bag {
+    fn from(value_bag: ValueBag<'_, '_>) -> Self {
         let mut result = Self::new();
         for v in &value_bag.values {
             result.insert(v);
@@ -45,8 +53,8 @@ impl<'s, 'v: 's> From<ValueBag<'s, 'v>> for Bag<'s, 'v> {
     }
 }
 
-impl<'s, 'v: 's> From<&ValueBag<'s, 'v>> for Bag<'s, 'v> {
-    fn from(value_bag: &ValueBag<'s, 'v>) -> Self {
+impl From<&ValueBag<'_, '_>> for Bag {
+    fn from(value_bag: &ValueBag<'_, '_>) -> Self {
         let mut result = Self::new();
         for v in &value_bag.values {
             result.insert(v);
@@ -5,6 +5,6 @@ use damasc_lang::identifier::Identifier;
 use crate::bag::Bag;
 
 #[derive(Debug, Default, Clone)]
-pub struct BagBundle<'s, 'v> {
-    pub bags: HashMap<Identifier<'s>, Bag<'s, 'v>>,
+pub struct BagBundle<'s> {
+    pub bags: HashMap<Identifier<'s>, Bag>,
 }
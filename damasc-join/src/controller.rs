@@ -1,9 +1,8 @@
-use std::{borrow::Cow, collections::HashSet};
+use std::collections::HashSet;
 
 use damasc_lang::runtime::{env::Environment, evaluation::Evaluation};
 
 use crate::{
-    bag::Bag,
     bag_bundle::BagBundle,
     identity::IdentifiedEnvironment,
     iter::BagMultiPredicateIterator,
@@ -12,12 +11,12 @@ use crate::{
 };
 
 #[derive(Default, Clone)]
-pub struct Controller<'s, 'v> {
-    pub storage: BagBundle<'s, 'v>,
+pub struct Controller<'s> {
+    pub storage: BagBundle<'s>,
 }
 
-impl<'s, 'v> Controller<'s, 'v> {
-    pub fn query<'x: 's, 'slf: 's>(
+impl<'s> Controller<'s> {
+    pub fn query<'x: 's, 'slf: 's, 'v>(
         &'slf self,
         join: &'x Join<'s, 'v>,
     ) -> impl Iterator<Item = Transaction<'_, '_>> {
@@ -28,11 +27,10 @@ impl<'s, 'v> Controller<'s, 'v> {
             .map(|(source, pred)| {
                 let bag = match source {
                     crate::join::JoinSource::Constant(value_bag) => {
-                        let adhoc_bag = Bag::from(value_bag);
                         BagMultiPredicateIterator::new_without_id(
                             Environment::default(),
                             pred,
-                            Cow::Owned(adhoc_bag),
+                            value_bag,
                         )
                     }
                     crate::join::JoinSource::Named(name) => {
@@ -41,7 +39,7 @@ impl<'s, 'v> Controller<'s, 'v> {
                                 Environment::default(),
                                 name.clone(),
                                 pred,
-                                Cow::Borrowed(b),
+                                b,
                             )
                         } else {
                             BagMultiPredicateIterator::empty(
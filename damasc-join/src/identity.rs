@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-use damasc_lang::{identifier::Identifier, runtime::env::Environment, value::Value};
+use damasc_lang::{identifier::Identifier, runtime::env::Environment};
 
 #[derive(PartialEq, Eq, Debug, Clone, Hash)]
 pub struct ValueId {
@@ -35,13 +35,13 @@ impl IdSequence {
 }
 
 #[derive(Debug, Clone)]
-pub(crate) struct IdentifiedValue<'s, 'v> {
+pub(crate) struct IdentifiedValue<V> {
     pub(crate) id: ValueId,
-    pub(crate) value: Value<'s, 'v>,
+    pub(crate) value: V,
 }
 
-impl<'s, 'v> IdentifiedValue<'s, 'v> {
-    pub fn new(id: ValueId, value: Value<'s, 'v>) -> Self {
+impl<V> IdentifiedValue<V> {
+    pub fn new(id: ValueId, value: V) -> Self {
         Self { id, value }
     }
 }
@@ -1,14 +1,16 @@
-use std::borrow::Cow;
 use std::collections::HashSet;
 
 use damasc_lang::identifier::Identifier;
 use damasc_lang::runtime::evaluation::Evaluation;
-use damasc_lang::{runtime::env::Environment, value::Value};
+use damasc_lang::{
+    runtime::env::Environment,
+    value::{Value, ValueBag},
+};
 use damasc_query::predicate::MultiPredicate;
 use damasc_query::predicate::PredicateError;
 use itertools::Permutations;
 
-use crate::identity::BagAndValueId;
+use crate::identity::{BagAndValueId, ValueId};
 use crate::{
     bag::Bag,
     identity::{IdentifiedEnvironment, IdentifiedValue},
@@ -18,7 +20,7 @@ pub(crate) struct BagMultiPredicateIterator<'i, 's, 'v, 'p> {
     bag_id: Option<Identifier<'s>>,
     env: Environment<'i, 's, 'v>,
     predicate: &'p MultiPredicate<'s>,
-    iter: Permutations<std::vec::IntoIter<IdentifiedValue<'s, 'v>>>,
+    iter: Permutations<std::vec::IntoIter<IdentifiedValue<Value<'s, 'v>>>>,
 }
 
 impl<'i, 's, 'v, 'p> Clone for BagMultiPredicateIterator<'i, 's, 'v, 'p> {
@@ -37,7 +39,7 @@ impl<'i, 's, 'v, 'p> BagMultiPredicateIterator<'i, 's, 'v, 'p> {
         env: Environment<'i, 's, 'v>,
         bag_id: Identifier<'s>,
         predicate: &'p MultiPredicate<'s>,
-        bag: Cow<'i, Bag<'s, 'v>>,
+        bag: &Bag,
     ) -> Self {
         use itertools::Itertools;
 
@@ -46,7 +48,9 @@ impl<'i, 's, 'v, 'p> BagMultiPredicateIterator<'i, 's, 'v, 'p> {
             env,
             iter: bag
                 .values
-                .clone()
+                .iter()
+                .map(|v| IdentifiedValue::new(v.id.clone(), v.value.to_value()))
+                .collect::<Vec<_>>()
                 .into_iter()
                 .permutations(predicate.capture.patterns.patterns.len()),
             predicate,
@@ -56,16 +60,19 @@ impl<'i, 's, 'v, 'p> BagMultiPredicateIterator<'i, 's, 'v, 'p> {
     pub fn new_without_id(
         env: Environment<'i, 's, 'v>,
         predicate: &'p MultiPredicate<'s>,
-        bag: Cow<'i, Bag<'s, 'v>>,
+        values: &ValueBag<'s, 'v>,
     ) -> Self {
         use itertools::Itertools;
 
         Self {
             bag_id: None,
             env,
-            iter: bag
+            iter: values
                 .values
-                .clone()
+                .iter()
+                .enumerate()
+                .map(|(i, v)| IdentifiedValue::new(ValueId::new(i as u64), v.clone()))
+                .collect::<Vec<_>>()
                 .into_iter()
                 .permutations(predicate.capture.patterns.patterns.len()),
             predicate,
@@ -129,7 +136,7 @@ impl<'i: 's, 's, 'p> Iterator for BagMultiPredicateIterator<'i, 's, 's, 'p> {
 pub(crate) fn apply_identified<'s: 'x, 'i: 's, 'e, 'x: 'y, 'y>(
     pred: &MultiPredicate<'s>,
     env: &Environment<'i, 's, 's>,
-    values: impl Iterator<Item = &'x IdentifiedValue<'s, 's>>,
+    values: impl Iterator<Item = &'x IdentifiedValue<Value<'s, 's>>>,
 ) -> Result<Option<Environment<'i, 's, 's>>, PredicateError> {
     let env = match pred.capture.apply(env, values.map(|v| &v.value)) {
         Ok(Some(e)) => e,
@@ -32,7 +32,7 @@ use crate::{
 
 pub fn bag_bundle<'v, 's, E: ParserError<'s>>(
     input: ParserInput<'s>,
-) -> ParserResult<BagBundle<'_, '_>, E> {
+) -> ParserResult<BagBundle<'_>, E> {
     let (leftover, bags) = fold_many0(
         pair(
             ws(delimited(tag("$"), identifier::identifier, tag(":"))),
@@ -53,7 +53,7 @@ pub fn bag_bundle<'v, 's, E: ParserError<'s>>(
     bags.map(|bags| (leftover, BagBundle { bags }))
 }
 
-pub fn bag_bundle_all_consuming(bundle_string: &str) -> Option<BagBundle<'_, '_>> {
+pub fn bag_bundle_all_consuming(bundle_string: &str) -> Option<BagBundle<'_>> {
     match all_consuming(ws(bag_bundle::<Error<ParserInput>>))(ParserInput::new(bundle_string)) {
         Ok((_, r)) => Some(r),
         Err(_) => None,
@@ -1,9 +1,12 @@
 #![feature(assert_matches)]
 
 use std::assert_matches::assert_matches;
+use std::sync::Arc;
 
+use damasc_join::bag::Bag;
 use damasc_join::parser;
 use damasc_lang::identifier::Identifier;
+use damasc_lang::value::owned::OwnedValue;
 
 #[test]
 fn read_bag_bundle() {
@@ -43,3 +46,26 @@ fn read_bag_bundle_empty() {
 
     assert_matches!(parser::bag_bundle_all_consuming(bundle_string), Some(_));
 }
+
+#[test]
+fn bag_with_owned_values() {
+    let Some(value) = damasc_lang::parser::value::single_value("{x: [1, 2, \"three\"]}") else {
+        unreachable!("value parse error");
+    };
+    let owned = OwnedValue::from(&value);
+
+    let mut bag = Bag::new();
+    bag.insert_owned(&owned);
+    bag.insert_owned(&owned);
+
+    assert_eq!(bag.len(), 2);
+    assert_eq!(bag.owned_values(), vec![owned.clone(), owned.clone()]);
+
+    let values = bag.owned_values();
+    let (OwnedValue::Object(inserted), [OwnedValue::Object(stored), _]) =
+        (owned, values.as_slice())
+    else {
+        unreachable!("expected objects");
+    };
+    assert!(Arc::ptr_eq(&inserted, stored));
+}
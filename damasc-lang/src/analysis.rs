@@ -0,0 +1,4 @@
+pub mod exhaustiveness;
+pub mod lint;
+pub mod types;
+pub mod typing;
@@ -0,0 +1,605 @@
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+use crate::identifier::Identifier;
+use crate::literal::Literal;
+use crate::syntax::expression::{MatchCase, PropertyKey};
+use crate::syntax::location::Location;
+use crate::syntax::pattern::{
+    ArrayPatternItem, ObjectPropertyPattern, Pattern, PatternBody, PropertyPattern, Rest,
+};
+use crate::value_type::ValueType;
+
+use super::types::{ObjectType, Type};
+
+const KINDS: [ValueType; 9] = [
+    ValueType::Null,
+    ValueType::String,
+    ValueType::Integer,
+    ValueType::Float,
+    ValueType::Boolean,
+    ValueType::Array,
+    ValueType::Object,
+    ValueType::Type,
+    ValueType::Lambda,
+];
+
+#[derive(Debug, Clone)]
+pub struct MatchWarning<'s> {
+    pub reason: MatchWarningReason<'s>,
+    pub location: Option<Location>,
+}
+
+#[derive(Debug, Clone)]
+pub enum MatchWarningReason<'s> {
+    /// The witness is matched by none of the cases.
+    NonExhaustive(Witness),
+    UnreachableCase(Pattern<'s>),
+}
+
+impl std::fmt::Display for MatchWarningReason<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MatchWarningReason::NonExhaustive(witness) => write!(
+                f,
+                "The cases do not cover all values, e.g. {} is not matched.",
+                witness
+            ),
+            MatchWarningReason::UnreachableCase(pattern) => {
+                write!(f, "The case {} can never be reached.", pattern)
+            }
+        }
+    }
+}
+
+/// A pattern describing values that are not matched, written in the syntax
+/// of patterns.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Witness(Space);
+
+impl std::fmt::Display for Witness {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Reports the cases that can never be reached, because the earlier cases
+/// or the type of the subject already rule out all their values, and
+/// whether some value of the subject's type is matched by no case at all.
+///
+/// Cases with a guard, pinned expressions, ranges, regular expressions and
+/// repeated identifiers are assumed to possibly fail, so they never make
+/// another case unreachable.
+pub fn check_cases<'s>(
+    subject: &Type,
+    cases: &[MatchCase<'s>],
+    location: Option<Location>,
+) -> Vec<MatchWarning<'s>> {
+    // Values the subject can not have are treated as matched up front.
+    let mut rows: Vec<Vec<Space>> = KINDS
+        .iter()
+        .filter(|t| !subject.admits(**t))
+        .map(|t| vec![Space::Kind(*t)])
+        .collect();
+    let mut warnings = vec![];
+
+    for case in cases {
+        if useful(&rows, &[Lowering::new(false).space(&case.pattern)]).is_none() {
+            warnings.push(MatchWarning {
+                reason: MatchWarningReason::UnreachableCase(case.pattern.clone()),
+                location: case.pattern.location,
+            });
+        }
+
+        if case.guard.is_none() {
+            rows.push(vec![Lowering::new(true).space(&case.pattern)]);
+        }
+    }
+
+    let subject = if subject.is_never() {
+        Space::Any
+    } else {
+        type_space(subject)
+    };
+    if let Some(mut witness) = useful(&rows, &[subject]) {
+        warnings.push(MatchWarning {
+            reason: MatchWarningReason::NonExhaustive(Witness(witness.remove(0))),
+            location,
+        });
+    }
+
+    warnings
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Constructor {
+    /// The absence of an object key.
+    Missing,
+    Null,
+    Boolean(bool),
+    Integer(i64),
+    Float(f64),
+    String(String),
+    Type(ValueType),
+    Array(usize),
+    /// Objects with the given keys present or missing, followed by a
+    /// pseudo field that is missing if there are no other keys.
+    Object(Vec<String>),
+    /// The values of the type that no pattern tells apart.
+    Other(ValueType),
+}
+
+impl Constructor {
+    fn kind(&self) -> Option<ValueType> {
+        Some(match self {
+            Constructor::Missing => return None,
+            Constructor::Null => ValueType::Null,
+            Constructor::Boolean(_) => ValueType::Boolean,
+            Constructor::Integer(_) => ValueType::Integer,
+            Constructor::Float(_) => ValueType::Float,
+            Constructor::String(_) => ValueType::String,
+            Constructor::Type(_) => ValueType::Type,
+            Constructor::Array(_) => ValueType::Array,
+            Constructor::Object(_) => ValueType::Object,
+            Constructor::Other(t) => *t,
+        })
+    }
+
+    fn fields(&self) -> Vec<Space> {
+        match self {
+            Constructor::Array(length) => vec![Space::Any; *length],
+            Constructor::Object(keys) => vec![Space::Wild; keys.len() + 1],
+            _ => vec![],
+        }
+    }
+}
+
+/// A set of values, or of missing object keys, described by a pattern.
+#[derive(Clone, Debug, PartialEq)]
+enum Space {
+    /// Any value or a missing key.
+    Wild,
+    /// Any value.
+    Any,
+    Empty,
+    Kind(ValueType),
+    Constructor(Constructor),
+    /// Prefix, the items in between if their number is not fixed, suffix.
+    Array(Vec<Space>, Option<Box<Space>>, Vec<Space>),
+    /// Fields and whether other keys are forbidden.
+    Object(BTreeMap<String, Space>, bool),
+    Or(Vec<Space>),
+}
+
+impl Space {
+    fn covers_kind(&self, kind: ValueType) -> bool {
+        match self {
+            Space::Wild | Space::Any => true,
+            Space::Kind(k) => *k == kind,
+            _ => false,
+        }
+    }
+
+    /// The fields of the values of this space that are built by the
+    /// constructor, `None` if there are none.
+    fn specialize(&self, constructor: &Constructor) -> Option<Vec<Space>> {
+        match (self, constructor) {
+            (Space::Wild, c) => Some(c.fields()),
+            (Space::Any, Constructor::Missing) => None,
+            (Space::Any, c) => Some(c.fields()),
+            (Space::Kind(t), c) if c.kind() == Some(*t) => Some(c.fields()),
+            (Space::Constructor(a), b) if a == b => Some(vec![]),
+            (Space::Array(prefix, middle, suffix), Constructor::Array(length)) => {
+                let fixed = prefix.len() + suffix.len();
+                let middle = match middle {
+                    None if *length == fixed => vec![],
+                    Some(item) if *length >= fixed => vec![item.as_ref().clone(); length - fixed],
+                    _ => return None,
+                };
+                Some(
+                    prefix
+                        .iter()
+                        .cloned()
+                        .chain(middle)
+                        .chain(suffix.iter().cloned())
+                        .collect(),
+                )
+            }
+            (Space::Object(fields, exact), Constructor::Object(keys)) => {
+                let unmentioned = if *exact {
+                    Space::Constructor(Constructor::Missing)
+                } else {
+                    Space::Wild
+                };
+                Some(
+                    keys.iter()
+                        .map(|k| fields.get(k).cloned().unwrap_or(unmentioned.clone()))
+                        .chain([unmentioned.clone()])
+                        .collect(),
+                )
+            }
+            _ => None,
+        }
+    }
+
+    fn alternatives(&self) -> Vec<&Space> {
+        match self {
+            Space::Or(alternatives) => alternatives.iter().flat_map(|a| a.alternatives()).collect(),
+            Space::Empty => vec![],
+            other => vec![other],
+        }
+    }
+}
+
+impl std::fmt::Display for Space {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Space::Wild | Space::Any | Space::Empty => write!(f, "_"),
+            Space::Kind(t) => write!(f, "_ is {t}"),
+            Space::Constructor(c) => match c {
+                Constructor::Null => write!(f, "null"),
+                Constructor::Boolean(b) => write!(f, "{b}"),
+                Constructor::Integer(i) => write!(f, "{i}"),
+                Constructor::Float(x) => write!(f, "{x:?}"),
+                Constructor::String(s) => write!(f, "\"{s}\""),
+                Constructor::Type(t) => write!(f, "{t}"),
+                Constructor::Other(t) => write!(f, "_ is {t}"),
+                Constructor::Missing | Constructor::Array(_) | Constructor::Object(_) => {
+                    write!(f, "_")
+                }
+            },
+            Space::Array(prefix, middle, suffix) => {
+                let mut items: Vec<String> = prefix.iter().map(|i| i.to_string()).collect();
+                if middle.is_some() {
+                    items.push("...".to_string());
+                }
+                items.extend(suffix.iter().map(|i| i.to_string()));
+                write!(f, "[{}]", items.join(", "))
+            }
+            Space::Object(fields, exact) => {
+                let mut items: Vec<String> =
+                    fields.iter().map(|(k, v)| format!("{k}: {v}")).collect();
+                if !exact {
+                    items.push("...".to_string());
+                }
+                write!(f, "{{{}}}", items.join(", "))
+            }
+            Space::Or(alternatives) => match alternatives.first() {
+                Some(first) => write!(f, "{first}"),
+                None => write!(f, "_"),
+            },
+        }
+    }
+}
+
+/// Translates patterns into spaces. An exact lowering only keeps the values
+/// the pattern surely matches, otherwise all values it might match are kept.
+struct Lowering {
+    exact: bool,
+    bound: HashSet<String>,
+}
+
+impl Lowering {
+    fn new(exact: bool) -> Self {
+        Self {
+            exact,
+            bound: HashSet::new(),
+        }
+    }
+
+    fn partial(&self, over: Space) -> Space {
+        if self.exact {
+            Space::Empty
+        } else {
+            over
+        }
+    }
+
+    // An identifier bound a second time only matches equal values.
+    fn bind(&mut self, identifier: &Identifier, space: Space) -> Space {
+        if self.bound.insert(identifier.name.to_string()) {
+            space
+        } else {
+            self.partial(space)
+        }
+    }
+
+    fn space(&mut self, pattern: &Pattern) -> Space {
+        match &pattern.body {
+            PatternBody::Discard => Space::Any,
+            PatternBody::Identifier(id) => self.bind(id, Space::Any),
+            PatternBody::Capture(id, inner) => {
+                let inner = self.space(inner);
+                self.bind(id, inner)
+            }
+            PatternBody::TypedDiscard(t) => Space::Kind(*t),
+            PatternBody::TypedIdentifier(id, t) => self.bind(id, Space::Kind(*t)),
+            PatternBody::Literal(literal) => match constructor_of(literal) {
+                Some(c) => Space::Constructor(c),
+                None => self.partial(Space::Any),
+            },
+            PatternBody::PinnedExpression(_) | PatternBody::Range(_) => self.partial(Space::Any),
+            PatternBody::Relational(_, _) => self.partial(Space::Any),
+            PatternBody::Regex(_) | PatternBody::String(_) => {
+                self.partial(Space::Kind(ValueType::String))
+            }
+            PatternBody::Alternative(alternatives) => {
+                let before = self.bound.clone();
+                let mut after = HashSet::new();
+                let mut spaces = Vec::with_capacity(alternatives.len());
+                for alternative in alternatives {
+                    self.bound = before.clone();
+                    spaces.push(self.space(alternative));
+                    after.extend(self.bound.drain());
+                }
+                self.bound = after;
+                Space::Or(spaces)
+            }
+            PatternBody::Array(items, rest, suffix) => {
+                let middle = match rest {
+                    Rest::Exact => None,
+                    Rest::Discard => Some(Box::new(Space::Any)),
+                    Rest::Collect(p) => {
+                        if !self.space(p).covers_kind(ValueType::Array) {
+                            return self.partial(Space::Kind(ValueType::Array));
+                        }
+                        Some(Box::new(Space::Any))
+                    }
+                };
+                let prefix = items
+                    .iter()
+                    .map(|ArrayPatternItem::Pattern(p)| self.space(p))
+                    .collect();
+                let suffix = suffix
+                    .iter()
+                    .map(|ArrayPatternItem::Pattern(p)| self.space(p))
+                    .collect();
+                Space::Array(prefix, middle, suffix)
+            }
+            PatternBody::Object(props, rest) => self.object_space(props, rest),
+        }
+    }
+
+    fn object_space(&mut self, props: &[ObjectPropertyPattern], rest: &Rest) -> Space {
+        let mut fields = BTreeMap::new();
+
+        for prop in props {
+            let (key, field) = match prop {
+                ObjectPropertyPattern::Single(id) => (id, self.bind(id, Space::Any)),
+                ObjectPropertyPattern::Optional(id) | ObjectPropertyPattern::Default(id, _) => {
+                    (id, self.bind(id, Space::Wild))
+                }
+                ObjectPropertyPattern::Match(PropertyPattern {
+                    key: PropertyKey::Identifier(id),
+                    value,
+                    default,
+                }) => {
+                    let value = self.space(value);
+                    let field = match default {
+                        None => value,
+                        Some(_) if value == Space::Any => Space::Wild,
+                        // a missing key is only matched if the default is
+                        Some(_) if self.exact => value,
+                        Some(_) => Space::Or(vec![value, Space::Constructor(Constructor::Missing)]),
+                    };
+                    (id, field)
+                }
+                ObjectPropertyPattern::Match(PropertyPattern {
+                    key: PropertyKey::Expression(_),
+                    ..
+                }) => return self.partial(Space::Kind(ValueType::Object)),
+            };
+
+            if fields.insert(key.name.to_string(), field).is_some() {
+                return self.partial(Space::Kind(ValueType::Object));
+            }
+        }
+
+        let exact = match rest {
+            Rest::Exact => true,
+            Rest::Discard => false,
+            Rest::Collect(p) => {
+                if !self.space(p).covers_kind(ValueType::Object) {
+                    return self.partial(Space::Kind(ValueType::Object));
+                }
+                false
+            }
+        };
+
+        Space::Object(fields, exact)
+    }
+}
+
+fn type_space(t: &Type) -> Space {
+    match t {
+        Type::Any => Space::Any,
+        Type::Never => Space::Empty,
+        Type::Null => Space::Constructor(Constructor::Null),
+        Type::Array(element) => Space::Array(vec![], Some(Box::new(type_space(element))), vec![]),
+        Type::Object(ObjectType { fields, open }) => Space::Object(
+            fields
+                .iter()
+                .map(|(k, t)| (k.clone(), type_space(t)))
+                .collect(),
+            !open,
+        ),
+        Type::Union(members) => Space::Or(members.iter().map(type_space).collect()),
+        other => other.value_type().map_or(Space::Any, Space::Kind),
+    }
+}
+
+fn constructor_of(literal: &Literal) -> Option<Constructor> {
+    Some(match literal {
+        Literal::Null => Constructor::Null,
+        Literal::String(s) => Constructor::String(s.to_string()),
+        Literal::Number(n) => Constructor::Integer(n.parse().ok()?),
+        Literal::Float(x) => Constructor::Float(x.parse().ok()?),
+        Literal::Boolean(b) => Constructor::Boolean(*b),
+        Literal::Type(t) => Constructor::Type(*t),
+    })
+}
+
+/// All constructors that need to be told apart to decide how the values are
+/// matched by the given spaces.
+fn constructors(column: &[&Space]) -> Vec<Constructor> {
+    let mut result = vec![
+        Constructor::Missing,
+        Constructor::Null,
+        Constructor::Boolean(false),
+        Constructor::Boolean(true),
+    ];
+    result.extend(KINDS.iter().map(|t| Constructor::Type(*t)));
+
+    for space in column {
+        if let Space::Constructor(
+            c @ (Constructor::Integer(_) | Constructor::Float(_) | Constructor::String(_)),
+        ) = space
+        {
+            if !result.contains(c) {
+                result.push(c.clone());
+            }
+        }
+    }
+    result.extend(
+        [
+            ValueType::Integer,
+            ValueType::Float,
+            ValueType::String,
+            ValueType::Lambda,
+        ]
+        .map(Constructor::Other),
+    );
+
+    // Arrays longer than any fixed length pattern whose prefixes and
+    // suffixes do not overlap are all matched alike.
+    let mut arrays = column.iter().filter_map(|space| match space {
+        Space::Array(prefix, middle, suffix) => {
+            Some((prefix.len(), middle.is_some(), suffix.len()))
+        }
+        _ => None,
+    });
+    if let Some(first) = arrays.next() {
+        let (mut fixed, mut prefix, mut suffix) = (0, 0, 0);
+        for (p, open, s) in [first].into_iter().chain(arrays) {
+            if open {
+                prefix = prefix.max(p);
+                suffix = suffix.max(s);
+            } else {
+                fixed = fixed.max(p + s + 1);
+            }
+        }
+        result.extend((0..=fixed.max(prefix + suffix)).map(Constructor::Array));
+    } else {
+        result.push(Constructor::Other(ValueType::Array));
+    }
+
+    let objects: Vec<_> = column
+        .iter()
+        .filter_map(|space| match space {
+            Space::Object(fields, _) => Some(fields.keys()),
+            _ => None,
+        })
+        .collect();
+    if objects.is_empty() {
+        result.push(Constructor::Other(ValueType::Object));
+    } else {
+        let keys: BTreeSet<&String> = objects.into_iter().flatten().collect();
+        result.push(Constructor::Object(keys.into_iter().cloned().collect()));
+    }
+
+    result
+}
+
+/// Searches for values matched by `row` but by none of the `rows`, see
+/// Maranget's "Warnings for pattern matching". The result describes them
+/// column by column.
+fn useful(rows: &[Vec<Space>], row: &[Space]) -> Option<Vec<Space>> {
+    let Some((head, tail)) = row.split_first() else {
+        return rows.is_empty().then(Vec::new);
+    };
+    if rows.is_empty() {
+        return Some(row.to_vec());
+    }
+
+    if let Space::Or(_) | Space::Empty = head {
+        return head.alternatives().into_iter().find_map(|alternative| {
+            let mut row = vec![alternative.clone()];
+            row.extend_from_slice(tail);
+            useful(rows, &row)
+        });
+    }
+
+    let rows: Vec<Vec<Space>> = rows
+        .iter()
+        .flat_map(|r| {
+            r[0].alternatives().into_iter().map(|alternative| {
+                let mut r = r.clone();
+                r[0] = alternative.clone();
+                r
+            })
+        })
+        .collect();
+
+    let column: Vec<&Space> = [head]
+        .into_iter()
+        .chain(rows.iter().map(|r| &r[0]))
+        .collect();
+    let mut seen = vec![];
+
+    for constructor in constructors(&column) {
+        let Some(fields) = head.specialize(&constructor) else {
+            continue;
+        };
+        let specialized: Vec<Option<Vec<Space>>> =
+            rows.iter().map(|r| r[0].specialize(&constructor)).collect();
+
+        // Constructors without fields that are covered by the same rows
+        // lead to the same result.
+        if fields.is_empty() {
+            let signature: Vec<bool> = specialized.iter().map(|s| s.is_some()).collect();
+            if seen.contains(&signature) {
+                continue;
+            }
+            seen.push(signature);
+        }
+
+        let remaining: Vec<Vec<Space>> = std::iter::zip(specialized, &rows)
+            .filter_map(|(fields, r)| {
+                let mut fields = fields?;
+                fields.extend_from_slice(&r[1..]);
+                Some(fields)
+            })
+            .collect();
+        let arity = fields.len();
+        let mut next = fields;
+        next.extend_from_slice(tail);
+
+        if let Some(mut witness) = useful(&remaining, &next) {
+            let rest = witness.split_off(arity);
+            let mut result = vec![rebuild(constructor, witness)];
+            result.extend(rest);
+            return Some(result);
+        }
+    }
+
+    None
+}
+
+fn rebuild(constructor: Constructor, mut fields: Vec<Space>) -> Space {
+    match constructor {
+        Constructor::Array(_) => Space::Array(fields, None, vec![]),
+        Constructor::Object(keys) => {
+            // A key that may be missing is left out of the example.
+            let left_out =
+                |s: &Space| matches!(s, Space::Wild | Space::Constructor(Constructor::Missing));
+            let exact = fields.pop().is_none_or(|other| left_out(&other));
+            Space::Object(
+                std::iter::zip(keys, fields)
+                    .filter(|(_, field)| !left_out(field))
+                    .collect(),
+                exact,
+            )
+        }
+        Constructor::Other(t) => Space::Kind(t),
+        c => Space::Constructor(c),
+    }
+}
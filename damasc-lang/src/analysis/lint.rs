@@ -0,0 +1,315 @@
+use std::collections::HashSet;
+
+use crate::identifier::Identifier;
+use crate::runtime::env::Environment;
+use crate::syntax::assignment::{Assignment, AssignmentSet};
+use crate::syntax::expression::{
+    ArrayComprehension, ArrayItem, ComprehensionSource, Expression, ExpressionBody,
+    LambdaAbstraction, LetExpression, MatchExpression, ObjectComprehension, ObjectProperty,
+    Property, PropertyKey, TryCatchExpression,
+};
+use crate::syntax::location::Location;
+use crate::syntax::pattern::Pattern;
+use crate::syntax::visit::{visit_expression, Visitor};
+
+#[derive(Debug, Clone)]
+pub struct LintWarning<'s> {
+    pub reason: LintWarningReason<'s>,
+    pub location: Option<Location>,
+}
+
+#[derive(Debug, Clone)]
+pub enum LintWarningReason<'s> {
+    /// A binding of an assignment or a comprehension source that is never
+    /// read. Identifiers starting with an underscore are exempt.
+    UnusedBinding(Identifier<'s>),
+    Shadowing(Identifier<'s>),
+    /// An expression within a pattern refers to an identifier bound by the
+    /// same pattern. Such expressions are evaluated before the pattern binds
+    /// anything, so they can only see the identifier of an outer scope.
+    SamePatternReference(Identifier<'s>),
+}
+
+impl std::fmt::Display for LintWarningReason<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LintWarningReason::UnusedBinding(id) => write!(f, "{} is bound but never used.", id),
+            LintWarningReason::Shadowing(id) => {
+                write!(f, "{} shadows a binding of an enclosing scope.", id)
+            }
+            LintWarningReason::SamePatternReference(id) => write!(
+                f,
+                "{} is bound by the surrounding pattern, but the expression is evaluated before it is bound.",
+                id
+            ),
+        }
+    }
+}
+
+/// Checks for unused bindings, shadowing and expressions within patterns
+/// that refer to the pattern's own bindings.
+pub struct Linter {
+    scope: Vec<String>,
+}
+
+impl Linter {
+    pub fn new(env: &Environment) -> Self {
+        Self {
+            scope: env.bindings.keys().map(|id| id.name.to_string()).collect(),
+        }
+    }
+
+    pub fn lint_expression<'s>(&self, expression: &Expression<'s>) -> Vec<LintWarning<'s>> {
+        self.lint(&AssignmentSet::default(), std::slice::from_ref(expression))
+    }
+
+    /// Lints the expressions together with the assignments binding their
+    /// local identifiers. Without expressions the assignments are taken as
+    /// top level definitions, which are neither unused nor shadowing.
+    pub fn lint<'s>(
+        &self,
+        assignments: &AssignmentSet<'s, 's>,
+        expressions: &[Expression<'s>],
+    ) -> Vec<LintWarning<'s>> {
+        let mut walk = Walk {
+            scope: self.scope.clone(),
+            warnings: vec![],
+        };
+
+        if expressions.is_empty() {
+            for Assignment {
+                pattern,
+                expression,
+            } in &assignments.assignments
+            {
+                walk.pattern(pattern);
+                walk.scope
+                    .extend(pattern.get_identifiers().map(|id| id.name.to_string()));
+                walk.expression(expression);
+            }
+        } else {
+            walk.assignments(&assignments.assignments, expressions.iter().collect());
+        }
+
+        walk.warnings
+    }
+}
+
+struct Walk<'s> {
+    scope: Vec<String>,
+    warnings: Vec<LintWarning<'s>>,
+}
+
+impl<'a> Visitor<'a> for Walk<'_> {
+    fn visit_expression(&mut self, expression: &'a Expression<'a>) {
+        self.expression(expression)
+    }
+
+    fn visit_pattern(&mut self, pattern: &'a Pattern<'a>) {
+        self.pattern(pattern)
+    }
+}
+
+impl<'s> Walk<'s> {
+    fn warn(&mut self, location: Option<Location>, reason: LintWarningReason<'s>) {
+        self.warnings.push(LintWarning { reason, location });
+    }
+
+    fn expression(&mut self, expression: &Expression) {
+        match &expression.body {
+            ExpressionBody::Let(LetExpression { assignments, body }) => {
+                self.assignments(&assignments.assignments, vec![body]);
+            }
+            ExpressionBody::Abstraction(LambdaAbstraction {
+                name,
+                arguments,
+                body,
+            }) => {
+                for argument in arguments {
+                    self.pattern(argument);
+                }
+
+                let depth = self.scope.len();
+                let name = name.iter().map(|n| (n, expression.location));
+                self.bind(name.chain(arguments.iter().flat_map(|a| a.get_bindings())));
+                self.expression(body);
+                self.scope.truncate(depth);
+            }
+            ExpressionBody::ArrayComp(ArrayComprehension {
+                sources,
+                projection,
+            }) => {
+                let projection = projection
+                    .iter()
+                    .map(|item| match item {
+                        ArrayItem::Single(e) | ArrayItem::Spread(e) => e,
+                    })
+                    .collect();
+                self.comprehension(sources, projection);
+            }
+            ExpressionBody::ObjectComp(ObjectComprehension {
+                sources,
+                projection,
+            }) => {
+                let projection = projection
+                    .iter()
+                    .flat_map(|prop| match prop {
+                        ObjectProperty::Single(_) => vec![],
+                        ObjectProperty::Property(Property {
+                            key: PropertyKey::Identifier(_),
+                            value,
+                        }) => vec![value],
+                        ObjectProperty::Property(Property {
+                            key: PropertyKey::Expression(key),
+                            value,
+                        }) => vec![key, value],
+                        ObjectProperty::Spread(e) => vec![e],
+                    })
+                    .collect();
+                self.comprehension(sources, projection);
+            }
+            ExpressionBody::Match(MatchExpression { subject, cases }) => {
+                self.expression(subject);
+                for case in cases {
+                    self.pattern(&case.pattern);
+
+                    let depth = self.scope.len();
+                    self.bind(case.pattern.get_bindings());
+                    if let Some(guard) = &case.guard {
+                        self.expression(guard);
+                    }
+                    self.expression(&case.body);
+                    self.scope.truncate(depth);
+                }
+            }
+            ExpressionBody::TryCatch(TryCatchExpression {
+                body,
+                pattern,
+                fallback,
+            }) => {
+                self.expression(body);
+                self.pattern(pattern);
+
+                let depth = self.scope.len();
+                self.bind(pattern.get_bindings());
+                self.expression(fallback);
+                self.scope.truncate(depth);
+            }
+            _ => visit_expression(self, expression),
+        }
+    }
+
+    // Checks the expressions within the pattern, which are evaluated in the
+    // enclosing scope.
+    fn pattern(&mut self, pattern: &Pattern) {
+        let bound: HashSet<&Identifier> = pattern.get_identifiers().collect();
+
+        for expression in pattern.get_expressions() {
+            let referenced: HashSet<&Identifier> = expression.get_identifiers().collect();
+            for id in referenced.into_iter().filter(|id| bound.contains(id)) {
+                self.warn(
+                    expression.location,
+                    LintWarningReason::SamePatternReference(id.deep_clone()),
+                );
+            }
+
+            self.expression(expression);
+        }
+    }
+
+    // Adds the identifiers to the scope. Identifiers bound more than once,
+    // e.g. by the alternatives of a pattern, only count once.
+    fn bind<'x>(&mut self, bindings: impl Iterator<Item = (&'x Identifier<'x>, Option<Location>)>) {
+        let mut added = HashSet::new();
+        for (id, location) in bindings {
+            if !added.insert(id.name.as_ref()) {
+                continue;
+            }
+            if self.scope.iter().any(|name| name == id.name.as_ref()) {
+                self.warn(location, LintWarningReason::Shadowing(id.deep_clone()));
+            }
+        }
+        self.scope.extend(added.into_iter().map(String::from));
+    }
+
+    fn unused<'x>(
+        &mut self,
+        bindings: impl Iterator<Item = (&'x Identifier<'x>, Option<Location>)>,
+        used: &HashSet<&Identifier>,
+    ) {
+        let mut reported = HashSet::new();
+        for (id, location) in bindings {
+            if id.name.starts_with('_') || used.contains(id) || !reported.insert(id) {
+                continue;
+            }
+            self.warn(location, LintWarningReason::UnusedBinding(id.deep_clone()));
+        }
+    }
+
+    // The assignments of a set see each other's bindings, just like the
+    // expressions they are local to.
+    fn assignments(&mut self, assignments: &[Assignment], body: Vec<&Expression>) {
+        let depth = self.scope.len();
+        self.bind(assignments.iter().flat_map(|a| a.pattern.get_bindings()));
+
+        for Assignment {
+            pattern,
+            expression,
+        } in assignments
+        {
+            self.pattern(pattern);
+            self.expression(expression);
+        }
+        for expression in &body {
+            self.expression(expression);
+        }
+        self.scope.truncate(depth);
+
+        let used: HashSet<&Identifier> = assignments
+            .iter()
+            .flat_map(|a| a.pattern.get_expressions().chain([&a.expression]))
+            .chain(body)
+            .flat_map(|e| e.get_identifiers())
+            .collect();
+        self.unused(
+            assignments.iter().flat_map(|a| a.pattern.get_bindings()),
+            &used,
+        );
+    }
+
+    fn comprehension(&mut self, sources: &[ComprehensionSource], projection: Vec<&Expression>) {
+        let depth = self.scope.len();
+        for source in sources {
+            self.expression(&source.collection);
+            self.pattern(&source.pattern);
+            self.bind(source.pattern.get_bindings());
+            if let Some(predicate) = &source.predicate {
+                self.expression(predicate);
+            }
+        }
+        for expression in &projection {
+            self.expression(expression);
+        }
+        self.scope.truncate(depth);
+
+        // A source's bindings can be read by its own predicate and by
+        // everything that follows it.
+        for (i, source) in sources.iter().enumerate() {
+            let later = sources[i + 1..].iter().flat_map(|s| {
+                s.pattern
+                    .get_expressions()
+                    .chain([s.collection.as_ref()])
+                    .chain(s.predicate.as_deref())
+            });
+            let used: HashSet<&Identifier> = source
+                .predicate
+                .as_deref()
+                .into_iter()
+                .chain(later)
+                .chain(projection.iter().copied())
+                .flat_map(|e| e.get_identifiers())
+                .collect();
+            self.unused(source.pattern.get_bindings(), &used);
+        }
+    }
+}
@@ -0,0 +1,297 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::literal::Literal;
+use crate::value::Value;
+use crate::value_type::ValueType;
+
+/// The statically known shape of a value.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Type {
+    /// Nothing is known about the value.
+    Any,
+    /// There is no value at all, e.g. the items of an empty array.
+    Never,
+    Null,
+    Boolean,
+    Integer,
+    Float,
+    String,
+    Type,
+    Array(Box<Type>),
+    Object(ObjectType),
+    Lambda(LambdaType),
+    Union(BTreeSet<Type>),
+}
+
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ObjectType {
+    pub fields: BTreeMap<String, Type>,
+    /// Whether the object may have further keys besides `fields`.
+    pub open: bool,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LambdaType {
+    /// `None` if not even the number of parameters is known.
+    pub parameters: Option<Vec<Type>>,
+    pub result: Box<Type>,
+}
+
+impl Type {
+    /// Combines the given types into a single one. Nested unions are
+    /// flattened and all arrays are merged into one array type.
+    pub fn union(types: impl IntoIterator<Item = Type>) -> Type {
+        let mut pending: Vec<Type> = types.into_iter().collect();
+        let mut members = BTreeSet::new();
+        let mut elements: Option<Vec<Type>> = None;
+
+        while let Some(t) = pending.pop() {
+            match t {
+                Type::Any => return Type::Any,
+                Type::Never => {}
+                Type::Union(inner) => pending.extend(inner),
+                Type::Array(element) => elements.get_or_insert_with(Vec::new).push(*element),
+                other => {
+                    members.insert(other);
+                }
+            }
+        }
+
+        if let Some(elements) = elements {
+            members.insert(Type::Array(Box::new(Type::union(elements))));
+        }
+
+        match members.len() {
+            0 => Type::Never,
+            1 => members.pop_first().unwrap_or(Type::Never),
+            _ => Type::Union(members),
+        }
+    }
+
+    pub fn of(value: &Value) -> Type {
+        match value {
+            Value::Null => Type::Null,
+            Value::String(_) => Type::String,
+            Value::Integer(_) => Type::Integer,
+            Value::Float(_) => Type::Float,
+            Value::Boolean(_) => Type::Boolean,
+            Value::Array(items) => {
+                Type::Array(Box::new(Type::union(items.iter().map(|v| Type::of(v)))))
+            }
+            Value::Object(map) => Type::Object(ObjectType {
+                fields: map
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), Type::of(v)))
+                    .collect(),
+                open: false,
+            }),
+            Value::Type(_) => Type::Type,
+            Value::Lambda(_, _, patterns, _) => Type::Lambda(LambdaType {
+                parameters: Some(vec![Type::Any; patterns.len()]),
+                result: Box::new(Type::Any),
+            }),
+        }
+    }
+
+    pub fn of_literal(literal: &Literal) -> Type {
+        match literal {
+            Literal::Null => Type::Null,
+            Literal::String(_) => Type::String,
+            Literal::Number(_) => Type::Integer,
+            Literal::Float(_) => Type::Float,
+            Literal::Boolean(_) => Type::Boolean,
+            Literal::Type(_) => Type::Type,
+        }
+    }
+
+    pub fn is_never(&self) -> bool {
+        matches!(self, Type::Never)
+    }
+
+    /// The runtime type of the values of this type, `None` for unions and
+    /// for `Any` and `Never`.
+    pub fn value_type(&self) -> Option<ValueType> {
+        Some(match self {
+            Type::Null => ValueType::Null,
+            Type::Boolean => ValueType::Boolean,
+            Type::Integer => ValueType::Integer,
+            Type::Float => ValueType::Float,
+            Type::String => ValueType::String,
+            Type::Type => ValueType::Type,
+            Type::Array(_) => ValueType::Array,
+            Type::Object(_) => ValueType::Object,
+            Type::Lambda(_) => ValueType::Lambda,
+            Type::Any | Type::Never | Type::Union(_) => return None,
+        })
+    }
+
+    /// Whether a value of this type may be of the given runtime type. This
+    /// holds vacuously for `Never`.
+    pub fn admits(&self, value_type: ValueType) -> bool {
+        match self {
+            Type::Any | Type::Never => true,
+            Type::Union(members) => members.iter().any(|m| m.admits(value_type)),
+            other => other.value_type() == Some(value_type),
+        }
+    }
+
+    /// Whether some value could be of both types.
+    pub fn overlaps(&self, other: &Type) -> bool {
+        match other {
+            Type::Any | Type::Never => true,
+            Type::Union(members) => members.iter().any(|m| self.overlaps(m)),
+            other => other.value_type().is_none_or(|t| self.admits(t)),
+        }
+    }
+
+    /// The part of this type that is of the given runtime type.
+    pub fn restrict(&self, value_type: ValueType) -> Type {
+        match self {
+            Type::Any => Type::from(value_type),
+            Type::Union(members) => Type::union(members.iter().map(|m| m.restrict(value_type))),
+            other if other.value_type() == Some(value_type) => other.clone(),
+            _ => Type::Never,
+        }
+    }
+
+    /// The part of this type that is not of the given runtime type.
+    pub fn without(&self, value_type: ValueType) -> Type {
+        match self {
+            Type::Union(members) => Type::union(members.iter().map(|m| m.without(value_type))),
+            other if other.value_type() == Some(value_type) => Type::Never,
+            other => other.clone(),
+        }
+    }
+
+    /// The type of the items of the arrays of this type.
+    pub fn element(&self) -> Type {
+        match self {
+            Type::Any | Type::Never => self.clone(),
+            Type::Array(element) => element.as_ref().clone(),
+            Type::Union(members) => Type::union(members.iter().map(|m| m.element())),
+            _ => Type::Never,
+        }
+    }
+
+    /// The type stored under `key` in the objects of this type, `None` if
+    /// none of them can have the key.
+    pub fn field(&self, key: &str) -> Option<Type> {
+        match self {
+            Type::Any | Type::Never => Some(self.clone()),
+            Type::Object(object) => match object.fields.get(key) {
+                Some(t) => Some(t.clone()),
+                None if object.open => Some(Type::Any),
+                None => None,
+            },
+            Type::Union(members) => {
+                let found: Vec<Type> = members.iter().filter_map(|m| m.field(key)).collect();
+                if found.is_empty() {
+                    None
+                } else {
+                    Some(Type::union(found))
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// The objects of this type with the given keys removed.
+    pub fn without_fields<'k>(&self, keys: impl Iterator<Item = &'k str> + Clone) -> Type {
+        match self {
+            Type::Object(object) => Type::Object(ObjectType {
+                fields: object
+                    .fields
+                    .iter()
+                    .filter(|(k, _)| !keys.clone().any(|key| key == k.as_str()))
+                    .map(|(k, t)| (k.clone(), t.clone()))
+                    .collect(),
+                open: object.open,
+            }),
+            Type::Union(members) => {
+                Type::union(members.iter().map(|m| m.without_fields(keys.clone())))
+            }
+            Type::Any => Type::from(ValueType::Object),
+            other => other.clone(),
+        }
+    }
+}
+
+impl From<ValueType> for Type {
+    fn from(value_type: ValueType) -> Self {
+        match value_type {
+            ValueType::Null => Type::Null,
+            ValueType::String => Type::String,
+            ValueType::Integer => Type::Integer,
+            ValueType::Float => Type::Float,
+            ValueType::Boolean => Type::Boolean,
+            ValueType::Array => Type::Array(Box::new(Type::Any)),
+            ValueType::Object => Type::Object(ObjectType {
+                fields: BTreeMap::new(),
+                open: true,
+            }),
+            ValueType::Type => Type::Type,
+            ValueType::Lambda => Type::Lambda(LambdaType {
+                parameters: None,
+                result: Box::new(Type::Any),
+            }),
+        }
+    }
+}
+
+impl std::fmt::Display for Type {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Type::Any => write!(f, "Any"),
+            Type::Never => write!(f, "Never"),
+            Type::Null => write!(f, "Null"),
+            Type::Boolean => write!(f, "Boolean"),
+            Type::Integer => write!(f, "Integer"),
+            Type::Float => write!(f, "Float"),
+            Type::String => write!(f, "String"),
+            Type::Type => write!(f, "Type"),
+            Type::Array(element) => write!(f, "[{element}]"),
+            Type::Object(ObjectType { fields, open }) => {
+                write!(f, "{{")?;
+                for (i, (key, t)) in fields.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{key}: {t}")?;
+                }
+                match (open, fields.is_empty()) {
+                    (true, true) => write!(f, "..")?,
+                    (true, false) => write!(f, ", ..")?,
+                    (false, _) => {}
+                }
+                write!(f, "}}")
+            }
+            Type::Lambda(LambdaType { parameters, result }) => {
+                write!(f, "fn(")?;
+                match parameters {
+                    Some(parameters) => {
+                        for (i, p) in parameters.iter().enumerate() {
+                            if i > 0 {
+                                write!(f, ", ")?;
+                            }
+                            write!(f, "{p}")?;
+                        }
+                    }
+                    None => write!(f, "..")?,
+                }
+                write!(f, ") -> {result}")
+            }
+            Type::Union(members) => {
+                for (i, m) in members.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " | ")?;
+                    }
+                    match m {
+                        Type::Lambda(_) => write!(f, "({m})")?,
+                        _ => write!(f, "{m}")?,
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+}
@@ -0,0 +1,1137 @@
+use std::collections::{BTreeMap, HashSet};
+
+use itertools::Itertools;
+
+use crate::identifier::Identifier;
+use crate::literal::Literal;
+use crate::runtime::env::Environment;
+use crate::runtime::native::{NativeRegistry, DEFAULT_NATIVES};
+use crate::syntax::assignment::{Assignment, AssignmentSet};
+use crate::syntax::expression::{
+    ArrayItem, BinaryExpression, BinaryOperator, CallExpression, ComprehensionSource, Expression,
+    ExpressionBody, IfElseExpression, LambdaAbstraction, LambdaApplication, LetExpression,
+    LogicalExpression, MatchExpression, MemberExpression, ObjectProperty, PipeExpression, Property,
+    PropertyKey, RangeExpression, SliceExpression, StringTemplate, TryCatchExpression,
+    UnaryExpression, UnaryOperator,
+};
+use crate::syntax::location::Location;
+use crate::syntax::pattern::{
+    ArrayPatternItem, ObjectPropertyPattern, Pattern, PatternBody, PropertyPattern, RangePattern,
+    RegexPattern, Rest, StringPattern,
+};
+use crate::topology::TopologyError;
+use crate::value_type::ValueType;
+
+use super::exhaustiveness::{check_cases, MatchWarning};
+use super::types::{LambdaType, ObjectType, Type};
+
+pub type TypeBindings<'s> = BTreeMap<Identifier<'s>, Type>;
+
+#[derive(Debug, Clone)]
+pub struct TypeError<'s> {
+    pub reason: TypeErrorReason<'s>,
+    pub location: Option<Location>,
+}
+
+#[derive(Debug, Clone)]
+pub enum TypeErrorReason<'s> {
+    Mismatch { expected: Type, actual: Type },
+    UnknownIdentifier(Identifier<'s>),
+    UnknownFunction(Identifier<'s>),
+    ArityMismatch(usize, usize),
+    MissingKey { key: String, actual: Type },
+    TopologyError(HashSet<Identifier<'s>>),
+}
+
+impl std::fmt::Display for TypeErrorReason<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TypeErrorReason::Mismatch { expected, actual } => write!(
+                f,
+                "Expected a value of type {} but found {}.",
+                expected, actual
+            ),
+            TypeErrorReason::UnknownIdentifier(identifier) => {
+                write!(f, "Unknown identifier {}.", identifier)
+            }
+            TypeErrorReason::UnknownFunction(fun) => {
+                write!(f, "Function of name {} does not exist.", fun)
+            }
+            TypeErrorReason::ArityMismatch(expected, actual) => {
+                write!(f, "Expected {} arguments but got {}.", expected, actual)
+            }
+            TypeErrorReason::MissingKey { key, actual } => {
+                write!(f, "Object of type {} has no key {}.", actual, key)
+            }
+            TypeErrorReason::TopologyError(cycle) => write!(
+                f,
+                "These definitions cyclicly depend on each other: {}",
+                cycle.iter().map(|k| k.name.as_ref()).sorted().join(", ")
+            ),
+        }
+    }
+}
+
+/// Infers the types of expressions, patterns and assignments without
+/// evaluating them. An error is only reported if the checked operation fails
+/// for every value of the inferred types, so unions and `Any` never cause
+/// false alarms.
+pub struct TypeChecker<'e, 's> {
+    scope: TypeBindings<'s>,
+    natives: &'e NativeRegistry,
+}
+
+impl<'e, 's> TypeChecker<'e, 's> {
+    pub fn new(env: &Environment) -> Self {
+        Self {
+            scope: env
+                .bindings
+                .iter()
+                .map(|(id, value)| (id.deep_clone(), Type::of(value)))
+                .collect(),
+            natives: &DEFAULT_NATIVES,
+        }
+    }
+
+    pub fn with_natives(self, natives: &'e NativeRegistry) -> Self {
+        Self { natives, ..self }
+    }
+
+    pub fn with_bindings(mut self, mut bindings: TypeBindings<'s>) -> Self {
+        self.scope.append(&mut bindings);
+        self
+    }
+
+    pub fn check_expression(
+        &self,
+        expression: &Expression<'s>,
+    ) -> Result<Type, Vec<TypeError<'s>>> {
+        let mut inference = Inference::new(self.natives);
+        let t = inference.infer(&self.scope, expression);
+        inference.finish(t)
+    }
+
+    /// The type of the values the pattern can match and the types of the
+    /// identifiers it binds.
+    pub fn check_pattern(
+        &self,
+        pattern: &Pattern<'s>,
+    ) -> Result<(Type, TypeBindings<'s>), Vec<TypeError<'s>>> {
+        let mut inference = Inference::new(self.natives);
+        let mut bindings = TypeBindings::new();
+        let t = inference.bind_pattern(&self.scope, &mut bindings, pattern, &Type::Any, true);
+        inference.finish((t, bindings))
+    }
+
+    pub fn check_assignment_set(
+        &self,
+        assignments: &AssignmentSet<'s, 's>,
+    ) -> Result<TypeBindings<'s>, Vec<TypeError<'s>>> {
+        let mut inference = Inference::new(self.natives);
+        let bindings = inference.infer_assignments(&self.scope, assignments);
+        inference.finish(bindings)
+    }
+
+    /// The warnings about unreachable cases and non-exhaustive matches of
+    /// all match expressions within the expression.
+    pub fn check_matches(&self, expression: &Expression<'s>) -> Vec<MatchWarning<'s>> {
+        let mut inference = Inference::new(self.natives);
+        inference.infer(&self.scope, expression);
+        inference.warnings
+    }
+
+    pub fn check_assignment_matches(
+        &self,
+        assignments: &AssignmentSet<'s, 's>,
+    ) -> Vec<MatchWarning<'s>> {
+        let mut inference = Inference::new(self.natives);
+        inference.infer_assignments(&self.scope, assignments);
+        inference.warnings
+    }
+}
+
+struct Inference<'e, 's> {
+    natives: &'e NativeRegistry,
+    errors: Vec<TypeError<'s>>,
+    warnings: Vec<MatchWarning<'s>>,
+}
+
+impl<'e, 's> Inference<'e, 's> {
+    fn new(natives: &'e NativeRegistry) -> Self {
+        Self {
+            natives,
+            errors: vec![],
+            warnings: vec![],
+        }
+    }
+
+    fn finish<T>(self, result: T) -> Result<T, Vec<TypeError<'s>>> {
+        if self.errors.is_empty() {
+            Ok(result)
+        } else {
+            Err(self.errors)
+        }
+    }
+
+    // Records the error and continues with `Any`, so a single mistake does
+    // not cause a cascade of follow-up errors.
+    fn fail(&mut self, location: Option<Location>, reason: TypeErrorReason<'s>) -> Type {
+        self.errors.push(TypeError { reason, location });
+        Type::Any
+    }
+
+    fn expect(
+        &mut self,
+        location: Option<Location>,
+        actual: &Type,
+        expected: &[ValueType],
+    ) -> bool {
+        if expected.iter().any(|t| actual.admits(*t)) {
+            return true;
+        }
+
+        self.fail(
+            location,
+            TypeErrorReason::Mismatch {
+                expected: Type::union(expected.iter().map(|t| Type::from(*t))),
+                actual: actual.clone(),
+            },
+        );
+        false
+    }
+
+    fn infer(&mut self, scope: &TypeBindings<'s>, expression: &Expression<'s>) -> Type {
+        let location = expression.location;
+
+        match &expression.body {
+            ExpressionBody::Literal(l) => Type::of_literal(l),
+            ExpressionBody::Identifier(id) => match scope.get(id) {
+                Some(t) => t.clone(),
+                None => self.fail(location, TypeErrorReason::UnknownIdentifier(id.clone())),
+            },
+            ExpressionBody::Array(items) => Type::Array(Box::new(self.infer_items(scope, items))),
+            ExpressionBody::Object(props) => self.infer_object(scope, location, props),
+            ExpressionBody::Binary(binary) => self.infer_binary(scope, binary),
+            ExpressionBody::Logical(LogicalExpression { left, right, .. }) => {
+                for operand in [left, right] {
+                    let t = self.infer(scope, operand);
+                    self.expect(operand.location, &t, &[ValueType::Boolean]);
+                }
+                Type::Boolean
+            }
+            ExpressionBody::Member(member) => self.infer_member(scope, member),
+            ExpressionBody::Slice(SliceExpression { object, start, end }) => {
+                for bound in start.iter().chain(end.iter()) {
+                    let t = self.infer(scope, bound);
+                    self.expect(bound.location, &t, &[ValueType::Integer]);
+                }
+                let t = self.infer(scope, object);
+                if !self.expect(object.location, &t, &[ValueType::Array, ValueType::String]) {
+                    return Type::Any;
+                }
+                Type::union([t.restrict(ValueType::Array), t.restrict(ValueType::String)])
+            }
+            ExpressionBody::Range(RangeExpression { start, end }) => {
+                for bound in [start, end] {
+                    let t = self.infer(scope, bound);
+                    self.expect(bound.location, &t, &[ValueType::Integer]);
+                }
+                Type::Array(Box::new(Type::Integer))
+            }
+            ExpressionBody::Unary(UnaryExpression { operator, argument }) => {
+                let t = self.infer(scope, argument);
+                match operator {
+                    UnaryOperator::Minus | UnaryOperator::Plus => {
+                        let numeric = [ValueType::Integer, ValueType::Float];
+                        if !self.expect(argument.location, &t, &numeric) {
+                            return Type::Any;
+                        }
+                        Type::union(numeric.map(|n| t.restrict(n)))
+                    }
+                    UnaryOperator::Not => {
+                        self.expect(argument.location, &t, &[ValueType::Boolean]);
+                        Type::Boolean
+                    }
+                }
+            }
+            ExpressionBody::Call(CallExpression {
+                function,
+                arguments,
+            }) => {
+                let arguments = arguments
+                    .iter()
+                    .map(|a| (self.infer(scope, a), a.location))
+                    .collect::<Vec<_>>();
+                self.infer_call(location, function, &arguments)
+            }
+            ExpressionBody::Template(StringTemplate { parts, .. }) => {
+                for part in parts {
+                    self.infer(scope, &part.dynamic_end);
+                }
+                Type::String
+            }
+            ExpressionBody::Abstraction(lambda) => self.infer_lambda(scope, lambda),
+            ExpressionBody::Application(LambdaApplication { lambda, parameters }) => {
+                let callee = self.infer(scope, lambda);
+                let arguments = parameters
+                    .iter()
+                    .map(|p| (self.infer(scope, p), p.location))
+                    .collect::<Vec<_>>();
+                self.apply(location, &callee, &arguments)
+            }
+            ExpressionBody::ArrayComp(comp) => {
+                let Some(local) = self.infer_sources(scope, &comp.sources) else {
+                    return Type::Array(Box::new(Type::Never));
+                };
+                Type::Array(Box::new(self.infer_items(&local, &comp.projection)))
+            }
+            ExpressionBody::ObjectComp(comp) => {
+                if let Some(local) = self.infer_sources(scope, &comp.sources) {
+                    self.infer_object(&local, location, &comp.projection);
+                }
+                // The keys depend on how often the sources produce a match.
+                Type::from(ValueType::Object)
+            }
+            ExpressionBody::Condition(IfElseExpression {
+                condition,
+                true_branch,
+                false_branch,
+            }) => {
+                let t = self.infer(scope, condition);
+                self.expect(condition.location, &t, &[ValueType::Boolean]);
+                let true_type = self.infer(scope, true_branch);
+                let false_type = match false_branch {
+                    Some(fb) => self.infer(scope, fb),
+                    None => Type::Null,
+                };
+                Type::union([true_type, false_type])
+            }
+            ExpressionBody::Match(MatchExpression { subject, cases }) => {
+                let subject_type = self.infer(scope, subject);
+                self.warnings
+                    .extend(check_cases(&subject_type, cases, location));
+                let mut results = Vec::with_capacity(cases.len());
+                for case in cases {
+                    let mut bindings = TypeBindings::new();
+                    let matched = self.bind_pattern(
+                        scope,
+                        &mut bindings,
+                        &case.pattern,
+                        &subject_type,
+                        false,
+                    );
+                    if matched.is_never() {
+                        continue;
+                    }
+                    let local = extend(scope, bindings);
+                    if let Some(guard) = &case.guard {
+                        let t = self.infer(&local, guard);
+                        self.expect(guard.location, &t, &[ValueType::Boolean]);
+                    }
+                    results.push(self.infer(&local, &case.body));
+                }
+                Type::union(results)
+            }
+            ExpressionBody::TryCatch(TryCatchExpression {
+                body,
+                pattern,
+                fallback,
+            }) => {
+                // Whatever fails inside the body is caught at runtime.
+                let reported = self.errors.len();
+                let body_type = self.infer(scope, body);
+                self.errors.truncate(reported);
+
+                let mut bindings = TypeBindings::new();
+                self.bind_pattern(scope, &mut bindings, pattern, &error_type(), false);
+                let fallback_type = self.infer(&extend(scope, bindings), fallback);
+                Type::union([body_type, fallback_type])
+            }
+            ExpressionBody::Let(LetExpression { assignments, body }) => {
+                let bindings = self.infer_assignments(scope, assignments);
+                self.infer(&extend(scope, bindings), body)
+            }
+            ExpressionBody::Pipe(PipeExpression { value, function }) => {
+                let value = (self.infer(scope, value), value.location);
+                match &function.body {
+                    ExpressionBody::Identifier(name) if !scope.contains_key(name) => {
+                        self.infer_call(location, name, &[value])
+                    }
+                    ExpressionBody::Call(CallExpression {
+                        function,
+                        arguments,
+                    }) => {
+                        let arguments = std::iter::once(value)
+                            .chain(arguments.iter().map(|a| (self.infer(scope, a), a.location)))
+                            .collect::<Vec<_>>();
+                        self.infer_call(location, function, &arguments)
+                    }
+                    ExpressionBody::Application(LambdaApplication { lambda, parameters }) => {
+                        let callee = self.infer(scope, lambda);
+                        let arguments = std::iter::once(value)
+                            .chain(
+                                parameters
+                                    .iter()
+                                    .map(|p| (self.infer(scope, p), p.location)),
+                            )
+                            .collect::<Vec<_>>();
+                        self.apply(location, &callee, &arguments)
+                    }
+                    _ => {
+                        let callee = self.infer(scope, function);
+                        self.apply(location, &callee, &[value])
+                    }
+                }
+            }
+        }
+    }
+
+    fn infer_items(&mut self, scope: &TypeBindings<'s>, items: &[ArrayItem<'s>]) -> Type {
+        let mut elements = Vec::with_capacity(items.len());
+        for item in items {
+            match item {
+                ArrayItem::Single(e) => elements.push(self.infer(scope, e)),
+                ArrayItem::Spread(e) => {
+                    let t = self.infer(scope, e);
+                    if self.expect(e.location, &t, &[ValueType::Array]) {
+                        elements.push(t.element());
+                    }
+                }
+            }
+        }
+        Type::union(elements)
+    }
+
+    fn infer_object(
+        &mut self,
+        scope: &TypeBindings<'s>,
+        location: Option<Location>,
+        props: &[ObjectProperty<'s>],
+    ) -> Type {
+        let mut object = ObjectType::default();
+        for prop in props {
+            match prop {
+                ObjectProperty::Single(id) => {
+                    let t = match scope.get(id) {
+                        Some(t) => t.clone(),
+                        None => self.fail(location, TypeErrorReason::UnknownIdentifier(id.clone())),
+                    };
+                    object.fields.insert(id.name.to_string(), t);
+                }
+                ObjectProperty::Property(Property { key, value }) => {
+                    let t = self.infer(scope, value);
+                    match key {
+                        PropertyKey::Identifier(id) => {
+                            object.fields.insert(id.name.to_string(), t);
+                        }
+                        PropertyKey::Expression(e) => {
+                            let key_type = self.infer(scope, e);
+                            self.expect(e.location, &key_type, &[ValueType::String]);
+                            object.open = true;
+                        }
+                    }
+                }
+                ObjectProperty::Spread(e) => {
+                    let t = self.infer(scope, e);
+                    match t {
+                        Type::Object(spread) => {
+                            object.fields.extend(spread.fields);
+                            object.open |= spread.open;
+                        }
+                        other => {
+                            self.expect(e.location, &other, &[ValueType::Object]);
+                            object.open = true;
+                        }
+                    }
+                }
+            }
+        }
+        Type::Object(object)
+    }
+
+    fn infer_binary(&mut self, scope: &TypeBindings<'s>, binary: &BinaryExpression<'s>) -> Type {
+        let BinaryExpression {
+            operator,
+            left,
+            right,
+        } = binary;
+        let left_type = self.infer(scope, left);
+        let right_type = self.infer(scope, right);
+        let l = (&left_type, left.location);
+        let r = (&right_type, right.location);
+
+        match operator {
+            BinaryOperator::StrictEqual | BinaryOperator::StrictNotEqual => Type::Boolean,
+            BinaryOperator::LessThan
+            | BinaryOperator::GreaterThan
+            | BinaryOperator::LessThanEqual
+            | BinaryOperator::GreaterThanEqual => {
+                self.expect_same_kind(l, r, &[NUMERIC, &[ValueType::String], &[ValueType::Array]]);
+                Type::Boolean
+            }
+            BinaryOperator::Plus => {
+                let kinds = [
+                    NUMERIC,
+                    &[ValueType::String],
+                    &[ValueType::Array],
+                    &[ValueType::Object],
+                ];
+                let viable = self.expect_same_kind(l, r, &kinds);
+                if viable.is_empty() {
+                    return Type::Any;
+                }
+                Type::union(viable.into_iter().map(|kind| match kinds[kind] {
+                    [ValueType::String] => Type::String,
+                    [ValueType::Array] => Type::Array(Box::new(Type::union([
+                        left_type.element(),
+                        right_type.element(),
+                    ]))),
+                    [ValueType::Object] => merge_objects(
+                        &left_type.restrict(ValueType::Object),
+                        &right_type.restrict(ValueType::Object),
+                    ),
+                    _ => numeric_result(&left_type, &right_type),
+                }))
+            }
+            BinaryOperator::Minus
+            | BinaryOperator::Times
+            | BinaryOperator::Over
+            | BinaryOperator::Mod
+            | BinaryOperator::PowerOf => {
+                if self.expect_same_kind(l, r, &[NUMERIC]).is_empty() {
+                    return Type::Any;
+                }
+                numeric_result(&left_type, &right_type)
+            }
+            BinaryOperator::In => {
+                let collections = [ValueType::Object, ValueType::Array, ValueType::String];
+                if self.expect(right.location, &right_type, &collections)
+                    && !right_type.admits(ValueType::Array)
+                {
+                    self.expect(left.location, &left_type, &[ValueType::String]);
+                }
+                Type::Boolean
+            }
+            BinaryOperator::Is => {
+                self.expect(right.location, &right_type, &[ValueType::Type]);
+                Type::Boolean
+            }
+            BinaryOperator::Cast => {
+                self.expect(right.location, &right_type, &[ValueType::Type]);
+                match &right.body {
+                    ExpressionBody::Literal(Literal::Type(t)) => Type::from(*t),
+                    _ => Type::Any,
+                }
+            }
+            BinaryOperator::NullCoalesce => {
+                Type::union([left_type.without(ValueType::Null), right_type])
+            }
+        }
+    }
+
+    // Both operands have to be of one of the same groups of runtime types.
+    // Returns the indices of the groups that remain possible.
+    fn expect_same_kind(
+        &mut self,
+        (left, left_location): (&Type, Option<Location>),
+        (right, right_location): (&Type, Option<Location>),
+        kinds: &[&[ValueType]],
+    ) -> Vec<usize> {
+        let all = kinds.concat();
+        if !self.expect(left_location, left, &all) {
+            return vec![];
+        }
+
+        let viable: Vec<usize> = kinds
+            .iter()
+            .enumerate()
+            .filter(|(_, kind)| {
+                kind.iter().any(|k| left.admits(*k)) && kind.iter().any(|k| right.admits(*k))
+            })
+            .map(|(i, _)| i)
+            .collect();
+
+        if viable.is_empty() {
+            let expected: Vec<ValueType> = kinds
+                .iter()
+                .filter(|kind| kind.iter().any(|k| left.admits(*k)))
+                .flat_map(|kind| kind.iter().copied())
+                .collect();
+            self.expect(right_location, right, &expected);
+        }
+
+        viable
+    }
+
+    fn infer_member(&mut self, scope: &TypeBindings<'s>, member: &MemberExpression<'s>) -> Type {
+        let MemberExpression {
+            object,
+            property,
+            optional,
+        } = member;
+        let object_type = self.infer(scope, object);
+        let property_type = self.infer(scope, property);
+        let subject = if *optional {
+            object_type.without(ValueType::Null)
+        } else {
+            object_type
+        };
+
+        let collections = [ValueType::Object, ValueType::Array, ValueType::String];
+        if !self.expect(object.location, &subject, &collections) {
+            return Type::Any;
+        }
+
+        let mut keys = vec![];
+        if subject.admits(ValueType::Object) {
+            keys.push(ValueType::String);
+        }
+        if subject.admits(ValueType::Array) || subject.admits(ValueType::String) {
+            keys.push(ValueType::Integer);
+        }
+        if !self.expect(property.location, &property_type, &keys) {
+            return Type::Any;
+        }
+
+        let mut results = vec![];
+        if property_type.admits(ValueType::String) && subject.admits(ValueType::Object) {
+            let objects = subject.restrict(ValueType::Object);
+            match &property.body {
+                ExpressionBody::Literal(Literal::String(key)) => match objects.field(key) {
+                    Some(t) => results.push(t),
+                    None if *optional => {}
+                    None => {
+                        return self.fail(
+                            property.location,
+                            TypeErrorReason::MissingKey {
+                                key: key.to_string(),
+                                actual: objects,
+                            },
+                        )
+                    }
+                },
+                _ => results.push(Type::Any),
+            }
+        }
+        if property_type.admits(ValueType::Integer) {
+            if subject.admits(ValueType::Array) {
+                results.push(subject.restrict(ValueType::Array).element());
+            }
+            if subject.admits(ValueType::String) {
+                results.push(Type::String);
+            }
+        }
+        if *optional {
+            results.push(Type::Null);
+        }
+
+        Type::union(results)
+    }
+
+    fn infer_call(
+        &mut self,
+        location: Option<Location>,
+        function: &Identifier<'s>,
+        arguments: &[(Type, Option<Location>)],
+    ) -> Type {
+        let Some(native) = self.natives.get(function.name.as_ref()) else {
+            return self.fail(location, TypeErrorReason::UnknownFunction(function.clone()));
+        };
+        let signature = native.signature();
+
+        if signature.parameters.len() != arguments.len() {
+            return self.fail(
+                location,
+                TypeErrorReason::ArityMismatch(signature.parameters.len(), arguments.len()),
+            );
+        }
+
+        for (expected, (actual, argument_location)) in
+            std::iter::zip(&signature.parameters, arguments)
+        {
+            if let Some(expected) = expected {
+                self.expect(*argument_location, actual, &[*expected]);
+            }
+        }
+
+        signature.result.map_or(Type::Any, Type::from)
+    }
+
+    fn infer_lambda(&mut self, scope: &TypeBindings<'s>, lambda: &LambdaAbstraction<'s>) -> Type {
+        let mut bindings = TypeBindings::new();
+        let parameters: Vec<Type> = lambda
+            .arguments
+            .iter()
+            .map(|p| self.bind_pattern(scope, &mut bindings, p, &Type::Any, true))
+            .collect();
+
+        let mut local = scope.clone();
+        if let Some(name) = &lambda.name {
+            local.insert(
+                name.clone(),
+                Type::Lambda(LambdaType {
+                    parameters: Some(parameters.clone()),
+                    result: Box::new(Type::Any),
+                }),
+            );
+        }
+        let result = self.infer(&extend(&local, bindings), &lambda.body);
+
+        Type::Lambda(LambdaType {
+            parameters: Some(parameters),
+            result: Box::new(result),
+        })
+    }
+
+    fn apply(
+        &mut self,
+        location: Option<Location>,
+        callee: &Type,
+        arguments: &[(Type, Option<Location>)],
+    ) -> Type {
+        if !self.expect(location, callee, &[ValueType::Lambda]) {
+            return Type::Any;
+        }
+
+        let Type::Lambda(LambdaType { parameters, result }) = callee else {
+            return match callee.restrict(ValueType::Lambda) {
+                Type::Union(lambdas) => Type::union(lambdas.into_iter().map(|l| match l {
+                    Type::Lambda(LambdaType { result, .. }) => *result,
+                    _ => Type::Any,
+                })),
+                _ => Type::Any,
+            };
+        };
+
+        if let Some(parameters) = parameters {
+            if parameters.len() != arguments.len() {
+                return self.fail(
+                    location,
+                    TypeErrorReason::ArityMismatch(parameters.len(), arguments.len()),
+                );
+            }
+
+            for (expected, (actual, argument_location)) in std::iter::zip(parameters, arguments) {
+                if !expected.overlaps(actual) {
+                    self.fail(
+                        *argument_location,
+                        TypeErrorReason::Mismatch {
+                            expected: expected.clone(),
+                            actual: actual.clone(),
+                        },
+                    );
+                }
+            }
+        }
+
+        result.as_ref().clone()
+    }
+
+    // The scope of the projection, `None` if the sources can never produce
+    // a match so the projection is never evaluated.
+    fn infer_sources(
+        &mut self,
+        scope: &TypeBindings<'s>,
+        sources: &[ComprehensionSource<'s>],
+    ) -> Option<TypeBindings<'s>> {
+        let mut local = scope.clone();
+        for source in sources {
+            let collection = self.infer(&local, &source.collection);
+            let element =
+                if self.expect(source.collection.location, &collection, &[ValueType::Array]) {
+                    collection.restrict(ValueType::Array).element()
+                } else {
+                    Type::Any
+                };
+
+            let mut bindings = TypeBindings::new();
+            let matched = self.bind_pattern(
+                &local,
+                &mut bindings,
+                &source.pattern,
+                &element,
+                source.strong_pattern,
+            );
+            if matched.is_never() {
+                return None;
+            }
+            local.append(&mut bindings);
+
+            if let Some(predicate) = &source.predicate {
+                let t = self.infer(&local, predicate);
+                self.expect(predicate.location, &t, &[ValueType::Boolean]);
+            }
+        }
+        Some(local)
+    }
+
+    fn infer_assignments(
+        &mut self,
+        scope: &TypeBindings<'s>,
+        assignments: &AssignmentSet<'s, 's>,
+    ) -> TypeBindings<'s> {
+        let mut local = scope.clone();
+        let sorted = match assignments.clone().sort_topological() {
+            Ok(sorted) => sorted,
+            Err(TopologyError::Cycle(cycle)) => {
+                self.fail(None, TypeErrorReason::TopologyError(cycle));
+                for Assignment { pattern, .. } in &assignments.assignments {
+                    for id in pattern.get_identifiers() {
+                        local.insert(id.deep_clone(), Type::Any);
+                    }
+                }
+                assignments.clone()
+            }
+        };
+
+        let mut result = TypeBindings::new();
+        for Assignment {
+            pattern,
+            expression,
+        } in &sorted.assignments
+        {
+            let t = self.infer(&local, expression);
+            let mut bindings = TypeBindings::new();
+            self.bind_pattern(&local, &mut bindings, pattern, &t, true);
+            local.extend(bindings.clone());
+            result.append(&mut bindings);
+        }
+        result
+    }
+
+    /// Binds the identifiers of the pattern matched against a value of type
+    /// `subject` and returns the type of the values it matches. If it can not
+    /// match at all, `Never` is returned and, if `strict`, an error reported.
+    fn bind_pattern(
+        &mut self,
+        scope: &TypeBindings<'s>,
+        bindings: &mut TypeBindings<'s>,
+        pattern: &Pattern<'s>,
+        subject: &Type,
+        strict: bool,
+    ) -> Type {
+        let location = pattern.location;
+
+        match &pattern.body {
+            PatternBody::Discard => subject.clone(),
+            PatternBody::Identifier(id) => {
+                bindings.insert(id.clone(), subject.clone());
+                subject.clone()
+            }
+            PatternBody::Capture(id, inner) => {
+                let t = self.bind_pattern(scope, bindings, inner, subject, strict);
+                bindings.insert(id.clone(), t.clone());
+                t
+            }
+            PatternBody::TypedDiscard(t) => self.narrow(location, subject, &[*t], strict),
+            PatternBody::TypedIdentifier(id, t) => {
+                let t = self.narrow(location, subject, &[*t], strict);
+                bindings.insert(id.clone(), t.clone());
+                t
+            }
+            PatternBody::Literal(l) => {
+                let kinds: Vec<ValueType> = Type::of_literal(l).value_type().into_iter().collect();
+                self.narrow(location, subject, &kinds, strict)
+            }
+            PatternBody::PinnedExpression(e) => {
+                let t = self.infer(scope, e);
+                if !subject.overlaps(&t) {
+                    return self.mismatch(location, &t, subject, strict);
+                }
+                t
+            }
+            PatternBody::Range(range) => {
+                let RangePattern { start, end, .. } = range.as_ref();
+                let kinds = [bound_kinds(start), bound_kinds(end)].concat();
+                self.narrow(location, subject, &kinds, strict)
+            }
+            PatternBody::Relational(_, literal) => {
+                self.narrow(location, subject, &bound_kinds(literal), strict)
+            }
+            PatternBody::Regex(RegexPattern { captures, .. }) => {
+                // Groups that did not take part in the match are bound to null.
+                for id in captures {
+                    bindings.insert(id.clone(), Type::union([Type::String, Type::Null]));
+                }
+                self.narrow(location, subject, &[ValueType::String], strict)
+            }
+            PatternBody::String(
+                StringPattern::Prefix(_, rest) | StringPattern::Suffix(rest, _),
+            ) => {
+                let t = self.narrow(location, subject, &[ValueType::String], strict);
+                let rest_type = self.bind_pattern(scope, bindings, rest, &t, strict);
+                if rest_type.is_never() {
+                    return Type::Never;
+                }
+                t
+            }
+            PatternBody::Alternative(alternatives) => {
+                let mut alternative_bindings = Vec::with_capacity(alternatives.len());
+                let mut types = Vec::with_capacity(alternatives.len());
+                for alternative in alternatives {
+                    let mut b = TypeBindings::new();
+                    types.push(self.bind_pattern(scope, &mut b, alternative, subject, false));
+                    alternative_bindings.push(b);
+                }
+
+                for id in alternative_bindings.iter().flat_map(|b| b.keys()).unique() {
+                    let t = Type::union(
+                        alternative_bindings
+                            .iter()
+                            .filter_map(|b| b.get(id).cloned()),
+                    );
+                    bindings.insert(id.clone(), t);
+                }
+
+                let t = Type::union(types);
+                if t.is_never() && !subject.is_never() {
+                    // Errors inside the alternatives have already been reported.
+                    let mut accepted = Inference::new(self.natives);
+                    let expected = Type::union(alternatives.iter().map(|a| {
+                        accepted.bind_pattern(scope, &mut TypeBindings::new(), a, &Type::Any, false)
+                    }));
+                    return self.mismatch(location, &expected, subject, strict);
+                }
+                t
+            }
+            PatternBody::Array(items, rest, suffix) => {
+                let t = self.narrow(location, subject, &[ValueType::Array], strict);
+                let element = t.element();
+                let mut matched = true;
+                let mut elements = vec![];
+                for ArrayPatternItem::Pattern(item) in items.iter().chain(suffix.iter()) {
+                    let item_type = self.bind_pattern(scope, bindings, item, &element, strict);
+                    matched &= !item_type.is_never() || element.is_never();
+                    elements.push(item_type);
+                }
+                match rest {
+                    Rest::Exact => {}
+                    Rest::Discard => elements.push(element),
+                    Rest::Collect(p) => {
+                        let rest_type = Type::Array(Box::new(element.clone()));
+                        matched &= !self
+                            .bind_pattern(scope, bindings, p, &rest_type, strict)
+                            .is_never();
+                        elements.push(element);
+                    }
+                }
+
+                if t.is_never() || !matched {
+                    return Type::Never;
+                }
+                Type::Array(Box::new(Type::union(elements)))
+            }
+            PatternBody::Object(props, rest) => {
+                self.bind_object_pattern(scope, bindings, pattern, props, rest, subject, strict)
+            }
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn bind_object_pattern(
+        &mut self,
+        scope: &TypeBindings<'s>,
+        bindings: &mut TypeBindings<'s>,
+        pattern: &Pattern<'s>,
+        props: &[ObjectPropertyPattern<'s>],
+        rest: &Rest<'s>,
+        subject: &Type,
+        strict: bool,
+    ) -> Type {
+        let objects = self.narrow(pattern.location, subject, &[ValueType::Object], strict);
+        let mut matched = true;
+        let mut result = ObjectType {
+            fields: BTreeMap::new(),
+            open: !matches!(rest, Rest::Exact),
+        };
+
+        for prop in props {
+            let (key, value_pattern, missing) = match prop {
+                ObjectPropertyPattern::Single(id) => (Some(id.name.to_string()), None, None),
+                ObjectPropertyPattern::Optional(id) => {
+                    (Some(id.name.to_string()), None, Some(Type::Null))
+                }
+                ObjectPropertyPattern::Default(id, default) => {
+                    let default_type = self.infer(scope, default);
+                    (Some(id.name.to_string()), None, Some(default_type))
+                }
+                ObjectPropertyPattern::Match(PropertyPattern {
+                    key,
+                    value,
+                    default,
+                }) => {
+                    let key = match key {
+                        PropertyKey::Identifier(id) => Some(id.name.to_string()),
+                        PropertyKey::Expression(e) => {
+                            let key_type = self.infer(scope, e);
+                            self.expect(e.location, &key_type, &[ValueType::String]);
+                            result.open = true;
+                            None
+                        }
+                    };
+                    let default_type = default.as_ref().map(|d| self.infer(scope, d));
+                    (key, Some(value), default_type)
+                }
+            };
+
+            let field_type = match (key.as_ref().map(|k| objects.field(k)), missing) {
+                (Some(Some(present)), None) => present,
+                (Some(Some(present)), Some(missing)) => Type::union([present, missing]),
+                (Some(None), Some(missing)) => missing,
+                (Some(None), None) => {
+                    matched = false;
+                    if strict && !objects.is_never() {
+                        self.fail(
+                            pattern.location,
+                            TypeErrorReason::MissingKey {
+                                key: key.clone().unwrap_or_default(),
+                                actual: objects.clone(),
+                            },
+                        );
+                    }
+                    Type::Never
+                }
+                (None, missing) => Type::union([Type::Any].into_iter().chain(missing)),
+            };
+
+            let value_type = match (value_pattern, prop) {
+                (Some(value_pattern), _) => {
+                    self.bind_pattern(scope, bindings, value_pattern, &field_type, strict)
+                }
+                (
+                    None,
+                    ObjectPropertyPattern::Single(id)
+                    | ObjectPropertyPattern::Optional(id)
+                    | ObjectPropertyPattern::Default(id, _),
+                ) => {
+                    bindings.insert(id.clone(), field_type.clone());
+                    field_type
+                }
+                (None, ObjectPropertyPattern::Match(_)) => field_type,
+            };
+            matched &= !value_type.is_never() || objects.is_never();
+
+            if let Some(key) = key {
+                result.fields.insert(key, value_type);
+            }
+        }
+
+        if let Rest::Collect(p) = rest {
+            let keys: Vec<String> = result.fields.keys().cloned().collect();
+            let remaining = objects.without_fields(keys.iter().map(|k| k.as_str()));
+            matched &= !self
+                .bind_pattern(scope, bindings, p, &remaining, strict)
+                .is_never()
+                || objects.is_never();
+        }
+
+        if objects.is_never() || !matched {
+            return Type::Never;
+        }
+        Type::Object(result)
+    }
+
+    // The part of `subject` that is of one of the given runtime types.
+    fn narrow(
+        &mut self,
+        location: Option<Location>,
+        subject: &Type,
+        kinds: &[ValueType],
+        strict: bool,
+    ) -> Type {
+        let narrowed = Type::union(kinds.iter().map(|k| subject.restrict(*k)));
+        if narrowed.is_never() && !subject.is_never() {
+            let expected = Type::union(kinds.iter().map(|k| Type::from(*k)));
+            return self.mismatch(location, &expected, subject, strict);
+        }
+        narrowed
+    }
+
+    fn mismatch(
+        &mut self,
+        location: Option<Location>,
+        expected: &Type,
+        actual: &Type,
+        strict: bool,
+    ) -> Type {
+        if strict {
+            self.fail(
+                location,
+                TypeErrorReason::Mismatch {
+                    expected: expected.clone(),
+                    actual: actual.clone(),
+                },
+            );
+        }
+        Type::Never
+    }
+}
+
+const NUMERIC: &[ValueType] = &[ValueType::Integer, ValueType::Float];
+
+fn extend<'s>(scope: &TypeBindings<'s>, mut bindings: TypeBindings<'s>) -> TypeBindings<'s> {
+    let mut local = scope.clone();
+    local.append(&mut bindings);
+    local
+}
+
+fn numeric_result(left: &Type, right: &Type) -> Type {
+    let integer = |t: &Type| !t.restrict(ValueType::Integer).is_never();
+    let float = |t: &Type| !t.restrict(ValueType::Float).is_never();
+
+    Type::union([
+        if integer(left) && integer(right) {
+            Type::Integer
+        } else {
+            Type::Never
+        },
+        if float(left) || float(right) {
+            Type::Float
+        } else {
+            Type::Never
+        },
+    ])
+}
+
+fn merge_objects(left: &Type, right: &Type) -> Type {
+    match (left, right) {
+        (Type::Object(l), Type::Object(r)) => {
+            let mut fields = l.fields.clone();
+            fields.extend(r.fields.clone());
+            Type::Object(ObjectType {
+                fields,
+                open: l.open || r.open,
+            })
+        }
+        _ => Type::from(ValueType::Object),
+    }
+}
+
+// Range and relational patterns compare numbers with numbers and strings
+// with strings.
+fn bound_kinds(literal: &Literal) -> Vec<ValueType> {
+    match literal {
+        Literal::Number(_) | Literal::Float(_) => NUMERIC.to_vec(),
+        other => Type::of_literal(other).value_type().into_iter().collect(),
+    }
+}
+
+// The shape of the object a `catch` pattern is matched against.
+fn error_type() -> Type {
+    let location = Type::Object(ObjectType {
+        fields: BTreeMap::from([
+            ("start".to_string(), Type::Integer),
+            ("end".to_string(), Type::Integer),
+        ]),
+        open: false,
+    });
+
+    Type::Object(ObjectType {
+        fields: BTreeMap::from([
+            ("kind".to_string(), Type::String),
+            ("message".to_string(), Type::String),
+            ("location".to_string(), Type::union([Type::Null, location])),
+        ]),
+        open: false,
+    })
+}
@@ -2,6 +2,7 @@
 #![feature(map_try_insert)]
 #![feature(impl_trait_in_assoc_type)]
 
+pub mod analysis;
 pub mod identifier;
 pub mod literal;
 pub mod parser;
@@ -7,6 +7,7 @@ pub enum Literal<'s> {
     Null,
     String(Cow<'s, str>),
     Number(Cow<'s, str>),
+    Float(Cow<'s, str>),
     Boolean(bool),
     Type(ValueType),
 }
@@ -16,6 +17,7 @@ impl Literal<'_> {
             Literal::Null => Literal::Null,
             Literal::String(s) => Literal::String(Cow::Owned(s.to_string())),
             Literal::Number(s) => Literal::Number(Cow::Owned(s.to_string())),
+            Literal::Float(s) => Literal::Float(Cow::Owned(s.to_string())),
             Literal::Boolean(b) => Literal::Boolean(*b),
             Literal::Type(t) => Literal::Type(*t),
         }
@@ -28,6 +30,7 @@ impl<'a> std::fmt::Display for Literal<'a> {
             Literal::Null => write!(f, "null"),
             Literal::String(s) => write!(f, "\"{s}\""),
             Literal::Number(n) => write!(f, "{n}"),
+            Literal::Float(n) => write!(f, "{n}"),
             Literal::Boolean(b) => write!(f, "{b}"),
             Literal::Type(t) => write!(f, "{t}"),
         }
@@ -1,14 +1,20 @@
 use crate::literal::Literal;
 use crate::parser::located::located_expression;
 use crate::parser::pattern::pattern;
+use crate::syntax::assignment::{Assignment, AssignmentSet};
 use crate::syntax::expression::ArrayComprehension;
 use crate::syntax::expression::ComprehensionSource;
 use crate::syntax::expression::IfElseExpression;
 use crate::syntax::expression::LambdaAbstraction;
 use crate::syntax::expression::LambdaApplication;
+use crate::syntax::expression::LetExpression;
 use crate::syntax::expression::MatchCase;
 use crate::syntax::expression::MatchExpression;
 use crate::syntax::expression::ObjectComprehension;
+use crate::syntax::expression::PipeExpression;
+use crate::syntax::expression::RangeExpression;
+use crate::syntax::expression::SliceExpression;
+use crate::syntax::expression::TryCatchExpression;
 use crate::syntax::location::Location;
 use crate::syntax::pattern::Pattern;
 use crate::syntax::pattern::PatternBody;
@@ -21,7 +27,7 @@ use nom::{
     branch::alt,
     bytes::complete::{is_not, tag, take_until},
     character::complete::{alpha1, space0},
-    combinator::{all_consuming, map, not, opt, peek, recognize, value},
+    combinator::{all_consuming, map, not, opt, peek, recognize, success, value, verify},
     error::{context, Error},
     multi::{fold_many0, many0, separated_list0, separated_list1},
     sequence::{delimited, pair, preceded, separated_pair, terminated, tuple},
@@ -106,20 +112,30 @@ fn expression_array_item<'v, 's, E: ParserError<'s>>(
     )(input)
 }
 
+fn expression_argument_list<'v, 's, E: ParserError<'s>>(
+    input: ParserInput<'s>,
+) -> ParserResult<Vec<Expression<'v>>, E> {
+    context(
+        "expression_argument_list",
+        delimited(
+            ws(tag("(")),
+            separated_list0(ws(tag(",")), expression),
+            ws(tag(")")),
+        ),
+    )(input)
+}
+
 fn expression_call<'v, 's, E: ParserError<'s>>(
     input: ParserInput<'s>,
 ) -> ParserResult<Expression<'v>, E> {
     context(
         "expression_call",
         located_expression(map(
-            pair(
-                identifier,
-                delimited(ws(tag("(")), expression, ws(tag(")"))),
-            ),
-            |(function, arg)| {
+            pair(identifier, expression_argument_list),
+            |(function, arguments)| {
                 ExpressionBody::Call(CallExpression {
                     function,
-                    argument: Box::new(arg),
+                    arguments,
                 })
             },
         )),
@@ -288,7 +304,20 @@ fn expression_object_comprehension<'v, 's, E: ParserError<'s>>(
     )(input)
 }
 
-fn expression_lambda_abstraction<'v, 's, E: ParserError<'s>>(
+fn expression_pattern_list<'v, 's, E: ParserError<'s>>(
+    input: ParserInput<'s>,
+) -> ParserResult<Vec<Pattern<'v>>, E> {
+    context(
+        "expression_pattern_list",
+        delimited(
+            ws(tag("(")),
+            separated_list0(ws(tag(",")), pattern),
+            ws(tag(")")),
+        ),
+    )(input)
+}
+
+fn expression_lambda_abstraction<'v, 's, E: ParserError<'s>, const ALLOW_IN: bool>(
     input: ParserInput<'s>,
 ) -> ParserResult<Expression<'v>, E> {
     context(
@@ -297,13 +326,18 @@ fn expression_lambda_abstraction<'v, 's, E: ParserError<'s>>(
             tuple((
                 preceded(
                     ws(tag("fn")),
-                    alt((delimited(ws(tag("(")), pattern, ws(tag(")"))), pattern)),
+                    alt((
+                        pair(map(ws(identifier), Some), expression_pattern_list),
+                        pair(success(None), expression_pattern_list),
+                        pair(success(None), map(pattern, |p| vec![p])),
+                    )),
                 ),
-                preceded(ws(tag("=>")), expression),
+                preceded(ws(tag("=>")), expression_trailing::<E, ALLOW_IN>),
             )),
-            |(arg, body)| {
+            |((name, arguments), body)| {
                 ExpressionBody::Abstraction(LambdaAbstraction {
-                    arguments: arg,
+                    name,
+                    arguments,
                     body: Box::new(body),
                 })
             },
@@ -395,33 +429,137 @@ fn expression_condition<'v, 's, E: ParserError<'s>>(
     )(input)
 }
 
+fn expression_try_catch<'v, 's, E: ParserError<'s>>(
+    input: ParserInput<'s>,
+) -> ParserResult<Expression<'v>, E> {
+    context(
+        "expression_try_catch",
+        located_expression(map(
+            tuple((
+                preceded(
+                    ws(tag("try")),
+                    delimited(ws(tag("{")), expression, ws(tag("}"))),
+                ),
+                preceded(
+                    ws(tag("catch")),
+                    delimited(ws(tag("(")), pattern, ws(tag(")"))),
+                ),
+                delimited(ws(tag("{")), expression, ws(tag("}"))),
+            )),
+            |(body, pattern, fallback)| {
+                ExpressionBody::TryCatch(TryCatchExpression {
+                    body: Box::new(body),
+                    pattern,
+                    fallback: Box::new(fallback),
+                })
+            },
+        )),
+    )(input)
+}
+
+// Binding values end at the `in` keyword of the enclosing `let`, so the `in`
+// operator must be parenthesized within them.
+fn expression_let_value<'v, 's, E: ParserError<'s>>(
+    input: ParserInput<'s>,
+) -> ParserResult<Expression<'v>, E> {
+    context(
+        "expression_let_value",
+        alt((
+            expression_lambda_match_abstraction,
+            expression_lambda_abstraction::<E, false>,
+            expression_match,
+            expression_condition,
+            expression_try_catch,
+            expression_let::<E, false>,
+            expression_pipe::<E, false>,
+        )),
+    )(input)
+}
+
+// Lambda and `let` bodies have no end of their own, so within a binding value
+// they end at the `in` keyword as well.
+fn expression_trailing<'v, 's, E: ParserError<'s>, const ALLOW_IN: bool>(
+    input: ParserInput<'s>,
+) -> ParserResult<Expression<'v>, E> {
+    if ALLOW_IN {
+        expression(input)
+    } else {
+        expression_let_value(input)
+    }
+}
+
+fn expression_let<'v, 's, E: ParserError<'s>, const ALLOW_IN: bool>(
+    input: ParserInput<'s>,
+) -> ParserResult<Expression<'v>, E> {
+    context(
+        "expression_let",
+        located_expression(map(
+            pair(
+                preceded(
+                    ws(tag("let")),
+                    terminated(
+                        separated_list1(
+                            ws(tag(";")),
+                            map(
+                                separated_pair(pattern, ws(tag("=")), expression_let_value),
+                                |(pattern, expression)| Assignment {
+                                    pattern,
+                                    expression,
+                                },
+                            ),
+                        ),
+                        opt(ws(tag(";"))),
+                    ),
+                ),
+                preceded(ws(tag("in")), expression_trailing::<E, ALLOW_IN>),
+            ),
+            |(assignments, body)| {
+                ExpressionBody::Let(LetExpression {
+                    assignments: AssignmentSet { assignments },
+                    body: Box::new(body),
+                })
+            },
+        )),
+    )(input)
+}
+
 fn expression_lambda_match_abstraction<'v, 's, E: ParserError<'s>>(
     input: ParserInput<'s>,
 ) -> ParserResult<Expression<'v>, E> {
     context(
         "expression_lambda_match_abstraction",
         located_expression(map(
-            preceded(
-                ws(tag("fn match")),
-                delimited(
-                    ws(tag("{")),
-                    opt(terminated(
-                        separated_list1(ws(tag(",")), expression_match_arm),
-                        opt(ws(tag(","))),
-                    )),
-                    ws(tag("}")),
+            tuple((
+                position,
+                preceded(
+                    ws(tag("fn match")),
+                    delimited(
+                        ws(tag("{")),
+                        opt(terminated(
+                            separated_list1(ws(tag(",")), expression_match_arm),
+                            opt(ws(tag(","))),
+                        )),
+                        ws(tag("}")),
+                    ),
                 ),
-            ),
-            |cases| {
+                position,
+            )),
+            |(start, cases, end)| {
                 let local_identifier = Identifier::new("___local");
                 ExpressionBody::Abstraction(LambdaAbstraction {
-                    arguments: Pattern::new(PatternBody::Identifier(local_identifier.clone())),
-                    body: Box::new(Expression::new(ExpressionBody::Match(MatchExpression {
-                        subject: Box::new(Expression::new(ExpressionBody::Identifier(
-                            local_identifier,
-                        ))),
-                        cases: cases.unwrap_or_default(),
-                    }))),
+                    name: None,
+                    arguments: vec![Pattern::new(PatternBody::Identifier(
+                        local_identifier.clone(),
+                    ))],
+                    body: Box::new(Expression::new_with_location(
+                        ExpressionBody::Match(MatchExpression {
+                            subject: Box::new(Expression::new(ExpressionBody::Identifier(
+                                local_identifier,
+                            ))),
+                            cases: cases.unwrap_or_default(),
+                        }),
+                        Location::new(start.location_offset(), end.location_offset()),
+                    )),
                 })
             },
         )),
@@ -470,7 +608,9 @@ fn string_template_part<'v, 's, E: ParserError<'s>>(
         "expression_string_template_part",
         map(
             tuple((
-                recognize(take_until("${")),
+                verify(take_until("${"), |s: &ParserInput| {
+                    !s.fragment().contains('`')
+                }),
                 delimited(
                     tag("${"),
                     context("expression_string_template_part_dynamic", expression),
@@ -506,6 +646,73 @@ fn expression_string_template<'v, 's, E: ParserError<'s>>(
     )(input)
 }
 
+fn expression_pipe<'v, 's, E: ParserError<'s>, const ALLOW_IN: bool>(
+    input: ParserInput<'s>,
+) -> ParserResult<Expression<'v>, E> {
+    let (input, init) = context(
+        "expression_pipe_lhs",
+        expression_null_coalesce::<E, ALLOW_IN>,
+    )(input)?;
+
+    fold_many0(
+        preceded(
+            ws(tag("|>")),
+            context(
+                "expression_pipe_rhs",
+                expression_null_coalesce::<E, ALLOW_IN>,
+            ),
+        ),
+        move || init.clone(),
+        |value, function| {
+            let outer_location = value
+                .location
+                .and_then(|l| function.location.map(|r| Location::new(l.start, r.end)));
+
+            Expression::new_with_optional_location(
+                ExpressionBody::Pipe(PipeExpression {
+                    value: Box::new(value),
+                    function: Box::new(function),
+                }),
+                outer_location,
+            )
+        },
+    )(input)
+}
+
+fn expression_null_coalesce<'v, 's, E: ParserError<'s>, const ALLOW_IN: bool>(
+    input: ParserInput<'s>,
+) -> ParserResult<Expression<'v>, E> {
+    let (input, init) = context(
+        "expression_null_coalesce_lhs",
+        expression_logic_additive::<E, ALLOW_IN>,
+    )(input)?;
+
+    fold_many0(
+        pair(
+            ws(value(BinaryOperator::NullCoalesce, tag("??"))),
+            context(
+                "expression_null_coalesce_rhs",
+                expression_logic_additive::<E, ALLOW_IN>,
+            ),
+        ),
+        move || init.clone(),
+        |left, (operator, right)| {
+            let outer_location = left
+                .location
+                .and_then(|l| right.location.map(|r| Location::new(l.start, r.end)));
+
+            Expression::new_with_optional_location(
+                ExpressionBody::Binary(BinaryExpression {
+                    operator,
+                    left: Box::new(left),
+                    right: Box::new(right),
+                }),
+                outer_location,
+            )
+        },
+    )(input)
+}
+
 fn expression_logic_additive_operator<'s, E: ParserError<'s>>(
     input: ParserInput<'s>,
 ) -> ParserResult<LogicalOperator, E> {
@@ -515,12 +722,12 @@ fn expression_logic_additive_operator<'s, E: ParserError<'s>>(
     )(input)
 }
 
-fn expression_logic_additive<'v, 's, E: ParserError<'s>>(
+fn expression_logic_additive<'v, 's, E: ParserError<'s>, const ALLOW_IN: bool>(
     input: ParserInput<'s>,
 ) -> ParserResult<Expression<'v>, E> {
     let (input, init) = context(
         "expression_logic_additive_lhs",
-        expression_logic_multiplicative,
+        expression_logic_multiplicative::<E, ALLOW_IN>,
     )(input)?;
 
     fold_many0(
@@ -528,7 +735,7 @@ fn expression_logic_additive<'v, 's, E: ParserError<'s>>(
             ws(expression_logic_additive_operator),
             context(
                 "expression_logic_additive_rhs",
-                expression_logic_multiplicative,
+                expression_logic_multiplicative::<E, ALLOW_IN>,
             ),
         ),
         move || init.clone(),
@@ -558,12 +765,12 @@ fn expression_logic_multiplicative_operator<'s, E: ParserError<'s>>(
     )(input)
 }
 
-fn expression_logic_multiplicative<'v, 's, E: ParserError<'s>>(
+fn expression_logic_multiplicative<'v, 's, E: ParserError<'s>, const ALLOW_IN: bool>(
     input: ParserInput<'s>,
 ) -> ParserResult<Expression<'v>, E> {
     let (input, init) = context(
         "expression_logic_multiplicative_lhs",
-        expression_type_predicate,
+        expression_type_predicate::<E, ALLOW_IN>,
     )(input)?;
 
     fold_many0(
@@ -571,7 +778,7 @@ fn expression_logic_multiplicative<'v, 's, E: ParserError<'s>>(
             ws(expression_logic_multiplicative_operator),
             context(
                 "expression_logic_multiplicative_rhs",
-                expression_type_predicate,
+                expression_type_predicate::<E, ALLOW_IN>,
             ),
         ),
         move || init.clone(),
@@ -601,14 +808,17 @@ fn expression_type_predicate_operator<'s, E: ParserError<'s>>(
     )(input)
 }
 
-fn expression_type_predicate<'v, 's, E: ParserError<'s>>(
+fn expression_type_predicate<'v, 's, E: ParserError<'s>, const ALLOW_IN: bool>(
     input: ParserInput<'s>,
 ) -> ParserResult<Expression<'v>, E> {
-    let (input, init) = context("expression_type_predicate_lhs", expression_type_additive)(input)?;
+    let (input, init) = context(
+        "expression_type_predicate_lhs",
+        expression_type_additive::<E, ALLOW_IN>,
+    )(input)?;
 
     let Ok((input, (op, t))) = tuple((
         ws(expression_type_predicate_operator::<E>),
-        expression_numeric_predicative,
+        expression_numeric_predicative::<E, ALLOW_IN>,
     ))(input) else {
         return Ok((input, init));
     };
@@ -639,17 +849,20 @@ fn expression_type_additive_operator<'s, E: ParserError<'s>>(
     )(input)
 }
 
-fn expression_type_additive<'v, 's, E: ParserError<'s>>(
+fn expression_type_additive<'v, 's, E: ParserError<'s>, const ALLOW_IN: bool>(
     input: ParserInput<'s>,
 ) -> ParserResult<Expression<'v>, E> {
-    let (input, init) = context("expression_type_additive", expression_numeric_predicative)(input)?;
+    let (input, init) = context(
+        "expression_type_additive",
+        expression_numeric_predicative::<E, ALLOW_IN>,
+    )(input)?;
 
     fold_many0(
         pair(
             ws(expression_type_additive_operator),
             context(
                 "expression_type_additive_rhs",
-                expression_numeric_predicative,
+                expression_numeric_predicative::<E, ALLOW_IN>,
             ),
         ),
         move || init.clone(),
@@ -687,21 +900,17 @@ fn expression_numeric_predicative_operator<'s, E: ParserError<'s>>(
     )(input)
 }
 
-fn expression_numeric_predicative<'v, 's, E: ParserError<'s>>(
+fn expression_numeric_predicative<'v, 's, E: ParserError<'s>, const ALLOW_IN: bool>(
     input: ParserInput<'s>,
 ) -> ParserResult<Expression<'v>, E> {
-    let (input, init) = context(
-        "expression_numeric_predicative_lhs",
-        expression_numeric_additive,
-    )(input)?;
+    let (input, init) = context("expression_numeric_predicative_lhs", expression_range)(input)?;
 
     fold_many0(
         pair(
-            ws(expression_numeric_predicative_operator),
-            context(
-                "expression_numeric_predicative_rhs",
-                expression_numeric_additive,
-            ),
+            ws(verify(expression_numeric_predicative_operator, |op| {
+                ALLOW_IN || *op != BinaryOperator::In
+            })),
+            context("expression_numeric_predicative_rhs", expression_range),
         ),
         move || init.clone(),
         |left, (operator, right)| {
@@ -721,6 +930,36 @@ fn expression_numeric_predicative<'v, 's, E: ParserError<'s>>(
     )(input)
 }
 
+fn expression_range<'v, 's, E: ParserError<'s>>(
+    input: ParserInput<'s>,
+) -> ParserResult<Expression<'v>, E> {
+    map(
+        pair(
+            context("expression_range_start", expression_numeric_additive),
+            opt(preceded(
+                ws(tag("..")),
+                context("expression_range_end", expression_numeric_additive),
+            )),
+        ),
+        |(start, end)| match end {
+            None => start,
+            Some(end) => {
+                let outer_location = start
+                    .location
+                    .and_then(|l| end.location.map(|r| Location::new(l.start, r.end)));
+
+                Expression::new_with_optional_location(
+                    ExpressionBody::Range(RangeExpression {
+                        start: Box::new(start),
+                        end: Box::new(end),
+                    }),
+                    outer_location,
+                )
+            }
+        },
+    )(input)
+}
+
 fn expression_numeric_additive_operator<'s, E: ParserError<'s>>(
     input: ParserInput<'s>,
 ) -> ParserResult<BinaryOperator, E> {
@@ -852,9 +1091,10 @@ fn expression_numeric_exponential<'v, 's, E: ParserError<'s>>(
 }
 
 enum PathSegment<'a> {
-    Application(Expression<'a>, Location),
-    Index(Expression<'a>, Location),
-    Prop(Identifier<'a>, Location),
+    Application(Vec<Expression<'a>>, Location),
+    Index(Expression<'a>, Location, bool),
+    Slice(Option<Expression<'a>>, Option<Expression<'a>>, Location),
+    Prop(Identifier<'a>, Location, bool),
 }
 
 fn expression_path<'v, 's, E: ParserError<'s>>(
@@ -867,6 +1107,7 @@ fn expression_path<'v, 's, E: ParserError<'s>>(
             map(
                 tuple((
                     position,
+                    opt(ws(tag("?."))),
                     delimited(
                         ws(tag("[")),
                         context("expression_path_rhs", expression),
@@ -874,32 +1115,56 @@ fn expression_path<'v, 's, E: ParserError<'s>>(
                     ),
                     position,
                 )),
-                |(ls, expr, rs)| {
+                |(ls, optional, expr, rs)| {
                     PathSegment::Index(
                         expr,
                         Location::new(ls.location_offset(), rs.location_offset()),
+                        optional.is_some(),
                     )
                 },
             ),
             map(
-                preceded(
-                    ws(tag(".")),
+                tuple((
+                    position,
+                    delimited(
+                        ws(tag("[")),
+                        separated_pair(
+                            opt(context("expression_slice_start", expression)),
+                            ws(tag(":")),
+                            opt(context("expression_slice_end", expression)),
+                        ),
+                        ws(tag("]")),
+                    ),
+                    position,
+                )),
+                |(ls, (start, end), rs)| {
+                    PathSegment::Slice(
+                        start,
+                        end,
+                        Location::new(ls.location_offset(), rs.location_offset()),
+                    )
+                },
+            ),
+            map(
+                pair(
+                    ws(alt((value(true, tag("?.")), value(false, tag("."))))),
                     context(
                         "expression_member_rhs",
                         tuple((position, identifier, position)),
                     ),
                 ),
-                |(ls, id, rs)| {
+                |(optional, (ls, id, rs))| {
                     PathSegment::Prop(
                         id,
                         Location::new(ls.location_offset(), rs.location_offset()),
+                        optional,
                     )
                 },
             ),
             map(
                 tuple((
                     ws(terminated(position, tag("."))),
-                    expression_with_paren,
+                    expression_argument_list,
                     position,
                 )),
                 |(ls, expr, rs)| {
@@ -914,8 +1179,9 @@ fn expression_path<'v, 's, E: ParserError<'s>>(
         |acc, segment| {
             let right_location = match segment {
                 PathSegment::Application(_, loc) => loc,
-                PathSegment::Index(_, loc) => loc,
-                PathSegment::Prop(_, loc) => loc,
+                PathSegment::Index(_, loc, _) => loc,
+                PathSegment::Slice(_, _, loc) => loc,
+                PathSegment::Prop(_, loc, _) => loc,
             };
 
             let outer_location = acc
@@ -924,23 +1190,34 @@ fn expression_path<'v, 's, E: ParserError<'s>>(
 
             Expression::new_with_optional_location(
                 match segment {
-                    PathSegment::Application(param, _) => {
+                    PathSegment::Application(parameters, _) => {
                         ExpressionBody::Application(LambdaApplication {
                             lambda: Box::new(acc),
-                            parameter: Box::new(param),
+                            parameters,
                         })
                     }
-                    PathSegment::Index(expr, _) => ExpressionBody::Member(MemberExpression {
-                        object: Box::new(acc),
-                        property: Box::new(expr),
-                    }),
-                    PathSegment::Prop(id, loc) => ExpressionBody::Member(MemberExpression {
+                    PathSegment::Index(expr, _, optional) => {
+                        ExpressionBody::Member(MemberExpression {
+                            object: Box::new(acc),
+                            property: Box::new(expr),
+                            optional,
+                        })
+                    }
+                    PathSegment::Slice(start, end, _) => ExpressionBody::Slice(SliceExpression {
                         object: Box::new(acc),
-                        property: Box::new(Expression::new_with_location(
-                            ExpressionBody::Literal(Literal::String(id.name)),
-                            loc,
-                        )),
+                        start: start.map(Box::new),
+                        end: end.map(Box::new),
                     }),
+                    PathSegment::Prop(id, loc, optional) => {
+                        ExpressionBody::Member(MemberExpression {
+                            object: Box::new(acc),
+                            property: Box::new(Expression::new_with_location(
+                                ExpressionBody::Literal(Literal::String(id.name)),
+                                loc,
+                            )),
+                            optional,
+                        })
+                    }
                 },
                 outer_location,
             )
@@ -1042,10 +1319,12 @@ pub fn expression<'v, 's, E: ParserError<'s>>(
         "expression",
         alt((
             expression_lambda_match_abstraction,
-            expression_lambda_abstraction,
+            expression_lambda_abstraction::<E, true>,
             expression_match,
             expression_condition,
-            expression_logic_additive,
+            expression_try_catch,
+            expression_let::<E, true>,
+            expression_pipe::<E, true>,
         )),
     )(input)
 }
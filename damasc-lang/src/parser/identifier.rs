@@ -17,7 +17,7 @@ use super::io::{ParserError, ParserInput, ParserResult};
 fn no_keyword(input: &ParserInput) -> bool {
     !matches!(
         input.fragment(),
-        &"where" | &"into" | &"limit" | &"with" | &"fn" | &"match" | &"if" | &"else"
+        &"where" | &"into" | &"limit" | &"with" | &"fn" | &"match" | &"if" | &"else" | &"try" | &"catch" | &"let"
     )
 }
 
@@ -4,9 +4,10 @@ use std::borrow::Cow;
 use nom::{
     branch::alt,
     bytes::complete::{tag, take_until},
-    combinator::{map, recognize, value},
+    character::complete::{digit1, one_of},
+    combinator::{map, opt, recognize, value},
     error::{context, ParseError},
-    sequence::delimited,
+    sequence::{delimited, tuple},
 };
 
 use crate::literal::Literal;
@@ -46,6 +47,31 @@ fn literal_bool<'v, 'e, E: ParserError<'e>>(
     )(input)
 }
 
+fn literal_exponent<'e, E: ParserError<'e>>(
+    input: ParserInput<'e>,
+) -> ParserResult<ParserInput<'e>, E> {
+    recognize(tuple((one_of("eE"), opt(one_of("+-")), digit1)))(input)
+}
+
+fn literal_float<'v, 'e, E: ParserError<'e>>(
+    input: ParserInput<'e>,
+) -> ParserResult<Literal<'v>, E> {
+    context(
+        "literal_float",
+        map(
+            recognize(tuple((
+                opt(tag("-")),
+                digit1,
+                alt((
+                    recognize(tuple((tag("."), digit1, opt(literal_exponent)))),
+                    literal_exponent,
+                )),
+            ))),
+            |s: ParserInput| Literal::Float(Cow::Owned(s.fragment().to_owned().to_owned())),
+        ),
+    )(input)
+}
+
 fn literal_number<'v, 'e, E: ParserError<'e>>(
     input: ParserInput<'e>,
 ) -> ParserResult<Literal<'v>, E> {
@@ -67,6 +93,7 @@ pub(crate) fn literal<'v, 'e, E: ParserError<'e>>(
             literal_null,
             literal_string,
             literal_bool,
+            literal_float,
             literal_number,
             literal_type,
         )),
@@ -81,6 +108,7 @@ pub(crate) fn literal_type_raw<'s, E: ParseError<ParserInput<'s>>>(
         value(ValueType::Null, tag("Null")),
         value(ValueType::Boolean, tag("Boolean")),
         value(ValueType::Integer, tag("Integer")),
+        value(ValueType::Float, tag("Float")),
         value(ValueType::Array, tag("Array")),
         value(ValueType::Object, tag("Object")),
         value(ValueType::String, tag("String")),
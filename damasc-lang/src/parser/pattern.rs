@@ -1,20 +1,25 @@
+use crate::literal::Literal;
 use crate::parser::expression::expression_identifier;
 use crate::parser::located::located_pattern;
 use crate::syntax::pattern::PatternBody;
 use nom::{
     branch::alt,
-    bytes::complete::tag,
-    character::complete::space0,
-    combinator::{all_consuming, map, opt, value},
+    bytes::complete::{escaped, tag},
+    character::complete::{anychar, none_of, one_of, space0},
+    combinator::{all_consuming, map, map_opt, not, opt, recognize, value, verify},
     error::{context, Error},
-    multi::{separated_list0, separated_list1},
-    sequence::{delimited, preceded, separated_pair, tuple},
+    multi::{fold_many0, many0, separated_list0, separated_list1},
+    sequence::{delimited, pair, preceded, separated_pair, terminated, tuple},
 };
+use nom_locate::position;
+use std::borrow::Cow;
 
 use crate::syntax::{
     expression::PropertyKey,
+    location::Location,
     pattern::{
-        ArrayPatternItem, ObjectPropertyPattern, Pattern, PatternSet, PropertyPattern, Rest,
+        ArrayPattern, ArrayPatternItem, ObjectPropertyPattern, Pattern, PatternSet,
+        PropertyPattern, RangePattern, RegexPattern, RelationalOperator, Rest, StringPattern,
     },
 };
 
@@ -75,27 +80,43 @@ fn pattern_object_prop<'v, 's, E: ParserError<'s>>(
         "pattern_object_prop",
         alt((
             map(
-                separated_pair(
-                    delimited(ws(tag("[")), expression, ws(tag("]"))),
-                    ws(tag(":")),
-                    pattern,
-                ),
-                |(prop, value)| {
+                tuple((
+                    separated_pair(
+                        delimited(ws(tag("[")), expression, ws(tag("]"))),
+                        ws(tag(":")),
+                        pattern,
+                    ),
+                    opt(preceded(ws(tag("=")), expression)),
+                )),
+                |((prop, value), default)| {
                     ObjectPropertyPattern::Match(PropertyPattern {
                         key: PropertyKey::Expression(prop),
                         value,
+                        default,
                     })
                 },
             ),
             map(
-                separated_pair(identifier, ws(tag(":")), pattern),
-                |(prop, value)| {
+                tuple((
+                    separated_pair(identifier, ws(tag(":")), pattern),
+                    opt(preceded(ws(tag("=")), expression)),
+                )),
+                |((prop, value), default)| {
                     ObjectPropertyPattern::Match(PropertyPattern {
                         key: PropertyKey::Identifier(prop),
                         value,
+                        default,
                     })
                 },
             ),
+            map(
+                separated_pair(identifier, ws(tag("=")), expression),
+                |(prop, default)| ObjectPropertyPattern::Default(prop, default),
+            ),
+            map(
+                terminated(identifier, ws(tag("?"))),
+                ObjectPropertyPattern::Optional,
+            ),
             map(identifier, ObjectPropertyPattern::Single),
         )),
     )(input)
@@ -145,14 +166,20 @@ fn pattern_array<'v, 's, E: ParserError<'s>>(
         located_pattern(delimited(
             ws(tag("[")),
             alt((
-                map(pattern_rest, |r| PatternBody::Array(vec![], r)),
+                map(pair(pattern_rest, pattern_array_suffix), |(r, suffix)| {
+                    PatternBody::Array(vec![], r, Box::new(suffix))
+                }),
                 map(
                     tuple((
                         separated_list0(ws(tag(",")), map(pattern, ArrayPatternItem::Pattern)),
-                        opt(preceded(ws(tag(",")), opt(pattern_rest))),
+                        opt(preceded(
+                            ws(tag(",")),
+                            opt(pair(pattern_rest, pattern_array_suffix)),
+                        )),
                     )),
-                    |(items, rest)| {
-                        PatternBody::Array(items, rest.flatten().unwrap_or(Rest::Exact))
+                    |(items, rest)| match rest.flatten() {
+                        Some((rest, suffix)) => PatternBody::Array(items, rest, Box::new(suffix)),
+                        None => PatternBody::Array(items, Rest::Exact, Box::default()),
                     },
                 ),
             )),
@@ -161,6 +188,72 @@ fn pattern_array<'v, 's, E: ParserError<'s>>(
     )(input)
 }
 
+fn pattern_array_suffix<'v, 's, E: ParserError<'s>>(
+    input: ParserInput<'s>,
+) -> ParserResult<ArrayPattern<'v>, E> {
+    context(
+        "pattern_array_suffix",
+        terminated(
+            many0(preceded(
+                ws(tag(",")),
+                map(pattern, ArrayPatternItem::Pattern),
+            )),
+            opt(ws(tag(","))),
+        ),
+    )(input)
+}
+
+fn pattern_string_literal<'v, 's, E: ParserError<'s>>(
+    input: ParserInput<'s>,
+) -> ParserResult<Cow<'v, str>, E> {
+    map_opt(literal, |l| match l {
+        Literal::String(s) => Some(s),
+        _ => None,
+    })(input)
+}
+
+fn pattern_string_prefix<'v, 's, E: ParserError<'s>>(
+    input: ParserInput<'s>,
+) -> ParserResult<Pattern<'v>, E> {
+    context(
+        "pattern_string_prefix",
+        located_pattern(map(
+            separated_pair(ws(pattern_string_literal), ws(tag("++")), pattern_concat),
+            |(prefix, rest)| PatternBody::String(StringPattern::Prefix(prefix, Box::new(rest))),
+        )),
+    )(input)
+}
+
+fn pattern_string_suffix<'v, 's, E: ParserError<'s>>(
+    input: ParserInput<'s>,
+) -> ParserResult<Pattern<'v>, E> {
+    let (input, (start, init)) =
+        context("pattern_string_suffix", pair(position, pattern_single))(input)?;
+
+    fold_many0(
+        pair(
+            preceded(ws(tag("++")), ws(pattern_string_literal)),
+            position,
+        ),
+        move || init.clone(),
+        move |rest, (suffix, end)| {
+            Pattern::new_with_location(
+                PatternBody::String(StringPattern::Suffix(Box::new(rest), suffix)),
+                Location::new(start.location_offset(), end.location_offset()),
+            )
+        },
+    )(input)
+}
+
+fn pattern_concat<'v, 's, E: ParserError<'s>>(
+    input: ParserInput<'s>,
+) -> ParserResult<Pattern<'v>, E> {
+    context(
+        "pattern_concat",
+        alt((pattern_string_prefix, pattern_string_suffix)),
+    )(input)
+}
+
 fn pattern_capture<'v, 's, E: ParserError<'s>>(
     input: ParserInput<'s>,
 ) -> ParserResult<Pattern<'v>, E> {
@@ -175,6 +268,7 @@ fn pattern_capture<'v, 's, E: ParserError<'s>>(
                     pattern_array,
                     pattern_object,
                     pattern_pinned_expression,
+                    pattern_parenthesized,
                 )),
             ),
             |(id, pat)| PatternBody::Capture(id, Box::new(pat)),
@@ -209,11 +303,122 @@ fn pattern_pinned_expression<'v, 's, E: ParserError<'s>>(
     )(input)
 }
 
+fn pattern_range<'v, 's, E: ParserError<'s>>(
+    input: ParserInput<'s>,
+) -> ParserResult<Pattern<'v>, E> {
+    context(
+        "pattern_range",
+        located_pattern(map(
+            tuple((
+                literal,
+                ws(alt((value(true, tag("..=")), value(false, tag(".."))))),
+                literal,
+            )),
+            |(start, inclusive, end)| {
+                PatternBody::Range(Box::new(RangePattern {
+                    start,
+                    end,
+                    inclusive,
+                }))
+            },
+        )),
+    )(input)
+}
+
+fn pattern_relational<'v, 's, E: ParserError<'s>>(
+    input: ParserInput<'s>,
+) -> ParserResult<Pattern<'v>, E> {
+    context(
+        "pattern_relational",
+        located_pattern(map(
+            pair(
+                ws(alt((
+                    value(RelationalOperator::GreaterThanEqual, tag(">=")),
+                    value(RelationalOperator::LessThanEqual, tag("<=")),
+                    value(RelationalOperator::GreaterThan, tag(">")),
+                    value(RelationalOperator::LessThan, tag("<")),
+                ))),
+                literal,
+            ),
+            |(operator, bound)| PatternBody::Relational(operator, bound),
+        )),
+    )(input)
+}
+
+fn pattern_regex<'v, 's, E: ParserError<'s>>(
+    input: ParserInput<'s>,
+) -> ParserResult<Pattern<'v>, E> {
+    context(
+        "pattern_regex",
+        located_pattern(map_opt(
+            delimited(
+                tag("/"),
+                recognize(opt(escaped(none_of("\\/"), '\\', anychar))),
+                tag("/"),
+            ),
+            |source: ParserInput| {
+                RegexPattern::new(Cow::Owned(source.fragment().to_string()))
+                    .ok()
+                    .map(PatternBody::Regex)
+            },
+        )),
+    )(input)
+}
+
+fn pattern_parenthesized<'v, 's, E: ParserError<'s>>(
+    input: ParserInput<'s>,
+) -> ParserResult<Pattern<'v>, E> {
+    context(
+        "pattern_parenthesized",
+        delimited(ws(tag("(")), pattern, ws(tag(")"))),
+    )(input)
+}
+
+fn pattern_alternatives<'v, 's, E: ParserError<'s>>(
+    input: ParserInput<'s>,
+) -> ParserResult<Pattern<'v>, E> {
+    context(
+        "pattern_alternatives",
+        map(
+            tuple((
+                position,
+                verify(
+                    separated_list1(ws(terminated(tag("|"), not(one_of("|>")))), pattern_concat),
+                    |alternatives: &[Pattern]| {
+                        Pattern::alternatives_bind_same_identifiers(alternatives)
+                    },
+                ),
+                position,
+            )),
+            |(start, mut alternatives, end): (_, Vec<_>, _)| {
+                if alternatives.len() == 1 {
+                    alternatives.remove(0)
+                } else {
+                    Pattern::new_with_location(
+                        PatternBody::Alternative(alternatives),
+                        Location::new(start.location_offset(), end.location_offset()),
+                    )
+                }
+            },
+        ),
+    )(input)
+}
+
 pub fn pattern<'v, 's, E: ParserError<'s>>(input: ParserInput<'s>) -> ParserResult<Pattern<'v>, E> {
+    context("pattern", pattern_alternatives)(input)
+}
+
+fn pattern_single<'v, 's, E: ParserError<'s>>(
+    input: ParserInput<'s>,
+) -> ParserResult<Pattern<'v>, E> {
     context(
-        "pattern",
+        "pattern_single",
         alt((
+            pattern_parenthesized,
             pattern_pinned_expression,
+            pattern_range,
+            pattern_relational,
+            pattern_regex,
             pattern_atom,
             pattern_capture,
             pattern_array,
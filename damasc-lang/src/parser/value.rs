@@ -8,7 +8,7 @@ use super::{
 };
 use crate::{
     literal::Literal,
-    value::{Value, ValueBag},
+    value::{Float, Value, ValueBag},
 };
 use nom::{
     branch::alt,
@@ -112,6 +112,7 @@ fn value_literal_atom<'v, 's, E: ParserError<'s>>(
             Literal::Null => Value::Null,
             Literal::String(s) => Value::String(s),
             Literal::Number(n) => Value::Integer(n.parse().unwrap()),
+            Literal::Float(n) => Value::Float(Float(n.parse().unwrap())),
             Literal::Boolean(b) => Value::Boolean(b),
             Literal::Type(t) => Value::Type(t),
         }),
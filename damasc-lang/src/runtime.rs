@@ -1,4 +1,11 @@
+// Errors carry the values that caused them, so that `catch` can hand them
+// back to the program. Failed patterns are how `match` picks its case, so
+// boxing them would allocate for every rejected case.
+#![allow(clippy::result_large_err)]
+
 pub mod assignment;
+pub mod budget;
 pub mod env;
 pub mod evaluation;
 pub mod matching;
+pub mod native;
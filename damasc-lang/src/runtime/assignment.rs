@@ -1,9 +1,12 @@
 use crate::identifier::Identifier;
+use crate::runtime::budget::EvalBudget;
 use crate::runtime::env::Environment;
 use crate::runtime::evaluation::EvalError;
 use crate::runtime::evaluation::Evaluation;
 use crate::runtime::matching::Matcher;
 use crate::runtime::matching::PatternFail;
+use crate::runtime::native::NativeRegistry;
+use crate::runtime::native::DEFAULT_NATIVES;
 use crate::syntax::assignment::Assignment;
 use crate::syntax::assignment::AssignmentSet;
 use crate::topology::TopologyError;
@@ -20,11 +23,25 @@ pub enum AssignmentError<'s, 'v> {
 #[derive(Debug)]
 pub struct AssignmentEvaluation<'i, 's, 'v, 'e> {
     env: &'e Environment<'i, 's, 'v>,
+    natives: &'e NativeRegistry,
+    budget: Option<&'e EvalBudget>,
 }
 
 impl<'i: 's, 's, 'v: 's, 'e> AssignmentEvaluation<'i, 's, 'v, 'e> {
     pub fn new(env: &'e Environment<'i, 's, 'v>) -> Self {
-        Self { env }
+        Self {
+            env,
+            natives: &DEFAULT_NATIVES,
+            budget: None,
+        }
+    }
+
+    pub fn with_natives(self, natives: &'e NativeRegistry) -> Self {
+        Self { natives, ..self }
+    }
+
+    pub fn with_budget(self, budget: Option<&'e EvalBudget>) -> Self {
+        Self { budget, ..self }
     }
 
     pub fn eval_assigment_set<'a: 's, 'b: 's>(
@@ -41,8 +58,12 @@ impl<'i: 's, 's, 'v: 's, 'e> AssignmentEvaluation<'i, 's, 'v, 'e> {
                     expression,
                 } in sorted_set.assignments
                 {
-                    let matcher = Matcher::new(&local_env);
-                    let evaluation = Evaluation::new(&local_env);
+                    let matcher = Matcher::new(&local_env)
+                        .with_natives(self.natives)
+                        .with_budget(self.budget);
+                    let evaluation = Evaluation::new(&local_env)
+                        .with_natives(self.natives)
+                        .with_budget(self.budget);
 
                     let value = match evaluation.eval_expr(&expression) {
                         Ok(value) => value,
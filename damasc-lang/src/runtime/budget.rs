@@ -0,0 +1,106 @@
+use std::cell::Cell;
+
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub struct EvalLimits {
+    pub max_steps: Option<usize>,
+    pub max_call_depth: Option<usize>,
+    pub max_collection_size: Option<usize>,
+}
+
+impl EvalLimits {
+    pub const UNLIMITED: Self = Self {
+        max_steps: None,
+        max_call_depth: None,
+        max_collection_size: None,
+    };
+
+    pub const fn new(max_steps: usize, max_call_depth: usize, max_collection_size: usize) -> Self {
+        Self {
+            max_steps: Some(max_steps),
+            max_call_depth: Some(max_call_depth),
+            max_collection_size: Some(max_collection_size),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum BudgetLimit {
+    Steps(usize),
+    CallDepth(usize),
+    CollectionSize(usize),
+}
+
+impl std::fmt::Display for BudgetLimit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BudgetLimit::Steps(max) => write!(f, "limit of {max} evaluation steps"),
+            BudgetLimit::CallDepth(max) => write!(f, "maximum call depth of {max}"),
+            BudgetLimit::CollectionSize(max) => write!(f, "maximum collection size of {max}"),
+        }
+    }
+}
+
+// Shared by all nested evaluations of a single run, so steps and call depth
+// are counted across lambda applications and native calls.
+#[derive(Debug, Default)]
+pub struct EvalBudget {
+    limits: EvalLimits,
+    steps: Cell<usize>,
+    call_depth: Cell<usize>,
+}
+
+impl EvalBudget {
+    pub fn new(limits: EvalLimits) -> Self {
+        Self {
+            limits,
+            steps: Cell::new(0),
+            call_depth: Cell::new(0),
+        }
+    }
+
+    pub fn limits(&self) -> EvalLimits {
+        self.limits
+    }
+
+    pub fn steps(&self) -> usize {
+        self.steps.get()
+    }
+
+    pub fn reset(&self) {
+        self.steps.set(0);
+        self.call_depth.set(0);
+    }
+
+    pub(crate) fn step(&self) -> Result<(), BudgetLimit> {
+        let steps = self.steps.get() + 1;
+        self.steps.set(steps);
+
+        match self.limits.max_steps {
+            Some(max) if steps > max => Err(BudgetLimit::Steps(max)),
+            _ => Ok(()),
+        }
+    }
+
+    pub(crate) fn enter_call(&self) -> Result<(), BudgetLimit> {
+        let depth = self.call_depth.get() + 1;
+
+        match self.limits.max_call_depth {
+            Some(max) if depth > max => Err(BudgetLimit::CallDepth(max)),
+            _ => {
+                self.call_depth.set(depth);
+                Ok(())
+            }
+        }
+    }
+
+    pub(crate) fn exit_call(&self) {
+        self.call_depth.set(self.call_depth.get().saturating_sub(1));
+    }
+
+    pub fn check_collection_size(&self, size: usize) -> Result<(), BudgetLimit> {
+        match self.limits.max_collection_size {
+            Some(max) if size > max => Err(BudgetLimit::CollectionSize(max)),
+            _ => Ok(()),
+        }
+    }
+}
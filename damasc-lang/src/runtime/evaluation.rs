@@ -1,16 +1,30 @@
+use crate::runtime::assignment::{AssignmentError, AssignmentEvaluation};
 use crate::runtime::matching::PatternFail;
 use crate::syntax::expression::IfElseExpression;
+use crate::syntax::expression::LetExpression;
 use crate::syntax::expression::MatchExpression;
+use crate::syntax::expression::PipeExpression;
+use crate::syntax::expression::RangeExpression;
+use crate::syntax::expression::SliceExpression;
+use crate::syntax::expression::TryCatchExpression;
 use crate::syntax::location::Location;
+use crate::syntax::visit::{visit_expression, Visitor};
+use crate::value::Float;
 use crate::value::LambdaBinding;
 use crate::value::ValueArray;
 use crate::value::ValueObjectMap;
 use crate::value_type::ValueType;
-use std::{borrow::Cow, collections::BTreeMap};
+use std::{
+    borrow::Cow,
+    cmp::Ordering,
+    collections::{BTreeMap, HashSet},
+};
 
 use itertools::Itertools;
 
+use super::budget::{BudgetLimit, EvalBudget};
 use super::env::Environment;
+use super::native::{NativeRegistry, DEFAULT_NATIVES};
 use crate::runtime::matching::Matcher;
 use crate::syntax::expression::ArrayComprehension;
 use crate::syntax::expression::ComprehensionSource;
@@ -28,6 +42,26 @@ use crate::{
     },
 };
 
+/// The bare names that values are piped into, e.g. `f` in `x |> f`.
+fn piped_names<'a>(expression: &'a Expression<'a>) -> HashSet<&'a Identifier<'a>> {
+    struct PipedNames<'a>(HashSet<&'a Identifier<'a>>);
+
+    impl<'a> Visitor<'a> for PipedNames<'a> {
+        fn visit_expression(&mut self, expression: &'a Expression<'a>) {
+            if let ExpressionBody::Pipe(PipeExpression { function, .. }) = &expression.body {
+                if let ExpressionBody::Identifier(id) = &function.body {
+                    self.0.insert(id);
+                }
+            }
+            visit_expression(self, expression)
+        }
+    }
+
+    let mut names = PipedNames(HashSet::new());
+    names.visit_expression(expression);
+    names.0
+}
+
 #[derive(Debug, Clone)]
 enum EvalErrorPropagation<'s, 'v> {
     Shallow(EvalErrorReason<'s, 'v>),
@@ -43,6 +77,11 @@ impl<'s, 'v> EvalErrorPropagation<'s, 'v> {
     }
 }
 
+enum NumericOperands {
+    Integer(i64, i64),
+    Float(f64, f64),
+}
+
 #[derive(Debug, Clone)]
 pub struct EvalError<'s, 'v> {
     pub reason: EvalErrorReason<'s, 'v>,
@@ -70,34 +109,223 @@ pub enum EvalErrorReason<'s, 'v> {
     KeyNotDefined(Value<'s, 'v>, Value<'s, 'v>),
     OutOfBound(usize, isize),
     IntegerOverflow,
+    NonFiniteFloat,
     UnknownFunction(Identifier<'s>),
     PatternError(Box<PatternFail<'s, 'v>>),
     PatternExhaustionError(Value<'s, 'v>),
+    ArityMismatch(usize, usize),
+    BudgetExhausted(BudgetLimit),
+    TopologyError(HashSet<Identifier<'s>>),
+}
+
+impl EvalErrorReason<'_, '_> {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            EvalErrorReason::KindError(_) => "KindError",
+            EvalErrorReason::TypeError(_, _) => "TypeError",
+            EvalErrorReason::CollectionTypeError(_) => "CollectionTypeError",
+            EvalErrorReason::CastError(_, _) => "CastError",
+            EvalErrorReason::UnknownIdentifier(_) => "UnknownIdentifier",
+            EvalErrorReason::InvalidNumber(_) => "InvalidNumber",
+            EvalErrorReason::MathDivisionByZero => "MathDivisionByZero",
+            EvalErrorReason::KeyNotDefined(_, _) => "KeyNotDefined",
+            EvalErrorReason::OutOfBound(_, _) => "OutOfBound",
+            EvalErrorReason::IntegerOverflow => "IntegerOverflow",
+            EvalErrorReason::NonFiniteFloat => "NonFiniteFloat",
+            EvalErrorReason::UnknownFunction(_) => "UnknownFunction",
+            EvalErrorReason::PatternError(_) => "PatternError",
+            EvalErrorReason::PatternExhaustionError(_) => "PatternExhaustionError",
+            EvalErrorReason::ArityMismatch(_, _) => "ArityMismatch",
+            EvalErrorReason::BudgetExhausted(_) => "BudgetExhausted",
+            EvalErrorReason::TopologyError(_) => "TopologyError",
+        }
+    }
 }
 
-pub struct Evaluation<'e, 'i, 's, 'v> {
+impl std::fmt::Display for EvalErrorReason<'_, '_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EvalErrorReason::KindError(actual) => {
+                write!(f, "Expected a type, but found {}.", actual)
+            }
+            EvalErrorReason::TypeError(expected_type, value) => write!(
+                f,
+                "Expected a value of type {} but found {} of type {}.",
+                expected_type,
+                value,
+                value.get_type()
+            ),
+            EvalErrorReason::CollectionTypeError(val) => write!(
+                f,
+                "Value must be an Array, Object or String, but was {} of type {}.",
+                val,
+                val.get_type()
+            ),
+            EvalErrorReason::CastError(expected_type, val) => write!(
+                f,
+                "Value {} of type {} can not be converted into {}..",
+                val,
+                val.get_type(),
+                expected_type
+            ),
+            EvalErrorReason::UnknownIdentifier(identifier) => {
+                write!(f, "Unknown identifier {}.", identifier)
+            }
+            EvalErrorReason::InvalidNumber(lit) => {
+                write!(f, "Literal {} is not a valid number.", lit)
+            }
+            EvalErrorReason::MathDivisionByZero => write!(f, "Division By Zero"),
+            EvalErrorReason::KeyNotDefined(key, val) => {
+                write!(f, "Object {} has no key {}.", val, key)
+            }
+            EvalErrorReason::OutOfBound(expected_lengnth, actual_length) => write!(
+                f,
+                "Tried to access index {} of value that has a length of {}.",
+                actual_length, expected_lengnth,
+            ),
+            EvalErrorReason::IntegerOverflow => write!(f, "Integer overflow"),
+            EvalErrorReason::NonFiniteFloat => write!(f, "Result is not a finite number"),
+            EvalErrorReason::UnknownFunction(fun) => {
+                write!(f, "Function of name {} does not exist.", fun)
+            }
+            EvalErrorReason::PatternError(_e) => {
+                write!(f, "A pattern failed to match during evaluation.")
+            }
+            EvalErrorReason::PatternExhaustionError(val) => {
+                write!(
+                    f,
+                    "None of the provided cases was a match for value {}.",
+                    val
+                )
+            }
+            EvalErrorReason::ArityMismatch(expected, actual) => {
+                write!(f, "Expected {} arguments but got {}.", expected, actual)
+            }
+            EvalErrorReason::BudgetExhausted(limit) => {
+                write!(f, "Evaluation aborted after exceeding the {}.", limit)
+            }
+            EvalErrorReason::TopologyError(cycle) => write!(
+                f,
+                "These definitions cyclicly depend on each other: {}",
+                cycle.iter().map(|k| k.name.as_ref()).sorted().join(", ")
+            ),
+        }
+    }
+}
+
+impl<'s, 'v> EvalError<'s, 'v> {
+    // The object a `try`/`catch` expression binds the caught error to.
+    pub fn to_value<'a, 'b>(&self) -> Value<'a, 'b> {
+        let location = match self.location {
+            Some(Location { start, end }) => Value::Object(BTreeMap::from([
+                (
+                    Cow::Borrowed("start"),
+                    Cow::Owned(Value::Integer(start as i64)),
+                ),
+                (Cow::Borrowed("end"), Cow::Owned(Value::Integer(end as i64))),
+            ])),
+            None => Value::Null,
+        };
+
+        Value::Object(BTreeMap::from([
+            (
+                Cow::Borrowed("kind"),
+                Cow::Owned(Value::String(Cow::Borrowed(self.reason.kind()))),
+            ),
+            (
+                Cow::Borrowed("message"),
+                Cow::Owned(Value::String(Cow::Owned(self.reason.to_string()))),
+            ),
+            (Cow::Borrowed("location"), Cow::Owned(location)),
+        ]))
+    }
+}
+
+impl<'s, 'v> From<EvalErrorReason<'s, 'v>> for EvalError<'s, 'v> {
+    fn from(reason: EvalErrorReason<'s, 'v>) -> Self {
+        Self {
+            reason,
+            location: None,
+        }
+    }
+}
+
+pub struct Evaluation<'e, 'i: 's, 's, 'v: 's> {
     env: &'e Environment<'i, 's, 'v>,
+    natives: &'e NativeRegistry,
+    budget: Option<&'e EvalBudget>,
 }
 
 const EMPTY_ENV: &Environment = &Environment::new();
 
 impl Default for Evaluation<'static, 'static, 'static, 'static> {
     fn default() -> Self {
-        Self { env: EMPTY_ENV }
+        Self {
+            env: EMPTY_ENV,
+            natives: &DEFAULT_NATIVES,
+            budget: None,
+        }
     }
 }
 
 impl<'e, 'i: 's, 's, 'v: 's> Evaluation<'e, 'i, 's, 'v> {
     pub fn new(env: &'e Environment<'i, 's, 'v>) -> Self {
-        Self { env }
+        Self {
+            env,
+            natives: &DEFAULT_NATIVES,
+            budget: None,
+        }
+    }
+
+    pub fn with_natives(self, natives: &'e NativeRegistry) -> Self {
+        Self { natives, ..self }
+    }
+
+    pub fn with_budget(self, budget: Option<&'e EvalBudget>) -> Self {
+        Self { budget, ..self }
+    }
+
+    pub fn check_collection_size(&self, size: usize) -> Result<(), EvalError<'s, 'v>> {
+        match self.budget {
+            Some(budget) => budget
+                .check_collection_size(size)
+                .map_err(|limit| EvalErrorReason::BudgetExhausted(limit).into()),
+            None => Ok(()),
+        }
+    }
+
+    fn check_value_size(&self, value: &Value<'s, 'v>) -> Result<(), EvalErrorPropagation<'s, 'v>> {
+        let size = match value {
+            Value::String(s) => s.len(),
+            Value::Array(a) => a.len(),
+            Value::Object(o) => o.len(),
+            _ => return Ok(()),
+        };
+
+        self.check_collection_size(size)
+            .map_err(|e| EvalErrorPropagation::Shallow(e.reason))
     }
 
     pub fn eval_expr<'x: 's, 'y>(
         &self,
         expression: &'y Expression<'x>,
     ) -> Result<Value<'s, 'v>, EvalError<'s, 'v>> {
+        if let Some(budget) = self.budget {
+            budget
+                .step()
+                .map_err(|limit| expression.cause_error(EvalErrorReason::BudgetExhausted(limit)))?;
+        }
+
         match &expression.body {
             ExpressionBody::Array(vec) => self.eval_array(vec).map_err(|e| e.justify(expression)),
+            ExpressionBody::Binary(BinaryExpression {
+                operator: BinaryOperator::NullCoalesce,
+                left,
+                right,
+            }) => match self.eval_expr(left)? {
+                Value::Null => self.eval_expr(right),
+                value => Ok(value),
+            },
             ExpressionBody::Binary(BinaryExpression {
                 operator,
                 left,
@@ -120,13 +348,28 @@ impl<'e, 'i: 's, 's, 'v: 's> Evaluation<'e, 'i, 's, 'v> {
                 .eval_logic(operator, left, right)
                 .map_err(|e| e.justify(expression)),
             ExpressionBody::Member(MemberExpression {
-                object, property, ..
+                object,
+                property,
+                optional,
             }) => self.eval_expr(object).and_then(move |obj| {
-                self.eval_expr(property).and_then(move |prop| {
-                    self.eval_member(&obj, &prop)
-                        .map_err(|e| e.justify(expression))
-                })
+                if *optional && obj == Value::Null {
+                    return Ok(Value::Null);
+                }
+
+                self.eval_expr(property)
+                    .and_then(move |prop| match self.eval_member(&obj, &prop) {
+                        Err(EvalErrorPropagation::Shallow(
+                            EvalErrorReason::KeyNotDefined(..) | EvalErrorReason::OutOfBound(..),
+                        )) if *optional => Ok(Value::Null),
+                        result => result.map_err(|e| e.justify(expression)),
+                    })
             }),
+            ExpressionBody::Slice(slice) => {
+                self.eval_slice(slice).map_err(|e| e.justify(expression))
+            }
+            ExpressionBody::Range(range) => {
+                self.eval_range(range).map_err(|e| e.justify(expression))
+            }
             ExpressionBody::Object(props) => {
                 self.eval_object(props).map_err(|e| e.justify(expression))
             }
@@ -136,28 +379,24 @@ impl<'e, 'i: 's, 's, 'v: 's> Evaluation<'e, 'i, 's, 'v> {
                 self.eval_unary(operator, &v)
                     .map_err(|e| e.justify(expression))
             }),
-            ExpressionBody::Call(CallExpression { function, argument }) => self
-                .eval_call(function, &self.eval_expr(argument)?)
-                .map_err(|e| e.justify(expression)),
+            ExpressionBody::Call(CallExpression {
+                function,
+                arguments,
+            }) => {
+                let argument_values = arguments
+                    .iter()
+                    .map(|a| self.eval_expr(a))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                self.eval_call(function, &argument_values)
+                    .map_err(|e| e.justify(expression))
+            }
             ExpressionBody::Template(template) => self
                 .eval_template(template)
                 .map_err(|e| e.justify(expression)),
-            ExpressionBody::Abstraction(LambdaAbstraction { arguments, body }) => {
-                let new_env = match LambdaBinding::try_from_env(
-                    self.env,
-                    body.get_identifiers(),
-                    arguments.get_identifiers(),
-                ) {
-                    Ok(new_env) => new_env,
-                    Err(missing_id) => {
-                        return Err(expression.cause_error(EvalErrorReason::UnknownIdentifier(
-                            missing_id.deep_clone(),
-                        )))
-                    }
-                };
-
-                Ok(Value::Lambda(new_env, arguments.clone(), *body.clone()))
-            }
+            ExpressionBody::Abstraction(abstraction) => self
+                .eval_abstraction(abstraction)
+                .map_err(|e| e.justify(expression)),
             ExpressionBody::Application(app) => self
                 .eval_application(app)
                 .map_err(|e| e.justify(expression)),
@@ -173,6 +412,13 @@ impl<'e, 'i: 's, 's, 'v: 's> Evaluation<'e, 'i, 's, 'v> {
             ExpressionBody::Condition(if_else) => self
                 .eval_condition(if_else)
                 .map_err(|e| e.justify(expression)),
+            ExpressionBody::Let(let_expr) => {
+                self.eval_let(let_expr).map_err(|e| e.justify(expression))
+            }
+            ExpressionBody::TryCatch(try_catch) => self
+                .eval_try_catch(try_catch)
+                .map_err(|e| e.justify(expression)),
+            ExpressionBody::Pipe(pipe) => self.eval_pipe(pipe).map_err(|e| e.justify(expression)),
         }
     }
 
@@ -189,6 +435,15 @@ impl<'e, 'i: 's, 's, 'v: 's> Evaluation<'e, 'i, 's, 'v> {
                 .unwrap_or(Err(EvalErrorPropagation::Shallow(
                     EvalErrorReason::InvalidNumber(s.to_string()),
                 ))),
+            Literal::Float(s) => str::parse::<f64>(s)
+                .ok()
+                .filter(|f| f.is_finite())
+                .map(Float)
+                .map(Value::Float)
+                .map(Ok)
+                .unwrap_or(Err(EvalErrorPropagation::Shallow(
+                    EvalErrorReason::InvalidNumber(s.to_string()),
+                ))),
             Literal::Boolean(b) => Ok(Value::Boolean(*b)),
             Literal::Type(t) => Ok(Value::Type(*t)),
         }
@@ -201,193 +456,102 @@ impl<'e, 'i: 's, 's, 'v: 's> Evaluation<'e, 'i, 's, 'v> {
         right: &Value<'s, 'v>,
     ) -> Result<Value<'s, 'v>, EvalErrorPropagation<'s, 'v>> {
         match op {
-            BinaryOperator::StrictEqual => Ok(Value::Boolean(left == right)),
-            BinaryOperator::StrictNotEqual => Ok(Value::Boolean(left != right)),
-            BinaryOperator::LessThan => {
-                let Value::Integer(l) = left else {
-                    return Err(EvalErrorPropagation::Shallow(EvalErrorReason::TypeError(
-                        ValueType::Integer,
-                        left.clone(),
-                    )));
-                };
-                let Value::Integer(r) = right else {
-                    return Err(EvalErrorPropagation::Shallow(EvalErrorReason::TypeError(
-                        ValueType::Integer,
-                        right.clone(),
-                    )));
-                };
-                Ok(Value::Boolean(l < r))
-            }
-            BinaryOperator::GreaterThan => {
-                let Value::Integer(l) = left else {
-                    return Err(EvalErrorPropagation::Shallow(EvalErrorReason::TypeError(
-                        ValueType::Integer,
-                        left.clone(),
-                    )));
-                };
-                let Value::Integer(r) = right else {
-                    return Err(EvalErrorPropagation::Shallow(EvalErrorReason::TypeError(
-                        ValueType::Integer,
-                        right.clone(),
-                    )));
-                };
-                Ok(Value::Boolean(l > r))
-            }
-            BinaryOperator::LessThanEqual => {
-                let Value::Integer(l) = left else {
-                    return Err(EvalErrorPropagation::Shallow(EvalErrorReason::TypeError(
-                        ValueType::Integer,
-                        left.clone(),
-                    )));
-                };
-                let Value::Integer(r) = right else {
-                    return Err(EvalErrorPropagation::Shallow(EvalErrorReason::TypeError(
-                        ValueType::Integer,
-                        right.clone(),
-                    )));
-                };
-                Ok(Value::Boolean(l <= r))
-            }
-            BinaryOperator::GreaterThanEqual => {
-                let Value::Integer(l) = left else {
-                    return Err(EvalErrorPropagation::Shallow(EvalErrorReason::TypeError(
-                        ValueType::Integer,
-                        left.clone(),
-                    )));
-                };
-                let Value::Integer(r) = right else {
-                    return Err(EvalErrorPropagation::Shallow(EvalErrorReason::TypeError(
-                        ValueType::Integer,
-                        right.clone(),
-                    )));
-                };
-                Ok(Value::Boolean(l >= r))
-            }
-            BinaryOperator::Plus => {
-                let Value::Integer(l) = left else {
-                    return Err(EvalErrorPropagation::Shallow(EvalErrorReason::TypeError(
-                        ValueType::Integer,
-                        left.clone(),
-                    )));
-                };
-                let Value::Integer(r) = right else {
-                    return Err(EvalErrorPropagation::Shallow(EvalErrorReason::TypeError(
-                        ValueType::Integer,
-                        right.clone(),
-                    )));
-                };
-                l.checked_add(*r).map(Value::Integer).map(Ok).unwrap_or(Err(
-                    EvalErrorPropagation::Shallow(EvalErrorReason::IntegerOverflow),
-                ))
-            }
-            BinaryOperator::Minus => {
-                let Value::Integer(l) = left else {
-                    return Err(EvalErrorPropagation::Shallow(EvalErrorReason::TypeError(
-                        ValueType::Integer,
-                        left.clone(),
-                    )));
-                };
-                let Value::Integer(r) = right else {
-                    return Err(EvalErrorPropagation::Shallow(EvalErrorReason::TypeError(
-                        ValueType::Integer,
-                        right.clone(),
-                    )));
-                };
-                l.checked_sub(*r).map(Value::Integer).map(Ok).unwrap_or(Err(
-                    EvalErrorPropagation::Shallow(EvalErrorReason::IntegerOverflow),
-                ))
-            }
-            BinaryOperator::Times => {
-                let Value::Integer(l) = left else {
-                    return Err(EvalErrorPropagation::Shallow(EvalErrorReason::TypeError(
-                        ValueType::Integer,
-                        left.clone(),
-                    )));
-                };
-                let Value::Integer(r) = right else {
-                    return Err(EvalErrorPropagation::Shallow(EvalErrorReason::TypeError(
-                        ValueType::Integer,
-                        right.clone(),
-                    )));
-                };
-                l.checked_mul(*r).map(Value::Integer).map(Ok).unwrap_or(Err(
-                    EvalErrorPropagation::Shallow(EvalErrorReason::IntegerOverflow),
-                ))
-            }
-            BinaryOperator::Over => {
-                let Value::Integer(l) = left else {
-                    return Err(EvalErrorPropagation::Shallow(EvalErrorReason::TypeError(
-                        ValueType::Integer,
-                        left.clone(),
-                    )));
-                };
-                let Value::Integer(r) = right else {
-                    return Err(EvalErrorPropagation::Shallow(EvalErrorReason::TypeError(
-                        ValueType::Integer,
-                        right.clone(),
-                    )));
-                };
-                if *r == 0 {
-                    return Err(EvalErrorPropagation::Shallow(
-                        EvalErrorReason::MathDivisionByZero,
-                    ));
+            BinaryOperator::StrictEqual => Ok(Value::Boolean(Self::equal_values(left, right))),
+            BinaryOperator::StrictNotEqual => Ok(Value::Boolean(!Self::equal_values(left, right))),
+            BinaryOperator::LessThan => Ok(Value::Boolean(matches!(
+                Self::compare_operands(left, right)?,
+                Some(Ordering::Less)
+            ))),
+            BinaryOperator::GreaterThan => Ok(Value::Boolean(matches!(
+                Self::compare_operands(left, right)?,
+                Some(Ordering::Greater)
+            ))),
+            BinaryOperator::LessThanEqual => Ok(Value::Boolean(matches!(
+                Self::compare_operands(left, right)?,
+                Some(Ordering::Less | Ordering::Equal)
+            ))),
+            BinaryOperator::GreaterThanEqual => Ok(Value::Boolean(matches!(
+                Self::compare_operands(left, right)?,
+                Some(Ordering::Greater | Ordering::Equal)
+            ))),
+            BinaryOperator::Plus => self.eval_plus(left, right),
+            BinaryOperator::Minus => match Self::numeric_operands(left, right)? {
+                NumericOperands::Integer(l, r) => {
+                    l.checked_sub(r).map(Value::Integer).map(Ok).unwrap_or(Err(
+                        EvalErrorPropagation::Shallow(EvalErrorReason::IntegerOverflow),
+                    ))
                 }
-                l.checked_div(*r).map(Value::Integer).map(Ok).unwrap_or(Err(
-                    EvalErrorPropagation::Shallow(EvalErrorReason::IntegerOverflow),
-                ))
-            }
-            BinaryOperator::Mod => {
-                let Value::Integer(l) = left else {
-                    return Err(EvalErrorPropagation::Shallow(EvalErrorReason::TypeError(
-                        ValueType::Integer,
-                        left.clone(),
-                    )));
-                };
-                let Value::Integer(r) = right else {
-                    return Err(EvalErrorPropagation::Shallow(EvalErrorReason::TypeError(
-                        ValueType::Integer,
-                        right.clone(),
-                    )));
-                };
-                l.checked_rem(*r).map(Value::Integer).map(Ok).unwrap_or(Err(
-                    EvalErrorPropagation::Shallow(EvalErrorReason::IntegerOverflow),
-                ))
-            }
-            BinaryOperator::In => {
-                let Value::String(s) = left else {
-                    return Err(EvalErrorPropagation::Shallow(EvalErrorReason::TypeError(
-                        ValueType::String,
-                        left.clone(),
-                    )));
-                };
-                let Value::Object(o) = right else {
-                    return Err(EvalErrorPropagation::Shallow(EvalErrorReason::TypeError(
-                        ValueType::Object,
-                        right.clone(),
-                    )));
-                };
-                Ok(Value::Boolean(o.contains_key(s)))
-            }
-            BinaryOperator::PowerOf => {
-                let Value::Integer(l) = left else {
-                    return Err(EvalErrorPropagation::Shallow(EvalErrorReason::TypeError(
-                        ValueType::Integer,
-                        left.clone(),
-                    )));
-                };
-                let Value::Integer(r) = right else {
-                    return Err(EvalErrorPropagation::Shallow(EvalErrorReason::TypeError(
-                        ValueType::Integer,
-                        right.clone(),
-                    )));
-                };
-                l.checked_pow(*r as u32)
+                NumericOperands::Float(l, r) => Self::float_result(l - r),
+            },
+            BinaryOperator::Times => match Self::numeric_operands(left, right)? {
+                NumericOperands::Integer(l, r) => {
+                    l.checked_mul(r).map(Value::Integer).map(Ok).unwrap_or(Err(
+                        EvalErrorPropagation::Shallow(EvalErrorReason::IntegerOverflow),
+                    ))
+                }
+                NumericOperands::Float(l, r) => Self::float_result(l * r),
+            },
+            BinaryOperator::Over => match Self::numeric_operands(left, right)? {
+                NumericOperands::Integer(_, 0) => Err(EvalErrorPropagation::Shallow(
+                    EvalErrorReason::MathDivisionByZero,
+                )),
+                NumericOperands::Integer(l, r) => {
+                    l.checked_div(r).map(Value::Integer).map(Ok).unwrap_or(Err(
+                        EvalErrorPropagation::Shallow(EvalErrorReason::IntegerOverflow),
+                    ))
+                }
+                NumericOperands::Float(_, 0.0) => Err(EvalErrorPropagation::Shallow(
+                    EvalErrorReason::MathDivisionByZero,
+                )),
+                NumericOperands::Float(l, r) => Self::float_result(l / r),
+            },
+            BinaryOperator::Mod => match Self::numeric_operands(left, right)? {
+                NumericOperands::Integer(l, r) => {
+                    l.checked_rem(r).map(Value::Integer).map(Ok).unwrap_or(Err(
+                        EvalErrorPropagation::Shallow(EvalErrorReason::IntegerOverflow),
+                    ))
+                }
+                NumericOperands::Float(_, 0.0) => Err(EvalErrorPropagation::Shallow(
+                    EvalErrorReason::MathDivisionByZero,
+                )),
+                NumericOperands::Float(l, r) => Self::float_result(l % r),
+            },
+            BinaryOperator::In => match right {
+                Value::Object(o) => {
+                    let Value::String(s) = left else {
+                        return Err(EvalErrorPropagation::Shallow(EvalErrorReason::TypeError(
+                            ValueType::String,
+                            left.clone(),
+                        )));
+                    };
+                    Ok(Value::Boolean(o.contains_key(s)))
+                }
+                Value::Array(a) => Ok(Value::Boolean(
+                    a.iter().any(|v| Self::equal_values(v, left)),
+                )),
+                Value::String(haystack) => {
+                    let Value::String(needle) = left else {
+                        return Err(EvalErrorPropagation::Shallow(EvalErrorReason::TypeError(
+                            ValueType::String,
+                            left.clone(),
+                        )));
+                    };
+                    Ok(Value::Boolean(haystack.contains(needle.as_ref())))
+                }
+                _ => Err(EvalErrorPropagation::Shallow(
+                    EvalErrorReason::CollectionTypeError(right.clone()),
+                )),
+            },
+            BinaryOperator::PowerOf => match Self::numeric_operands(left, right)? {
+                NumericOperands::Integer(l, r) => l
+                    .checked_pow(r as u32)
                     .map(Value::Integer)
                     .map(Ok)
                     .unwrap_or(Err(EvalErrorPropagation::Shallow(
                         EvalErrorReason::IntegerOverflow,
-                    )))
-            }
+                    ))),
+                NumericOperands::Float(l, r) => Self::float_result(l.powf(r)),
+            },
             BinaryOperator::Is => {
                 let Value::Type(specified_type) = right else {
                     return Err(EvalErrorPropagation::Shallow(EvalErrorReason::KindError(
@@ -414,6 +578,171 @@ impl<'e, 'i: 's, 's, 'v: 's> Evaluation<'e, 'i, 's, 'v> {
 
                 Ok(v)
             }
+            // `eval_expr` short-circuits `??` before evaluating the right side.
+            BinaryOperator::NullCoalesce => unreachable!("?? is evaluated lazily"),
+        }
+    }
+
+    fn eval_plus(
+        &self,
+        left: &Value<'s, 'v>,
+        right: &Value<'s, 'v>,
+    ) -> Result<Value<'s, 'v>, EvalErrorPropagation<'s, 'v>> {
+        let result = match (left, right) {
+            (Value::String(l), Value::String(r)) => Value::String(Cow::Owned(format!("{l}{r}"))),
+            (Value::Array(l), Value::Array(r)) => {
+                Value::Array(l.iter().chain(r.iter()).cloned().collect())
+            }
+            (Value::Object(l), Value::Object(r)) => Value::Object(
+                l.iter()
+                    .chain(r.iter())
+                    .map(|(k, v)| (k.clone(), v.clone()))
+                    .collect(),
+            ),
+            (Value::String(_) | Value::Array(_) | Value::Object(_), _) => {
+                return Err(EvalErrorPropagation::Shallow(EvalErrorReason::TypeError(
+                    left.get_type(),
+                    right.clone(),
+                )))
+            }
+            (_, Value::String(_) | Value::Array(_) | Value::Object(_)) => {
+                return Err(EvalErrorPropagation::Shallow(EvalErrorReason::TypeError(
+                    right.get_type(),
+                    left.clone(),
+                )))
+            }
+            _ => {
+                return match Self::numeric_operands(left, right)? {
+                    NumericOperands::Integer(l, r) => {
+                        l.checked_add(r).map(Value::Integer).map(Ok).unwrap_or(Err(
+                            EvalErrorPropagation::Shallow(EvalErrorReason::IntegerOverflow),
+                        ))
+                    }
+                    NumericOperands::Float(l, r) => Self::float_result(l + r),
+                }
+            }
+        };
+
+        self.check_value_size(&result)?;
+
+        Ok(result)
+    }
+
+    pub(crate) fn eval_literal<'x>(
+        &self,
+        literal: &'x Literal<'x>,
+    ) -> Result<Value<'s, 'v>, EvalError<'s, 'v>> {
+        self.eval_lit(literal).map_err(|e| match e {
+            EvalErrorPropagation::Shallow(reason) => reason.into(),
+            EvalErrorPropagation::Nested(e) => e,
+        })
+    }
+
+    // Floats that overflow have no literal form, so they are not values.
+    fn float_result(result: f64) -> Result<Value<'s, 'v>, EvalErrorPropagation<'s, 'v>> {
+        if result.is_finite() {
+            Ok(Value::Float(Float(result)))
+        } else {
+            Err(EvalErrorPropagation::Shallow(
+                EvalErrorReason::NonFiniteFloat,
+            ))
+        }
+    }
+
+    /// Compares two values like `==` does: numbers by their numeric value,
+    /// collections element by element and everything else structurally.
+    pub(crate) fn equal_values(left: &Value<'s, 'v>, right: &Value<'s, 'v>) -> bool {
+        match (left, right) {
+            (Value::Integer(l), Value::Float(r)) | (Value::Float(r), Value::Integer(l)) => {
+                *l as f64 == r.0
+            }
+            (Value::Float(l), Value::Float(r)) => l.0 == r.0,
+            (Value::Array(l), Value::Array(r)) => {
+                l.len() == r.len() && l.iter().zip(r).all(|(l, r)| Self::equal_values(l, r))
+            }
+            (Value::Object(l), Value::Object(r)) => {
+                l.len() == r.len()
+                    && l.iter()
+                        .zip(r)
+                        .all(|((lk, lv), (rk, rv))| lk == rk && Self::equal_values(lv, rv))
+            }
+            _ => left == right,
+        }
+    }
+
+    /// Orders two values like the comparison operators do, `None` if they
+    /// are not comparable.
+    pub(crate) fn compare_values(left: &Value<'s, 'v>, right: &Value<'s, 'v>) -> Option<Ordering> {
+        Self::compare_operands(left, right).ok().flatten()
+    }
+
+    /// Orders two values like `<` does, failing if they are not comparable.
+    pub(crate) fn order_values(
+        left: &Value<'s, 'v>,
+        right: &Value<'s, 'v>,
+    ) -> Result<Ordering, EvalError<'s, 'v>> {
+        match Self::compare_operands(left, right) {
+            Ok(Some(ordering)) => Ok(ordering),
+            Ok(None) => Err(EvalErrorReason::TypeError(left.get_type(), right.clone()).into()),
+            Err(EvalErrorPropagation::Shallow(reason)) => Err(reason.into()),
+            Err(EvalErrorPropagation::Nested(error)) => Err(error),
+        }
+    }
+
+    // Strings and arrays are ordered lexicographically, numbers numerically.
+    // `None` means the operands are unordered, which is only the case for NaN.
+    fn compare_operands(
+        left: &Value<'s, 'v>,
+        right: &Value<'s, 'v>,
+    ) -> Result<Option<Ordering>, EvalErrorPropagation<'s, 'v>> {
+        match (left, right) {
+            (Value::String(l), Value::String(r)) => Ok(Some(l.cmp(r))),
+            (Value::Array(l), Value::Array(r)) => {
+                for (l, r) in l.iter().zip(r.iter()) {
+                    match Self::compare_operands(l, r)? {
+                        Some(Ordering::Equal) => continue,
+                        ordering => return Ok(ordering),
+                    }
+                }
+                Ok(Some(l.len().cmp(&r.len())))
+            }
+            (Value::String(_) | Value::Array(_), _) => Err(EvalErrorPropagation::Shallow(
+                EvalErrorReason::TypeError(left.get_type(), right.clone()),
+            )),
+            (_, Value::String(_) | Value::Array(_)) => Err(EvalErrorPropagation::Shallow(
+                EvalErrorReason::TypeError(right.get_type(), left.clone()),
+            )),
+            _ => Ok(match Self::numeric_operands(left, right)? {
+                NumericOperands::Integer(l, r) => Some(l.cmp(&r)),
+                NumericOperands::Float(l, r) => l.partial_cmp(&r),
+            }),
+        }
+    }
+
+    fn numeric_operands(
+        left: &Value<'s, 'v>,
+        right: &Value<'s, 'v>,
+    ) -> Result<NumericOperands, EvalErrorPropagation<'s, 'v>> {
+        match (left, right) {
+            (Value::Integer(l), Value::Integer(r)) => Ok(NumericOperands::Integer(*l, *r)),
+            (Value::Integer(l), Value::Float(r)) => Ok(NumericOperands::Float(*l as f64, r.0)),
+            (Value::Float(l), Value::Integer(r)) => Ok(NumericOperands::Float(l.0, *r as f64)),
+            (Value::Float(l), Value::Float(r)) => Ok(NumericOperands::Float(l.0, r.0)),
+            (Value::Integer(_), _) => Err(EvalErrorPropagation::Shallow(
+                EvalErrorReason::TypeError(ValueType::Integer, right.clone()),
+            )),
+            (Value::Float(_), _) => Err(EvalErrorPropagation::Shallow(EvalErrorReason::TypeError(
+                ValueType::Float,
+                right.clone(),
+            ))),
+            (_, Value::Float(_)) => Err(EvalErrorPropagation::Shallow(EvalErrorReason::TypeError(
+                ValueType::Float,
+                left.clone(),
+            ))),
+            _ => Err(EvalErrorPropagation::Shallow(EvalErrorReason::TypeError(
+                ValueType::Integer,
+                left.clone(),
+            ))),
         }
     }
 
@@ -423,24 +752,22 @@ impl<'e, 'i: 's, 's, 'v: 's> Evaluation<'e, 'i, 's, 'v> {
         arg: &Value<'s, 'v>,
     ) -> Result<Value<'s, 'v>, EvalErrorPropagation<'s, 'v>> {
         match op {
-            UnaryOperator::Minus => {
-                let Value::Integer(v) = arg else {
-                    return Err(EvalErrorPropagation::Shallow(EvalErrorReason::TypeError(
-                        ValueType::Integer,
-                        arg.clone(),
-                    )));
-                };
-                Ok(Value::Integer(-v))
-            }
-            UnaryOperator::Plus => {
-                let Value::Integer(v) = arg else {
-                    return Err(EvalErrorPropagation::Shallow(EvalErrorReason::TypeError(
-                        ValueType::Integer,
-                        arg.clone(),
-                    )));
-                };
-                Ok(Value::Integer(*v))
-            }
+            UnaryOperator::Minus => match arg {
+                Value::Integer(v) => Ok(Value::Integer(-v)),
+                Value::Float(v) => Ok(Value::Float(Float(-v.0))),
+                _ => Err(EvalErrorPropagation::Shallow(EvalErrorReason::TypeError(
+                    ValueType::Integer,
+                    arg.clone(),
+                ))),
+            },
+            UnaryOperator::Plus => match arg {
+                Value::Integer(v) => Ok(Value::Integer(*v)),
+                Value::Float(v) => Ok(Value::Float(*v)),
+                _ => Err(EvalErrorPropagation::Shallow(EvalErrorReason::TypeError(
+                    ValueType::Integer,
+                    arg.clone(),
+                ))),
+            },
             UnaryOperator::Not => {
                 let Value::Boolean(b) = arg else {
                     return Err(EvalErrorPropagation::Shallow(EvalErrorReason::TypeError(
@@ -513,6 +840,9 @@ impl<'e, 'i: 's, 's, 'v: 's> Evaluation<'e, 'i, 's, 'v> {
             }
         }
 
+        self.check_collection_size(into.len())
+            .map_err(|e| EvalErrorPropagation::Shallow(e.reason))?;
+
         Ok(into)
     }
 
@@ -551,6 +881,9 @@ impl<'e, 'i: 's, 's, 'v: 's> Evaluation<'e, 'i, 's, 'v> {
             }
         }
 
+        self.check_collection_size(target.len())
+            .map_err(|e| EvalErrorPropagation::Shallow(e.reason))?;
+
         Ok(target)
     }
 
@@ -641,15 +974,23 @@ impl<'e, 'i: 's, 's, 'v: 's> Evaluation<'e, 'i, 's, 'v> {
                         prop.clone(),
                     )));
                 };
+                let len = s.chars().count();
                 let index = if *i < 0 {
-                    s.len() - i.unsigned_abs() as usize
+                    let sub = i.unsigned_abs() as usize;
+                    if len < sub {
+                        return Err(EvalErrorPropagation::Shallow(EvalErrorReason::OutOfBound(
+                            len,
+                            *i as isize,
+                        )));
+                    }
+                    len - sub
                 } else {
                     *i as usize
                 };
 
-                let Some(val) = s.chars().nth(index).map(|v| v.clone().to_string()) else {
+                let Some(val) = s.chars().nth(index).map(|v| v.to_string()) else {
                     return Err(EvalErrorPropagation::Shallow(EvalErrorReason::OutOfBound(
-                        s.len(),
+                        len,
                         index as isize,
                     )));
                 };
@@ -663,6 +1004,91 @@ impl<'e, 'i: 's, 's, 'v: 's> Evaluation<'e, 'i, 's, 'v> {
         }
     }
 
+    fn eval_integer<'x: 's>(
+        &self,
+        expression: &Expression<'x>,
+    ) -> Result<i64, EvalErrorPropagation<'s, 'v>> {
+        match self
+            .eval_expr(expression)
+            .map_err(EvalErrorPropagation::Nested)?
+        {
+            Value::Integer(i) => Ok(i),
+            other => Err(EvalErrorPropagation::Shallow(EvalErrorReason::TypeError(
+                ValueType::Integer,
+                other,
+            ))),
+        }
+    }
+
+    fn eval_slice<'x: 's>(
+        &self,
+        slice: &SliceExpression<'x>,
+    ) -> Result<Value<'s, 'v>, EvalErrorPropagation<'s, 'v>> {
+        let object = self
+            .eval_expr(&slice.object)
+            .map_err(EvalErrorPropagation::Nested)?;
+        let start = slice
+            .start
+            .as_ref()
+            .map(|s| self.eval_integer(s))
+            .transpose()?;
+        let end = slice
+            .end
+            .as_ref()
+            .map(|e| self.eval_integer(e))
+            .transpose()?;
+
+        match &object {
+            Value::Array(a) => {
+                let (from, to) = Self::slice_bounds(a.len(), start, end);
+                Ok(Value::Array(a[from..to].to_vec()))
+            }
+            Value::String(s) => {
+                let (from, to) = Self::slice_bounds(s.chars().count(), start, end);
+                Ok(Value::String(Cow::Owned(
+                    s.chars().skip(from).take(to - from).collect(),
+                )))
+            }
+            _ => Err(EvalErrorPropagation::Shallow(
+                EvalErrorReason::CollectionTypeError(object.clone()),
+            )),
+        }
+    }
+
+    // Negative bounds count from the end, bounds outside of the collection
+    // are clamped, so slicing never fails.
+    fn slice_bounds(len: usize, start: Option<i64>, end: Option<i64>) -> (usize, usize) {
+        let resolve = |i: i64| {
+            if i < 0 {
+                len.saturating_sub(usize::try_from(i.unsigned_abs()).unwrap_or(usize::MAX))
+            } else {
+                usize::try_from(i).unwrap_or(usize::MAX).min(len)
+            }
+        };
+
+        let from = start.map(resolve).unwrap_or(0);
+        let to = end.map(resolve).unwrap_or(len);
+
+        (from, to.max(from))
+    }
+
+    fn eval_range<'x: 's>(
+        &self,
+        range: &RangeExpression<'x>,
+    ) -> Result<Value<'s, 'v>, EvalErrorPropagation<'s, 'v>> {
+        let start = self.eval_integer(&range.start)?;
+        let end = self.eval_integer(&range.end)?;
+
+        self.check_collection_size(usize::try_from(end.saturating_sub(start)).unwrap_or(0))
+            .map_err(|e| EvalErrorPropagation::Shallow(e.reason))?;
+
+        Ok(Value::Array(
+            (start..end)
+                .map(|i| Cow::Owned(Value::Integer(i)))
+                .collect(),
+        ))
+    }
+
     fn eval_identifier(
         &self,
         id: &Identifier<'s>,
@@ -679,107 +1105,64 @@ impl<'e, 'i: 's, 's, 'v: 's> Evaluation<'e, 'i, 's, 'v> {
     fn eval_call(
         &self,
         function: &Identifier<'s>,
-        argument: &Value<'s, 'v>,
+        arguments: &[Value<'s, 'v>],
     ) -> Result<Value<'s, 'v>, EvalErrorPropagation<'s, 'v>> {
-        Ok(match function.name.as_ref() {
-            "length" => Value::Integer(match argument {
-                Value::String(s) => s.len() as i64,
-                Value::Array(a) => a.len() as i64,
-                Value::Object(o) => o.len() as i64,
-                _ => {
-                    return Err(EvalErrorPropagation::Shallow(
-                        EvalErrorReason::CollectionTypeError(argument.clone()),
-                    ))
-                }
-            }),
-            "keys" => Value::Array(match argument {
-                Value::Object(o) => o
-                    .keys()
-                    .map(|k| Cow::Owned(Value::String(Cow::Owned(k.to_string()))))
-                    .collect(),
-                _ => {
-                    return Err(EvalErrorPropagation::Shallow(EvalErrorReason::TypeError(
-                        ValueType::Object,
-                        argument.clone(),
-                    )))
-                }
-            }),
-            "values" => Value::Array(match argument {
-                Value::Object(o) => o.values().cloned().collect(),
-                _ => {
-                    return Err(EvalErrorPropagation::Shallow(EvalErrorReason::TypeError(
-                        ValueType::Object,
-                        argument.clone(),
-                    )))
-                }
-            }),
-            "env" => Value::Object(match argument {
-                Value::Lambda(env, _, _) => env
-                    .bindings
-                    .iter()
-                    .map(|(k, v)| (Cow::Owned(k.to_string()), Cow::Owned(v.to_owned())))
-                    .collect(),
-                _ => {
-                    return Err(EvalErrorPropagation::Shallow(EvalErrorReason::TypeError(
-                        ValueType::Lambda,
-                        argument.clone(),
-                    )))
-                }
+        let Some(native) = self.natives.get(function.name.as_ref()) else {
+            return Err(EvalErrorPropagation::Shallow(
+                EvalErrorReason::UnknownFunction(function.clone()),
+            ));
+        };
+
+        native
+            .signature()
+            .check(arguments)
+            .map_err(EvalErrorPropagation::Shallow)?;
+
+        let result = native.call(self, arguments).map_err(|e| match e.location {
+            Some(_) => EvalErrorPropagation::Nested(e),
+            None => EvalErrorPropagation::Shallow(e.reason),
+        })?;
+
+        self.check_value_size(&result)?;
+
+        Ok(result)
+    }
+
+    fn eval_abstraction<'x: 's>(
+        &self,
+        LambdaAbstraction {
+            name,
+            arguments,
+            body,
+        }: &LambdaAbstraction<'x>,
+    ) -> Result<Value<'s, 'v>, EvalErrorPropagation<'s, 'v>> {
+        // A native function that values are piped into by its bare name is
+        // not captured from the environment.
+        let piped = piped_names(body);
+        let new_env = LambdaBinding::try_from_env(
+            self.env,
+            body.get_identifiers().filter(|id| {
+                self.env.bindings.contains_key(id)
+                    || !piped.contains(id)
+                    || self.natives.get(&id.name).is_none()
             }),
-            "rebind" => {
-                match argument {
-                    Value::Array(arr) => {
-                        let Some(x) = arr.first() else {
-                            return Err(EvalErrorPropagation::Shallow(
-                                EvalErrorReason::OutOfBound(arr.len(), 1),
-                            ));
-                        };
-
-                        let Value::Lambda(env, pattern, expression) = x.clone().into_owned() else {
-                            return Err(EvalErrorPropagation::Shallow(EvalErrorReason::TypeError(
-                                ValueType::Lambda,
-                                x.clone().into_owned(),
-                            )));
-                        };
-
-                        let Some(y) = arr.get(1) else {
-                            return Err(EvalErrorPropagation::Shallow(
-                                EvalErrorReason::OutOfBound(arr.len(), 2),
-                            ));
-                        };
-
-                        let Value::Object(obj) = y.clone().into_owned() else {
-                            return Err(EvalErrorPropagation::Shallow(EvalErrorReason::TypeError(
-                                ValueType::Object,
-                                y.clone().into_owned(),
-                            )));
-                        };
-
-                        let mut new_env = env.clone();
-                        new_env.replace(obj.into_iter().map(|(k, v)| {
-                            (
-                                Identifier::new_owned(k.into_owned()),
-                                v.clone().into_owned(),
-                            )
-                        }));
-
-                        Value::Lambda(new_env, pattern.clone(), expression.clone())
-                    }
-                    _ => {
-                        return Err(EvalErrorPropagation::Shallow(EvalErrorReason::TypeError(
-                            ValueType::Array,
-                            argument.clone(),
-                        )))
-                    }
-                }
-            }
-            "type" => Value::Type(argument.get_type()),
-            _ => {
-                return Err(EvalErrorPropagation::Shallow(
-                    EvalErrorReason::UnknownFunction(function.clone()),
-                ))
-            }
-        })
+            arguments
+                .iter()
+                .flat_map(|a| a.get_identifiers())
+                .chain(name),
+        )
+        .map_err(|missing_id| {
+            EvalErrorPropagation::Shallow(EvalErrorReason::UnknownIdentifier(
+                missing_id.deep_clone(),
+            ))
+        })?;
+
+        Ok(Value::Lambda(
+            new_env,
+            name.clone(),
+            arguments.clone(),
+            *body.clone(),
+        ))
     }
 
     fn eval_template<'x: 's, 'y>(
@@ -812,7 +1195,11 @@ impl<'e, 'i: 's, 's, 'v: 's> Evaluation<'e, 'i, 's, 'v> {
             .chain(Some(Ok(Cow::Owned(template.suffix.as_ref().into()))))
             .collect::<Result<Vec<Cow<'s, str>>, _>>()?;
 
-        return Ok(Value::String(Cow::Owned(joined.join(""))));
+        let result = Value::String(Cow::Owned(joined.join("")));
+
+        self.check_value_size(&result)?;
+
+        Ok(result)
     }
 
     fn eval_application<'x: 's>(
@@ -822,34 +1209,90 @@ impl<'e, 'i: 's, 's, 'v: 's> Evaluation<'e, 'i, 's, 'v> {
         let lambda = self
             .eval_expr(&app.lambda)
             .map_err(EvalErrorPropagation::Nested)?;
-        let param = self
-            .eval_expr(&app.parameter)
+        let params = app
+            .parameters
+            .iter()
+            .map(|p| self.eval_expr(p))
+            .collect::<Result<Vec<_>, _>>()
             .map_err(EvalErrorPropagation::Nested)?;
 
-        let Value::Lambda(bindings, pattern, lambda_body) = lambda else {
+        self.eval_lambda(&lambda, &params)
+    }
+
+    pub fn apply_lambda(
+        &self,
+        lambda: &Value<'s, 'v>,
+        arguments: &[Value<'s, 'v>],
+    ) -> Result<Value<'s, 'v>, EvalError<'s, 'v>> {
+        self.eval_lambda(lambda, arguments).map_err(|e| match e {
+            EvalErrorPropagation::Shallow(reason) => reason.into(),
+            EvalErrorPropagation::Nested(e) => e,
+        })
+    }
+
+    fn eval_lambda(
+        &self,
+        lambda: &Value<'s, 'v>,
+        arguments: &[Value<'s, 'v>],
+    ) -> Result<Value<'s, 'v>, EvalErrorPropagation<'s, 'v>> {
+        let Some(budget) = self.budget else {
+            return self.eval_lambda_body(lambda, arguments);
+        };
+
+        budget.enter_call().map_err(|limit| {
+            EvalErrorPropagation::Shallow(EvalErrorReason::BudgetExhausted(limit))
+        })?;
+        let result = self.eval_lambda_body(lambda, arguments);
+        budget.exit_call();
+
+        result
+    }
+
+    fn eval_lambda_body(
+        &self,
+        lambda: &Value<'s, 'v>,
+        arguments: &[Value<'s, 'v>],
+    ) -> Result<Value<'s, 'v>, EvalErrorPropagation<'s, 'v>> {
+        let Value::Lambda(bindings, name, patterns, lambda_body) = lambda else {
             return Err(EvalErrorPropagation::Shallow(EvalErrorReason::TypeError(
                 ValueType::Lambda,
                 lambda.clone(),
             )));
         };
 
-        let local_env = bindings.into();
-        let matcher = Matcher::new(&local_env);
+        if patterns.len() != arguments.len() {
+            return Err(EvalErrorPropagation::Shallow(
+                EvalErrorReason::ArityMismatch(patterns.len(), arguments.len()),
+            ));
+        }
 
-        let new_env = match matcher.match_pattern(Environment::new(), &pattern, &param) {
-            Err(e) => {
-                return Err(EvalErrorPropagation::Shallow(
-                    EvalErrorReason::PatternError(Box::new(e)),
-                ));
-            }
-            Ok(new_env) => new_env,
-        };
+        let mut local_env: Environment = bindings.clone().into();
+        if let Some(name) = name {
+            local_env.bindings.insert(name.clone(), lambda.clone());
+        }
+        let matcher = Matcher::new(&local_env)
+            .with_natives(self.natives)
+            .with_budget(self.budget);
+
+        let mut new_env = Environment::new();
+        for (pattern, argument) in std::iter::zip(patterns, arguments) {
+            new_env = match matcher.match_pattern(new_env, pattern, argument) {
+                Err(e) => {
+                    return Err(EvalErrorPropagation::Shallow(
+                        EvalErrorReason::PatternError(Box::new(e)),
+                    ));
+                }
+                Ok(new_env) => new_env,
+            };
+        }
 
         let local_env = matcher.outer_env.combine_with_override(&new_env);
-        let local_eval = Evaluation::new(&local_env);
+        let local_eval = Evaluation::new(&local_env)
+            .with_natives(self.natives)
+            .with_budget(self.budget);
 
         local_eval
-            .eval_expr(&lambda_body)
+            .eval_expr(lambda_body)
             .map_err(EvalErrorPropagation::Nested)
     }
 
@@ -863,7 +1306,12 @@ impl<'e, 'i: 's, 's, 'v: 's> Evaluation<'e, 'i, 's, 'v> {
                 |current_envs, source| {
                     Box::new(
                         current_envs
-                            .map(|e| Evaluation::new(&e?).eval_comprehension_source(source))
+                            .map(|e| {
+                                Evaluation::new(&e?)
+                                    .with_natives(self.natives)
+                                    .with_budget(self.budget)
+                                    .eval_comprehension_source(source)
+                            })
                             .flatten_ok(),
                     )
                 },
@@ -871,7 +1319,9 @@ impl<'e, 'i: 's, 's, 'v: 's> Evaluation<'e, 'i, 's, 'v> {
 
         envs.try_fold(vec![], |result, e| {
             let binding = e?;
-            let eval = Evaluation::new(&binding);
+            let eval = Evaluation::new(&binding)
+                .with_natives(self.natives)
+                .with_budget(self.budget);
 
             eval.eval_into_array(result, &comp.projection)
         })
@@ -895,7 +1345,9 @@ impl<'e, 'i: 's, 's, 'v: 's> Evaluation<'e, 'i, 's, 'v> {
         let mut results = vec![];
 
         for val in vals {
-            let matcher = Matcher::new(self.env);
+            let matcher = Matcher::new(self.env)
+                .with_natives(self.natives)
+                .with_budget(self.budget);
             let new_env = match matcher.match_pattern(Environment::new(), &source.pattern, &val) {
                 Err(err) => {
                     if source.strong_pattern {
@@ -910,7 +1362,9 @@ impl<'e, 'i: 's, 's, 'v: 's> Evaluation<'e, 'i, 's, 'v> {
             };
 
             let local_env = matcher.outer_env.combine_with_override(&new_env);
-            let local_eval = Evaluation::new(&local_env);
+            let local_eval = Evaluation::new(&local_env)
+                .with_natives(self.natives)
+                .with_budget(self.budget);
 
             if let Some(p) = &source.predicate {
                 let pred_result = local_eval
@@ -945,7 +1399,12 @@ impl<'e, 'i: 's, 's, 'v: 's> Evaluation<'e, 'i, 's, 'v> {
                 |current_envs, source| {
                     Box::new(
                         current_envs
-                            .map(|e| Evaluation::new(&e?).eval_comprehension_source(source))
+                            .map(|e| {
+                                Evaluation::new(&e?)
+                                    .with_natives(self.natives)
+                                    .with_budget(self.budget)
+                                    .eval_comprehension_source(source)
+                            })
                             .flatten_ok(),
                     )
                 },
@@ -953,7 +1412,9 @@ impl<'e, 'i: 's, 's, 'v: 's> Evaluation<'e, 'i, 's, 'v> {
 
         envs.try_fold(BTreeMap::new(), |result, e| {
             let binding = e?;
-            let eval = Evaluation::new(&binding);
+            let eval = Evaluation::new(&binding)
+                .with_natives(self.natives)
+                .with_budget(self.budget);
 
             eval.eval_into_object(result, &comp.projection)
         })
@@ -969,7 +1430,9 @@ impl<'e, 'i: 's, 's, 'v: 's> Evaluation<'e, 'i, 's, 'v> {
             .map_err(EvalErrorPropagation::Nested)?;
 
         for case in &match_expr.cases {
-            let matcher = Matcher::new(self.env);
+            let matcher = Matcher::new(self.env)
+                .with_natives(self.natives)
+                .with_budget(self.budget);
             let new_env =
                 match matcher.match_pattern(Environment::new(), &case.pattern, &subject_value) {
                     Err(_) => continue,
@@ -977,7 +1440,9 @@ impl<'e, 'i: 's, 's, 'v: 's> Evaluation<'e, 'i, 's, 'v> {
                 };
 
             let local_env = matcher.outer_env.combine_with_override(&new_env);
-            let local_eval = Evaluation::new(&local_env);
+            let local_eval = Evaluation::new(&local_env)
+                .with_natives(self.natives)
+                .with_budget(self.budget);
 
             if let Some(guard) = &case.guard {
                 let guard_val = local_eval
@@ -1030,4 +1495,112 @@ impl<'e, 'i: 's, 's, 'v: 's> Evaluation<'e, 'i, 's, 'v> {
             return Ok(Value::Null);
         }
     }
+
+    fn eval_let<'x: 's>(
+        &self,
+        let_expr: &LetExpression<'x>,
+    ) -> Result<Value<'s, 'v>, EvalErrorPropagation<'s, 'v>> {
+        let new_env = AssignmentEvaluation::new(self.env)
+            .with_natives(self.natives)
+            .with_budget(self.budget)
+            .eval_assigment_set(let_expr.assignments.clone())
+            .map_err(|e| match e {
+                AssignmentError::EvalError(e) => EvalErrorPropagation::Nested(e),
+                AssignmentError::MatchError(fail) => {
+                    EvalErrorPropagation::Shallow(EvalErrorReason::PatternError(Box::new(fail)))
+                }
+                AssignmentError::TopologyError(cycle) => {
+                    EvalErrorPropagation::Shallow(EvalErrorReason::TopologyError(cycle))
+                }
+            })?;
+
+        let local_env = self.env.combine_with_override(&new_env);
+        Evaluation::new(&local_env)
+            .with_natives(self.natives)
+            .with_budget(self.budget)
+            .eval_expr(&let_expr.body)
+            .map_err(EvalErrorPropagation::Nested)
+    }
+
+    fn eval_pipe<'x: 's>(
+        &self,
+        pipe: &PipeExpression<'x>,
+    ) -> Result<Value<'s, 'v>, EvalErrorPropagation<'s, 'v>> {
+        let value = self
+            .eval_expr(&pipe.value)
+            .map_err(EvalErrorPropagation::Nested)?;
+
+        // The piped value is prepended to the arguments of the call or
+        // application on the right, so `x |> f(y)` behaves like `f(x, y)`.
+        // A bare name that is not bound in the environment names a native function.
+        match &pipe.function.body {
+            ExpressionBody::Identifier(function) if !self.env.bindings.contains_key(function) => {
+                self.eval_call(function, &[value])
+            }
+            ExpressionBody::Call(CallExpression {
+                function,
+                arguments,
+            }) => {
+                let argument_values = std::iter::once(Ok(value))
+                    .chain(arguments.iter().map(|a| self.eval_expr(a)))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(EvalErrorPropagation::Nested)?;
+
+                self.eval_call(function, &argument_values)
+            }
+            ExpressionBody::Application(LambdaApplication { lambda, parameters }) => {
+                let lambda = self
+                    .eval_expr(lambda)
+                    .map_err(EvalErrorPropagation::Nested)?;
+                let params = std::iter::once(Ok(value))
+                    .chain(parameters.iter().map(|p| self.eval_expr(p)))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(EvalErrorPropagation::Nested)?;
+
+                self.eval_lambda(&lambda, &params)
+            }
+            _ => {
+                let lambda = self
+                    .eval_expr(&pipe.function)
+                    .map_err(EvalErrorPropagation::Nested)?;
+
+                self.eval_lambda(&lambda, &[value])
+            }
+        }
+    }
+
+    fn eval_try_catch<'x: 's>(
+        &self,
+        try_catch: &TryCatchExpression<'x>,
+    ) -> Result<Value<'s, 'v>, EvalErrorPropagation<'s, 'v>> {
+        let error = match self.eval_expr(&try_catch.body) {
+            Ok(value) => return Ok(value),
+            // Exhausting the budget is not recoverable, otherwise a `try`
+            // could be used to escape the configured limits.
+            Err(
+                error @ EvalError {
+                    reason: EvalErrorReason::BudgetExhausted(_),
+                    ..
+                },
+            ) => return Err(EvalErrorPropagation::Nested(error)),
+            Err(error) => error,
+        };
+
+        let error_value = error.to_value();
+        let matcher = Matcher::new(self.env)
+            .with_natives(self.natives)
+            .with_budget(self.budget);
+        let Ok(new_env) =
+            matcher.match_pattern(Environment::new(), &try_catch.pattern, &error_value)
+        else {
+            return Err(EvalErrorPropagation::Nested(error));
+        };
+
+        let local_env = matcher.outer_env.combine_with_override(&new_env);
+        Evaluation::new(&local_env)
+            .with_natives(self.natives)
+            .with_budget(self.budget)
+            .eval_expr(&try_catch.fallback)
+            .map_err(EvalErrorPropagation::Nested)
+    }
 }
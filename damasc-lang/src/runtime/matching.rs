@@ -1,10 +1,12 @@
 use crate::runtime::evaluation::EvalError;
 use crate::syntax::location::Location;
 use crate::syntax::pattern::PatternBody;
+use crate::syntax::pattern::{RangePattern, RegexPattern, RelationalOperator, StringPattern};
 use crate::value_type::ValueType;
 
 use std::{
     borrow::Cow,
+    cmp::Ordering,
     collections::{btree_map::Entry, BTreeMap},
 };
 
@@ -12,17 +14,26 @@ use crate::{
     identifier::Identifier,
     literal::Literal,
     syntax::{
-        expression::PropertyKey,
+        expression::{Expression, PropertyKey},
         pattern::{ArrayPatternItem, ObjectPropertyPattern, Pattern, PropertyPattern, Rest},
     },
     value::{Value, ValueObjectMap},
 };
 
 use super::{
+    budget::EvalBudget,
     env::{Environment, EMPTY_ENVIRONMENT},
     evaluation::Evaluation,
+    native::{NativeRegistry, DEFAULT_NATIVES},
 };
 
+/// What an object pattern does when one of its keys is absent.
+enum MissingKey<'x, 's> {
+    Fail,
+    Null,
+    Default(&'x Expression<'s>),
+}
+
 enum PatternFailPropagation<'s, 'v> {
     Shallow(PatternFailReason<'s, 'v>),
     Nested(PatternFail<'s, 'v>),
@@ -76,11 +87,28 @@ pub enum PatternFailReason<'s, 'v> {
         expected: Value<'s, 'v>,
         actual: Value<'s, 'v>,
     },
+    AlternativesMismatch(Vec<PatternFail<'s, 'v>>),
+    OutOfRange {
+        actual: Value<'s, 'v>,
+    },
+    RegexMismatch {
+        actual: Value<'s, 'v>,
+    },
+    PrefixMismatch {
+        expected: Cow<'s, str>,
+        actual: Value<'s, 'v>,
+    },
+    SuffixMismatch {
+        expected: Cow<'s, str>,
+        actual: Value<'s, 'v>,
+    },
 }
 
 #[derive(Clone, Debug)]
 pub struct Matcher<'i, 's, 'v, 'e> {
     pub outer_env: &'e Environment<'i, 's, 'v>,
+    natives: &'e NativeRegistry,
+    budget: Option<&'e EvalBudget>,
 }
 
 impl<'i: 's, 's, 'v: 's, 'e> Matcher<'i, 's, 'v, 'e> {
@@ -134,14 +162,14 @@ impl<'i: 's, 's, 'v: 's, 'e> Matcher<'i, 's, 'v, 'e> {
                         PatternFailPropagation::Nested(e) => e,
                     })
             }
-            PatternBody::Array(items, rest) => {
+            PatternBody::Array(items, rest, suffix) => {
                 let Value::Array(a) = value else {
                     return Err(pattern.cause_error(PatternFailReason::TypeMismatch {
                         expected: ValueType::Array,
                         actual: value.clone(),
                     }));
                 };
-                self.match_array(slf_env, items, rest, a)
+                self.match_array(slf_env, items, rest, suffix, a)
                     .map_err(|propagation| match propagation {
                         PatternFailPropagation::Shallow(e) => pattern.cause_error(e),
                         PatternFailPropagation::Nested(e) => e,
@@ -151,7 +179,9 @@ impl<'i: 's, 's, 'v: 's, 'e> Matcher<'i, 's, 'v, 'e> {
                 .match_literal(slf_env, l, value)
                 .map_err(|e| pattern.cause_error(e)),
             PatternBody::PinnedExpression(expr) => {
-                let eval = Evaluation::new(self.outer_env);
+                let eval = Evaluation::new(self.outer_env)
+                    .with_natives(self.natives)
+                    .with_budget(self.budget);
 
                 let exptected_value = match eval.eval_expr(expr) {
                     Err(e) => {
@@ -169,7 +199,102 @@ impl<'i: 's, 's, 'v: 's, 'e> Matcher<'i, 's, 'v, 'e> {
                     }))
                 }
             }
+            PatternBody::Alternative(alternatives) => {
+                let mut failures = Vec::with_capacity(alternatives.len());
+                for alternative in alternatives {
+                    match self.match_pattern(slf_env.clone(), alternative, value) {
+                        Ok(env) => return Ok(env),
+                        Err(fail) => failures.push(fail),
+                    }
+                }
+
+                Err(pattern.cause_error(PatternFailReason::AlternativesMismatch(failures)))
+            }
+            PatternBody::Range(range) => {
+                let RangePattern {
+                    start,
+                    end,
+                    inclusive,
+                } = range.as_ref();
+                self.match_bound(value, start, |o| o != Ordering::Less)
+                    .and_then(|_| {
+                        self.match_bound(value, end, |o| {
+                            o == Ordering::Less || (*inclusive && o == Ordering::Equal)
+                        })
+                    })
+                    .map(|_| slf_env)
+                    .map_err(|e| pattern.cause_error(e))
+            }
+            PatternBody::Relational(operator, bound) => self
+                .match_bound(value, bound, |o| match operator {
+                    RelationalOperator::LessThan => o == Ordering::Less,
+                    RelationalOperator::LessThanEqual => o != Ordering::Greater,
+                    RelationalOperator::GreaterThan => o == Ordering::Greater,
+                    RelationalOperator::GreaterThanEqual => o != Ordering::Less,
+                })
+                .map(|_| slf_env)
+                .map_err(|e| pattern.cause_error(e)),
+            PatternBody::Regex(regex_pattern) => self
+                .match_regex(slf_env, regex_pattern, value)
+                .map_err(|e| pattern.cause_error(e)),
+            PatternBody::String(string_pattern) => self
+                .match_string(slf_env, string_pattern, value)
+                .map_err(|propagation| match propagation {
+                    PatternFailPropagation::Shallow(e) => pattern.cause_error(e),
+                    PatternFailPropagation::Nested(e) => e,
+                }),
+        }
+    }
+
+    fn match_bound(
+        &self,
+        value: &Value<'s, 'v>,
+        bound: &Literal<'s>,
+        accept: impl Fn(Ordering) -> bool,
+    ) -> Result<(), PatternFailReason<'s, 'v>> {
+        let bound = Evaluation::new(self.outer_env)
+            .with_natives(self.natives)
+            .with_budget(self.budget)
+            .eval_literal(bound)
+            .map_err(|e| PatternFailReason::EvalError(Box::new(e)))?;
+
+        match Evaluation::compare_values(value, &bound) {
+            Some(ordering) if accept(ordering) => Ok(()),
+            _ => Err(PatternFailReason::OutOfRange {
+                actual: value.clone(),
+            }),
+        }
+    }
+
+    fn match_regex<'x>(
+        &'x self,
+        mut slf_env: Environment<'i, 's, 'v>,
+        regex_pattern: &'x RegexPattern<'s>,
+        value: &Value<'s, 'v>,
+    ) -> Result<Environment<'i, 's, 'v>, PatternFailReason<'s, 'v>> {
+        let Value::String(s) = value else {
+            return Err(PatternFailReason::TypeMismatch {
+                expected: ValueType::String,
+                actual: value.clone(),
+            });
+        };
+
+        let Some(found) = regex_pattern.regex.captures(s) else {
+            return Err(PatternFailReason::RegexMismatch {
+                actual: value.clone(),
+            });
+        };
+
+        // optional groups that did not participate in the match are bound to null
+        for name in &regex_pattern.captures {
+            let captured = found
+                .name(&name.name)
+                .map(|m| Value::String(Cow::Owned(m.as_str().to_string())))
+                .unwrap_or(Value::Null);
+            slf_env = self.match_identifier(slf_env, name, &captured)?;
         }
+
+        Ok(slf_env)
     }
 
     fn match_identifier<'x>(
@@ -205,7 +330,8 @@ impl<'i: 's, 's, 'v: 's, 'e> Matcher<'i, 's, 'v, 'e> {
         value: &ValueObjectMap<'s, 'v>,
     ) -> Result<Environment<'i, 's, 'v>, PatternFailPropagation<'s, 'v>> {
         if let Rest::Exact = rest {
-            if value.len() != props.len() {
+            let required = props.iter().filter(|p| !p.is_optional()).count();
+            if value.len() > props.len() || value.len() < required {
                 return Err(PatternFailPropagation::Shallow(
                     PatternFailReason::ObjectLengthMismatch {
                         expected: props.len(),
@@ -217,22 +343,49 @@ impl<'i: 's, 's, 'v: 's, 'e> Matcher<'i, 's, 'v, 'e> {
 
         let mut keys = value.clone();
         for prop in props {
-            let (k, v) = match prop {
+            let (k, v, missing) = match prop {
                 ObjectPropertyPattern::Single(key) => (
                     key.name.clone(),
                     Pattern::new(PatternBody::Identifier(key.clone())),
+                    MissingKey::Fail,
+                ),
+                ObjectPropertyPattern::Optional(key) => (
+                    key.name.clone(),
+                    Pattern::new(PatternBody::Identifier(key.clone())),
+                    MissingKey::Null,
+                ),
+                ObjectPropertyPattern::Default(key, default) => (
+                    key.name.clone(),
+                    Pattern::new(PatternBody::Identifier(key.clone())),
+                    MissingKey::Default(default),
                 ),
                 ObjectPropertyPattern::Match(PropertyPattern {
                     key: PropertyKey::Identifier(key),
                     value,
-                }) => (key.name.clone(), value.clone()),
+                    default,
+                }) => (
+                    key.name.clone(),
+                    value.clone(),
+                    default
+                        .as_ref()
+                        .map_or(MissingKey::Fail, MissingKey::Default),
+                ),
                 ObjectPropertyPattern::Match(PropertyPattern {
                     key: PropertyKey::Expression(exp),
                     value,
+                    default,
                 }) => {
-                    let evaluation = Evaluation::new(self.outer_env);
+                    let evaluation = Evaluation::new(self.outer_env)
+                        .with_natives(self.natives)
+                        .with_budget(self.budget);
                     match evaluation.eval_expr(exp) {
-                        Ok(Value::String(k)) => (k.clone(), value.clone()),
+                        Ok(Value::String(k)) => (
+                            k.clone(),
+                            value.clone(),
+                            default
+                                .as_ref()
+                                .map_or(MissingKey::Fail, MissingKey::Default),
+                        ),
                         Ok(v) => {
                             return Err(PatternFailPropagation::Shallow(
                                 PatternFailReason::TypeMismatch {
@@ -250,22 +403,30 @@ impl<'i: 's, 's, 'v: 's, 'e> Matcher<'i, 's, 'v, 'e> {
                 }
             };
 
-            if keys.remove(&k).is_none() {
-                return Err(PatternFailPropagation::Shallow(
-                    PatternFailReason::ObjectKeyMismatch {
-                        expected: k,
-                        actual: value.clone(),
-                    },
-                ));
-            }
-
-            let Some(actual_value) = value.get(&k) else {
-                return Err(PatternFailPropagation::Shallow(
-                    PatternFailReason::ObjectKeyMismatch {
-                        expected: k,
-                        actual: value.clone(),
-                    },
-                ));
+            let actual_value = match (keys.remove(&k), missing) {
+                (Some(actual), _) => actual,
+                (None, MissingKey::Fail) => {
+                    return Err(PatternFailPropagation::Shallow(
+                        PatternFailReason::ObjectKeyMismatch {
+                            expected: k,
+                            actual: value.clone(),
+                        },
+                    ));
+                }
+                (None, MissingKey::Null) => Cow::Owned(Value::Null),
+                (None, MissingKey::Default(default)) => {
+                    let evaluation = Evaluation::new(self.outer_env)
+                        .with_natives(self.natives)
+                        .with_budget(self.budget);
+                    match evaluation.eval_expr(default) {
+                        Ok(v) => Cow::Owned(v),
+                        Err(e) => {
+                            return Err(PatternFailPropagation::Shallow(
+                                PatternFailReason::EvalError(Box::new(e)),
+                            ))
+                        }
+                    }
+                }
             };
 
             slf_env = self
@@ -273,14 +434,21 @@ impl<'i: 's, 's, 'v: 's, 'e> Matcher<'i, 's, 'v, 'e> {
                 .map_err(PatternFailPropagation::Nested)?
         }
 
-        if let Rest::Collect(rest_pattern) = rest {
-            let remaining: BTreeMap<Cow<str>, Cow<Value>> =
-                keys.iter().map(|(k, v)| (k.clone(), v.clone())).collect();
+        match rest {
+            Rest::Collect(rest_pattern) => {
+                let remaining: BTreeMap<Cow<str>, Cow<Value>> =
+                    keys.iter().map(|(k, v)| (k.clone(), v.clone())).collect();
 
-            self.match_pattern(slf_env, rest_pattern, &Value::Object(remaining))
-                .map_err(PatternFailPropagation::Nested)
-        } else {
-            Ok(slf_env)
+                self.match_pattern(slf_env, rest_pattern, &Value::Object(remaining))
+                    .map_err(PatternFailPropagation::Nested)
+            }
+            Rest::Exact if !keys.is_empty() => Err(PatternFailPropagation::Shallow(
+                PatternFailReason::ObjectLengthMismatch {
+                    expected: props.len(),
+                    actual: value.len(),
+                },
+            )),
+            _ => Ok(slf_env),
         }
     }
 
@@ -289,44 +457,104 @@ impl<'i: 's, 's, 'v: 's, 'e> Matcher<'i, 's, 'v, 'e> {
         mut slf_env: Environment<'i, 's, 'v>,
         items: &[ArrayPatternItem<'s>],
         rest: &Rest<'s>,
+        suffix: &[ArrayPatternItem<'s>],
         value: &[Cow<'v, Value<'s, 'v>>],
     ) -> Result<Environment<'i, 's, 'v>, PatternFailPropagation<'s, 'v>> {
+        let fixed_len = items.len() + suffix.len();
+
         if let Rest::Exact = rest {
-            if value.len() != items.len() {
+            if value.len() != fixed_len {
                 return Err(PatternFailPropagation::Shallow(
                     PatternFailReason::ArrayLengthMismatch {
-                        expected: items.len(),
+                        expected: fixed_len,
                         actual: value.len(),
                     },
                 ));
             }
         }
 
-        if value.len() < items.len() {
+        if value.len() < fixed_len {
             return Err(PatternFailPropagation::Shallow(
                 PatternFailReason::ArrayMinimumLengthMismatch {
-                    expected: items.len(),
+                    expected: fixed_len,
                     actual: value.len(),
                 },
             ));
         }
 
-        for (ArrayPatternItem::Pattern(p), val) in std::iter::zip(items, value.iter()) {
+        let (head, tail) = value.split_at(value.len() - suffix.len());
+
+        for (ArrayPatternItem::Pattern(p), val) in std::iter::zip(items, head.iter()) {
             slf_env = self
                 .match_pattern(slf_env, p, val.as_ref())
                 .map_err(PatternFailPropagation::Nested)?
         }
 
         if let Rest::Collect(rest_pattern) = rest {
-            self.match_pattern(
-                slf_env,
-                rest_pattern,
-                &Value::Array(value.iter().skip(items.len()).cloned().collect()),
-            )
-            .map_err(PatternFailPropagation::Nested)
-        } else {
-            Ok(slf_env)
+            slf_env = self
+                .match_pattern(
+                    slf_env,
+                    rest_pattern,
+                    &Value::Array(head.iter().skip(items.len()).cloned().collect()),
+                )
+                .map_err(PatternFailPropagation::Nested)?
+        }
+
+        for (ArrayPatternItem::Pattern(p), val) in std::iter::zip(suffix, tail.iter()) {
+            slf_env = self
+                .match_pattern(slf_env, p, val.as_ref())
+                .map_err(PatternFailPropagation::Nested)?
         }
+
+        Ok(slf_env)
+    }
+
+    fn match_string<'x>(
+        &'x self,
+        slf_env: Environment<'i, 's, 'v>,
+        string_pattern: &'x StringPattern<'s>,
+        value: &Value<'s, 'v>,
+    ) -> Result<Environment<'i, 's, 'v>, PatternFailPropagation<'s, 'v>> {
+        let Value::String(s) = value else {
+            return Err(PatternFailPropagation::Shallow(
+                PatternFailReason::TypeMismatch {
+                    expected: ValueType::String,
+                    actual: value.clone(),
+                },
+            ));
+        };
+
+        let (remainder, rest_pattern) = match string_pattern {
+            StringPattern::Prefix(prefix, rest_pattern) => {
+                let Some(remainder) = s.strip_prefix(prefix.as_ref()) else {
+                    return Err(PatternFailPropagation::Shallow(
+                        PatternFailReason::PrefixMismatch {
+                            expected: prefix.clone(),
+                            actual: value.clone(),
+                        },
+                    ));
+                };
+                (remainder, rest_pattern)
+            }
+            StringPattern::Suffix(rest_pattern, suffix) => {
+                let Some(remainder) = s.strip_suffix(suffix.as_ref()) else {
+                    return Err(PatternFailPropagation::Shallow(
+                        PatternFailReason::SuffixMismatch {
+                            expected: suffix.clone(),
+                            actual: value.clone(),
+                        },
+                    ));
+                };
+                (remainder, rest_pattern)
+            }
+        };
+
+        self.match_pattern(
+            slf_env,
+            rest_pattern,
+            &Value::String(Cow::Owned(remainder.to_string())),
+        )
+        .map_err(PatternFailPropagation::Nested)
     }
 
     fn match_literal(
@@ -341,6 +569,9 @@ impl<'i: 's, 's, 'v: 's, 'e> Matcher<'i, 's, 'v, 'e> {
             (Literal::Number(n), Value::Integer(i)) => {
                 str::parse::<i64>(n).map(|p| &p == i).unwrap_or(false)
             }
+            (Literal::Float(n), Value::Float(x)) => {
+                str::parse::<f64>(n).map(|p| p == x.0).unwrap_or(false)
+            }
             (Literal::Boolean(a), Value::Boolean(b)) => a == b,
             (Literal::Type(a), Value::Type(b)) => a == b,
             _ => false,
@@ -354,7 +585,19 @@ impl<'i: 's, 's, 'v: 's, 'e> Matcher<'i, 's, 'v, 'e> {
     }
 
     pub fn new<'x: 'e>(env: &'x Environment<'i, 's, 'v>) -> Self {
-        Self { outer_env: env }
+        Self {
+            outer_env: env,
+            natives: &DEFAULT_NATIVES,
+            budget: None,
+        }
+    }
+
+    pub fn with_natives(self, natives: &'e NativeRegistry) -> Self {
+        Self { natives, ..self }
+    }
+
+    pub fn with_budget(self, budget: Option<&'e EvalBudget>) -> Self {
+        Self { budget, ..self }
     }
 }
 
@@ -362,6 +605,8 @@ impl Default for Matcher<'_, '_, '_, 'static> {
     fn default() -> Self {
         Self {
             outer_env: &EMPTY_ENVIRONMENT,
+            natives: &DEFAULT_NATIVES,
+            budget: None,
         }
     }
 }
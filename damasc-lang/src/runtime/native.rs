@@ -0,0 +1,143 @@
+use std::collections::BTreeMap;
+use std::sync::LazyLock;
+
+use crate::value::Value;
+use crate::value_type::ValueType;
+
+use super::evaluation::{EvalError, EvalErrorReason, Evaluation};
+
+/// Destructures the arguments of a native function. They have been checked
+/// against its signature before the call, so the patterns can not fail.
+macro_rules! arguments {
+    ($arguments:expr, [$($pattern:pat),+ $(,)?]) => {
+        let [$($pattern),+] = $arguments else {
+            unreachable!("arguments are checked against the signature")
+        };
+    };
+}
+
+pub mod array;
+pub mod builtin;
+pub mod string;
+
+pub trait NativeFunction: Send + Sync {
+    fn name(&self) -> &str;
+
+    fn signature(&self) -> NativeSignature;
+
+    fn arity(&self) -> usize {
+        self.signature().parameters.len()
+    }
+
+    fn call<'s, 'v>(
+        &self,
+        evaluation: &Evaluation<'_, '_, 's, 'v>,
+        arguments: &[Value<'s, 'v>],
+    ) -> Result<Value<'s, 'v>, EvalError<'s, 'v>>;
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct NativeSignature {
+    pub parameters: Vec<Option<ValueType>>,
+    pub result: Option<ValueType>,
+}
+
+impl NativeSignature {
+    pub fn new(parameters: Vec<Option<ValueType>>, result: Option<ValueType>) -> Self {
+        Self { parameters, result }
+    }
+
+    pub(crate) fn check<'s, 'v>(
+        &self,
+        arguments: &[Value<'s, 'v>],
+    ) -> Result<(), EvalErrorReason<'s, 'v>> {
+        if arguments.len() != self.parameters.len() {
+            return Err(EvalErrorReason::ArityMismatch(
+                self.parameters.len(),
+                arguments.len(),
+            ));
+        }
+
+        for (expected, actual) in std::iter::zip(&self.parameters, arguments) {
+            let Some(expected_type) = expected else {
+                continue;
+            };
+
+            if actual.get_type() != *expected_type {
+                return Err(EvalErrorReason::TypeError(*expected_type, actual.clone()));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for NativeSignature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(")?;
+        for (i, p) in self.parameters.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            match p {
+                Some(t) => write!(f, "{t}")?,
+                None => write!(f, "_")?,
+            }
+        }
+        write!(f, ")")?;
+        match &self.result {
+            Some(t) => write!(f, " -> {t}"),
+            None => write!(f, " -> _"),
+        }
+    }
+}
+
+pub struct NativeRegistry {
+    functions: BTreeMap<String, Box<dyn NativeFunction>>,
+}
+
+impl NativeRegistry {
+    pub fn new() -> Self {
+        Self {
+            functions: BTreeMap::new(),
+        }
+    }
+
+    pub fn register(
+        &mut self,
+        function: impl NativeFunction + 'static,
+    ) -> Option<Box<dyn NativeFunction>> {
+        self.functions
+            .insert(function.name().to_string(), Box::new(function))
+    }
+
+    pub fn unregister(&mut self, name: &str) -> Option<Box<dyn NativeFunction>> {
+        self.functions.remove(name)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&dyn NativeFunction> {
+        self.functions.get(name).map(|f| f.as_ref())
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.functions.keys().map(|k| k.as_str())
+    }
+}
+
+impl Default for NativeRegistry {
+    fn default() -> Self {
+        let mut registry = Self::new();
+        builtin::register(&mut registry);
+        array::register(&mut registry);
+        string::register(&mut registry);
+        registry
+    }
+}
+
+impl std::fmt::Debug for NativeRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.functions.keys()).finish()
+    }
+}
+
+pub static DEFAULT_NATIVES: LazyLock<NativeRegistry> = LazyLock::new(NativeRegistry::default);
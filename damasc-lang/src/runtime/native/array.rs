@@ -0,0 +1,523 @@
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::runtime::evaluation::{EvalError, EvalErrorReason, Evaluation};
+use crate::value::{Value, ValueArray};
+use crate::value_type::ValueType;
+
+use super::{NativeFunction, NativeRegistry, NativeSignature};
+
+pub fn register(registry: &mut NativeRegistry) {
+    registry.register(Map);
+    registry.register(Filter);
+    registry.register(Fold);
+    registry.register(Sort);
+    registry.register(SortBy);
+    registry.register(Zip);
+    registry.register(Flatten);
+    registry.register(Reverse);
+    registry.register(Range);
+    registry.register(Take);
+    registry.register(Skip);
+    registry.register(Unique);
+    registry.register(Any);
+    registry.register(All);
+    registry.register(GroupBy);
+}
+
+// A stable merge sort, because the comparison fails for values that `<`
+// can not order and `slice::sort_by` expects a total order.
+fn sort_values<T, E>(
+    mut items: Vec<T>,
+    compare: &impl Fn(&T, &T) -> Result<Ordering, E>,
+) -> Result<Vec<T>, E> {
+    if items.len() <= 1 {
+        return Ok(items);
+    }
+
+    let right = items.split_off(items.len() / 2);
+    let mut left = sort_values(items, compare)?.into_iter().peekable();
+    let mut right = sort_values(right, compare)?.into_iter().peekable();
+    let mut merged = Vec::with_capacity(left.len() + right.len());
+
+    while let (Some(l), Some(r)) = (left.peek(), right.peek()) {
+        if compare(r, l)? == Ordering::Less {
+            merged.extend(right.next());
+        } else {
+            merged.extend(left.next());
+        }
+    }
+    merged.extend(left);
+    merged.extend(right);
+
+    Ok(merged)
+}
+
+fn predicate<'e, 'i: 's, 's, 'v: 's>(
+    evaluation: &Evaluation<'e, 'i, 's, 'v>,
+    lambda: &Value<'s, 'v>,
+    item: &Value<'s, 'v>,
+) -> Result<bool, EvalError<'s, 'v>> {
+    match evaluation.apply_lambda(lambda, std::slice::from_ref(item))? {
+        Value::Boolean(b) => Ok(b),
+        other => Err(EvalErrorReason::TypeError(ValueType::Boolean, other).into()),
+    }
+}
+
+fn count_argument<'s, 'v>(count: i64, length: usize) -> Result<usize, EvalError<'s, 'v>> {
+    if count < 0 {
+        return Err(EvalErrorReason::OutOfBound(length, count as isize).into());
+    }
+
+    Ok(count as usize)
+}
+
+pub struct Map;
+
+impl NativeFunction for Map {
+    fn name(&self) -> &str {
+        "map"
+    }
+
+    fn signature(&self) -> NativeSignature {
+        NativeSignature::new(
+            vec![Some(ValueType::Array), Some(ValueType::Lambda)],
+            Some(ValueType::Array),
+        )
+    }
+
+    fn call<'s, 'v>(
+        &self,
+        evaluation: &Evaluation<'_, '_, 's, 'v>,
+        arguments: &[Value<'s, 'v>],
+    ) -> Result<Value<'s, 'v>, EvalError<'s, 'v>> {
+        arguments!(arguments, [Value::Array(items), lambda]);
+
+        items
+            .iter()
+            .map(|item| {
+                evaluation
+                    .apply_lambda(lambda, std::slice::from_ref(item.as_ref()))
+                    .map(Cow::Owned)
+            })
+            .collect::<Result<_, _>>()
+            .map(Value::Array)
+    }
+}
+
+pub struct Filter;
+
+impl NativeFunction for Filter {
+    fn name(&self) -> &str {
+        "filter"
+    }
+
+    fn signature(&self) -> NativeSignature {
+        NativeSignature::new(
+            vec![Some(ValueType::Array), Some(ValueType::Lambda)],
+            Some(ValueType::Array),
+        )
+    }
+
+    fn call<'s, 'v>(
+        &self,
+        evaluation: &Evaluation<'_, '_, 's, 'v>,
+        arguments: &[Value<'s, 'v>],
+    ) -> Result<Value<'s, 'v>, EvalError<'s, 'v>> {
+        arguments!(arguments, [Value::Array(items), lambda]);
+        let mut result = vec![];
+
+        for item in items {
+            if predicate(evaluation, lambda, item.as_ref())? {
+                result.push(item.clone());
+            }
+        }
+
+        Ok(Value::Array(result))
+    }
+}
+
+pub struct Fold;
+
+impl NativeFunction for Fold {
+    fn name(&self) -> &str {
+        "fold"
+    }
+
+    fn signature(&self) -> NativeSignature {
+        NativeSignature::new(
+            vec![Some(ValueType::Array), None, Some(ValueType::Lambda)],
+            None,
+        )
+    }
+
+    fn call<'s, 'v>(
+        &self,
+        evaluation: &Evaluation<'_, '_, 's, 'v>,
+        arguments: &[Value<'s, 'v>],
+    ) -> Result<Value<'s, 'v>, EvalError<'s, 'v>> {
+        arguments!(arguments, [Value::Array(items), initial, lambda]);
+
+        items.iter().try_fold(initial.clone(), |acc, item| {
+            evaluation.apply_lambda(lambda, &[acc, item.clone().into_owned()])
+        })
+    }
+}
+
+pub struct Sort;
+
+impl NativeFunction for Sort {
+    fn name(&self) -> &str {
+        "sort"
+    }
+
+    fn signature(&self) -> NativeSignature {
+        NativeSignature::new(vec![Some(ValueType::Array)], Some(ValueType::Array))
+    }
+
+    fn call<'s, 'v>(
+        &self,
+        _evaluation: &Evaluation<'_, '_, 's, 'v>,
+        arguments: &[Value<'s, 'v>],
+    ) -> Result<Value<'s, 'v>, EvalError<'s, 'v>> {
+        arguments!(arguments, [Value::Array(items)]);
+
+        Ok(Value::Array(sort_values(items.clone(), &|a, b| {
+            Evaluation::order_values(a, b)
+        })?))
+    }
+}
+
+pub struct SortBy;
+
+impl NativeFunction for SortBy {
+    fn name(&self) -> &str {
+        "sort_by"
+    }
+
+    fn signature(&self) -> NativeSignature {
+        NativeSignature::new(
+            vec![Some(ValueType::Array), Some(ValueType::Lambda)],
+            Some(ValueType::Array),
+        )
+    }
+
+    fn call<'s, 'v>(
+        &self,
+        evaluation: &Evaluation<'_, '_, 's, 'v>,
+        arguments: &[Value<'s, 'v>],
+    ) -> Result<Value<'s, 'v>, EvalError<'s, 'v>> {
+        arguments!(arguments, [Value::Array(items), lambda]);
+
+        let keyed = items
+            .iter()
+            .map(|item| {
+                evaluation
+                    .apply_lambda(lambda, std::slice::from_ref(item.as_ref()))
+                    .map(|key| (key, item.clone()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let sorted = sort_values(keyed, &|(a, _), (b, _)| Evaluation::order_values(a, b))?;
+
+        Ok(Value::Array(sorted.into_iter().map(|(_, v)| v).collect()))
+    }
+}
+
+pub struct Zip;
+
+impl NativeFunction for Zip {
+    fn name(&self) -> &str {
+        "zip"
+    }
+
+    fn signature(&self) -> NativeSignature {
+        NativeSignature::new(
+            vec![Some(ValueType::Array), Some(ValueType::Array)],
+            Some(ValueType::Array),
+        )
+    }
+
+    fn call<'s, 'v>(
+        &self,
+        _evaluation: &Evaluation<'_, '_, 's, 'v>,
+        arguments: &[Value<'s, 'v>],
+    ) -> Result<Value<'s, 'v>, EvalError<'s, 'v>> {
+        arguments!(arguments, [Value::Array(left), Value::Array(right)]);
+
+        Ok(Value::Array(
+            std::iter::zip(left, right)
+                .map(|(l, r)| Cow::Owned(Value::Array(vec![l.clone(), r.clone()])))
+                .collect(),
+        ))
+    }
+}
+
+pub struct Flatten;
+
+impl NativeFunction for Flatten {
+    fn name(&self) -> &str {
+        "flatten"
+    }
+
+    fn signature(&self) -> NativeSignature {
+        NativeSignature::new(vec![Some(ValueType::Array)], Some(ValueType::Array))
+    }
+
+    fn call<'s, 'v>(
+        &self,
+        _evaluation: &Evaluation<'_, '_, 's, 'v>,
+        arguments: &[Value<'s, 'v>],
+    ) -> Result<Value<'s, 'v>, EvalError<'s, 'v>> {
+        arguments!(arguments, [Value::Array(items)]);
+        let mut result = vec![];
+
+        for item in items {
+            match item.as_ref() {
+                Value::Array(inner) => result.extend(inner.iter().cloned()),
+                _ => result.push(item.clone()),
+            }
+        }
+
+        Ok(Value::Array(result))
+    }
+}
+
+pub struct Reverse;
+
+impl NativeFunction for Reverse {
+    fn name(&self) -> &str {
+        "reverse"
+    }
+
+    fn signature(&self) -> NativeSignature {
+        NativeSignature::new(vec![Some(ValueType::Array)], Some(ValueType::Array))
+    }
+
+    fn call<'s, 'v>(
+        &self,
+        _evaluation: &Evaluation<'_, '_, 's, 'v>,
+        arguments: &[Value<'s, 'v>],
+    ) -> Result<Value<'s, 'v>, EvalError<'s, 'v>> {
+        arguments!(arguments, [Value::Array(items)]);
+
+        Ok(Value::Array(items.iter().rev().cloned().collect()))
+    }
+}
+
+pub struct Range;
+
+impl NativeFunction for Range {
+    fn name(&self) -> &str {
+        "range"
+    }
+
+    fn signature(&self) -> NativeSignature {
+        NativeSignature::new(
+            vec![Some(ValueType::Integer), Some(ValueType::Integer)],
+            Some(ValueType::Array),
+        )
+    }
+
+    fn call<'s, 'v>(
+        &self,
+        evaluation: &Evaluation<'_, '_, 's, 'v>,
+        arguments: &[Value<'s, 'v>],
+    ) -> Result<Value<'s, 'v>, EvalError<'s, 'v>> {
+        arguments!(arguments, [Value::Integer(start), Value::Integer(end)]);
+        let (start, end) = (*start, *end);
+
+        evaluation
+            .check_collection_size(usize::try_from(end.saturating_sub(start)).unwrap_or(0))?;
+
+        Ok(Value::Array(
+            (start..end)
+                .map(|i| Cow::Owned(Value::Integer(i)))
+                .collect(),
+        ))
+    }
+}
+
+pub struct Take;
+
+impl NativeFunction for Take {
+    fn name(&self) -> &str {
+        "take"
+    }
+
+    fn signature(&self) -> NativeSignature {
+        NativeSignature::new(
+            vec![Some(ValueType::Array), Some(ValueType::Integer)],
+            Some(ValueType::Array),
+        )
+    }
+
+    fn call<'s, 'v>(
+        &self,
+        _evaluation: &Evaluation<'_, '_, 's, 'v>,
+        arguments: &[Value<'s, 'v>],
+    ) -> Result<Value<'s, 'v>, EvalError<'s, 'v>> {
+        arguments!(arguments, [Value::Array(items), Value::Integer(count)]);
+        let count = count_argument(*count, items.len())?;
+
+        Ok(Value::Array(items.iter().take(count).cloned().collect()))
+    }
+}
+
+pub struct Skip;
+
+impl NativeFunction for Skip {
+    fn name(&self) -> &str {
+        "skip"
+    }
+
+    fn signature(&self) -> NativeSignature {
+        NativeSignature::new(
+            vec![Some(ValueType::Array), Some(ValueType::Integer)],
+            Some(ValueType::Array),
+        )
+    }
+
+    fn call<'s, 'v>(
+        &self,
+        _evaluation: &Evaluation<'_, '_, 's, 'v>,
+        arguments: &[Value<'s, 'v>],
+    ) -> Result<Value<'s, 'v>, EvalError<'s, 'v>> {
+        arguments!(arguments, [Value::Array(items), Value::Integer(count)]);
+        let count = count_argument(*count, items.len())?;
+
+        Ok(Value::Array(items.iter().skip(count).cloned().collect()))
+    }
+}
+
+pub struct Unique;
+
+impl NativeFunction for Unique {
+    fn name(&self) -> &str {
+        "unique"
+    }
+
+    fn signature(&self) -> NativeSignature {
+        NativeSignature::new(vec![Some(ValueType::Array)], Some(ValueType::Array))
+    }
+
+    fn call<'s, 'v>(
+        &self,
+        _evaluation: &Evaluation<'_, '_, 's, 'v>,
+        arguments: &[Value<'s, 'v>],
+    ) -> Result<Value<'s, 'v>, EvalError<'s, 'v>> {
+        arguments!(arguments, [Value::Array(items)]);
+        let mut seen = BTreeSet::new();
+        let mut result = vec![];
+
+        for item in items {
+            if seen.insert(item.as_ref()) {
+                result.push(item.clone());
+            }
+        }
+
+        Ok(Value::Array(result))
+    }
+}
+
+pub struct Any;
+
+impl NativeFunction for Any {
+    fn name(&self) -> &str {
+        "any"
+    }
+
+    fn signature(&self) -> NativeSignature {
+        NativeSignature::new(
+            vec![Some(ValueType::Array), Some(ValueType::Lambda)],
+            Some(ValueType::Boolean),
+        )
+    }
+
+    fn call<'s, 'v>(
+        &self,
+        evaluation: &Evaluation<'_, '_, 's, 'v>,
+        arguments: &[Value<'s, 'v>],
+    ) -> Result<Value<'s, 'v>, EvalError<'s, 'v>> {
+        arguments!(arguments, [Value::Array(items), lambda]);
+
+        for item in items {
+            if predicate(evaluation, lambda, item.as_ref())? {
+                return Ok(Value::Boolean(true));
+            }
+        }
+
+        Ok(Value::Boolean(false))
+    }
+}
+
+pub struct All;
+
+impl NativeFunction for All {
+    fn name(&self) -> &str {
+        "all"
+    }
+
+    fn signature(&self) -> NativeSignature {
+        NativeSignature::new(
+            vec![Some(ValueType::Array), Some(ValueType::Lambda)],
+            Some(ValueType::Boolean),
+        )
+    }
+
+    fn call<'s, 'v>(
+        &self,
+        evaluation: &Evaluation<'_, '_, 's, 'v>,
+        arguments: &[Value<'s, 'v>],
+    ) -> Result<Value<'s, 'v>, EvalError<'s, 'v>> {
+        arguments!(arguments, [Value::Array(items), lambda]);
+
+        for item in items {
+            if !predicate(evaluation, lambda, item.as_ref())? {
+                return Ok(Value::Boolean(false));
+            }
+        }
+
+        Ok(Value::Boolean(true))
+    }
+}
+
+pub struct GroupBy;
+
+impl NativeFunction for GroupBy {
+    fn name(&self) -> &str {
+        "group_by"
+    }
+
+    fn signature(&self) -> NativeSignature {
+        NativeSignature::new(
+            vec![Some(ValueType::Array), Some(ValueType::Lambda)],
+            Some(ValueType::Object),
+        )
+    }
+
+    fn call<'s, 'v>(
+        &self,
+        evaluation: &Evaluation<'_, '_, 's, 'v>,
+        arguments: &[Value<'s, 'v>],
+    ) -> Result<Value<'s, 'v>, EvalError<'s, 'v>> {
+        arguments!(arguments, [Value::Array(items), lambda]);
+        let mut groups: BTreeMap<Cow<'s, str>, ValueArray<'s, 'v>> = BTreeMap::new();
+
+        for item in items {
+            let key = evaluation.apply_lambda(lambda, std::slice::from_ref(item.as_ref()))?;
+            let Some(Value::String(key)) = key.convert(ValueType::String) else {
+                return Err(EvalErrorReason::CastError(ValueType::String, key).into());
+            };
+
+            groups.entry(key).or_default().push(item.clone());
+        }
+
+        Ok(Value::Object(
+            groups
+                .into_iter()
+                .map(|(k, v)| (k, Cow::Owned(Value::Array(v))))
+                .collect(),
+        ))
+    }
+}
@@ -0,0 +1,185 @@
+use std::borrow::Cow;
+
+use crate::identifier::Identifier;
+use crate::runtime::evaluation::{EvalError, EvalErrorReason, Evaluation};
+use crate::value::Value;
+use crate::value_type::ValueType;
+
+use super::{NativeFunction, NativeRegistry, NativeSignature};
+
+pub fn register(registry: &mut NativeRegistry) {
+    registry.register(Length);
+    registry.register(Keys);
+    registry.register(Values);
+    registry.register(Env);
+    registry.register(Rebind);
+    registry.register(Type);
+}
+
+pub struct Length;
+
+impl NativeFunction for Length {
+    fn name(&self) -> &str {
+        "length"
+    }
+
+    fn signature(&self) -> NativeSignature {
+        NativeSignature::new(vec![None], Some(ValueType::Integer))
+    }
+
+    fn call<'s, 'v>(
+        &self,
+        _evaluation: &Evaluation<'_, '_, 's, 'v>,
+        arguments: &[Value<'s, 'v>],
+    ) -> Result<Value<'s, 'v>, EvalError<'s, 'v>> {
+        arguments!(arguments, [argument]);
+
+        Ok(Value::Integer(match argument {
+            Value::String(s) => s.chars().count() as i64,
+            Value::Array(a) => a.len() as i64,
+            Value::Object(o) => o.len() as i64,
+            _ => {
+                return Err(EvalErrorReason::CollectionTypeError(argument.clone()).into());
+            }
+        }))
+    }
+}
+
+pub struct Keys;
+
+impl NativeFunction for Keys {
+    fn name(&self) -> &str {
+        "keys"
+    }
+
+    fn signature(&self) -> NativeSignature {
+        NativeSignature::new(vec![Some(ValueType::Object)], Some(ValueType::Array))
+    }
+
+    fn call<'s, 'v>(
+        &self,
+        _evaluation: &Evaluation<'_, '_, 's, 'v>,
+        arguments: &[Value<'s, 'v>],
+    ) -> Result<Value<'s, 'v>, EvalError<'s, 'v>> {
+        arguments!(arguments, [Value::Object(o)]);
+
+        Ok(Value::Array(
+            o.keys()
+                .map(|k| Cow::Owned(Value::String(Cow::Owned(k.to_string()))))
+                .collect(),
+        ))
+    }
+}
+
+pub struct Values;
+
+impl NativeFunction for Values {
+    fn name(&self) -> &str {
+        "values"
+    }
+
+    fn signature(&self) -> NativeSignature {
+        NativeSignature::new(vec![Some(ValueType::Object)], Some(ValueType::Array))
+    }
+
+    fn call<'s, 'v>(
+        &self,
+        _evaluation: &Evaluation<'_, '_, 's, 'v>,
+        arguments: &[Value<'s, 'v>],
+    ) -> Result<Value<'s, 'v>, EvalError<'s, 'v>> {
+        arguments!(arguments, [Value::Object(o)]);
+
+        Ok(Value::Array(o.values().cloned().collect()))
+    }
+}
+
+pub struct Env;
+
+impl NativeFunction for Env {
+    fn name(&self) -> &str {
+        "env"
+    }
+
+    fn signature(&self) -> NativeSignature {
+        NativeSignature::new(vec![Some(ValueType::Lambda)], Some(ValueType::Object))
+    }
+
+    fn call<'s, 'v>(
+        &self,
+        _evaluation: &Evaluation<'_, '_, 's, 'v>,
+        arguments: &[Value<'s, 'v>],
+    ) -> Result<Value<'s, 'v>, EvalError<'s, 'v>> {
+        arguments!(arguments, [Value::Lambda(env, _, _, _)]);
+
+        Ok(Value::Object(
+            env.bindings
+                .iter()
+                .map(|(k, v)| (Cow::Owned(k.to_string()), Cow::Owned(v.to_owned())))
+                .collect(),
+        ))
+    }
+}
+
+pub struct Rebind;
+
+impl NativeFunction for Rebind {
+    fn name(&self) -> &str {
+        "rebind"
+    }
+
+    fn signature(&self) -> NativeSignature {
+        NativeSignature::new(
+            vec![Some(ValueType::Lambda), Some(ValueType::Object)],
+            Some(ValueType::Lambda),
+        )
+    }
+
+    fn call<'s, 'v>(
+        &self,
+        _evaluation: &Evaluation<'_, '_, 's, 'v>,
+        arguments: &[Value<'s, 'v>],
+    ) -> Result<Value<'s, 'v>, EvalError<'s, 'v>> {
+        arguments!(
+            arguments,
+            [
+                Value::Lambda(env, name, pattern, expression),
+                Value::Object(obj)
+            ]
+        );
+
+        let mut new_env = env.clone();
+        new_env.replace(
+            obj.iter()
+                .map(|(k, v)| (Identifier::new_owned(k.to_string()), v.clone().into_owned())),
+        );
+
+        Ok(Value::Lambda(
+            new_env,
+            name.clone(),
+            pattern.clone(),
+            expression.clone(),
+        ))
+    }
+}
+
+pub struct Type;
+
+impl NativeFunction for Type {
+    fn name(&self) -> &str {
+        "type"
+    }
+
+    fn signature(&self) -> NativeSignature {
+        NativeSignature::new(vec![None], Some(ValueType::Type))
+    }
+
+    fn call<'s, 'v>(
+        &self,
+        _evaluation: &Evaluation<'_, '_, 's, 'v>,
+        arguments: &[Value<'s, 'v>],
+    ) -> Result<Value<'s, 'v>, EvalError<'s, 'v>> {
+        arguments!(arguments, [argument]);
+
+        Ok(Value::Type(argument.get_type()))
+    }
+}
@@ -0,0 +1,359 @@
+use std::borrow::Cow;
+
+use crate::runtime::evaluation::{EvalError, EvalErrorReason, Evaluation};
+use crate::value::Value;
+use crate::value_type::ValueType;
+
+use super::{NativeFunction, NativeRegistry, NativeSignature};
+
+pub fn register(registry: &mut NativeRegistry) {
+    registry.register(Split);
+    registry.register(Join);
+    registry.register(Trim);
+    registry.register(Lower);
+    registry.register(Upper);
+    registry.register(StartsWith);
+    registry.register(EndsWith);
+    registry.register(Contains);
+    registry.register(Replace);
+    registry.register(Substring);
+    registry.register(Chars);
+}
+
+fn string_value<'s, 'v>(s: impl Into<String>) -> Value<'s, 'v> {
+    Value::String(Cow::Owned(s.into()))
+}
+
+pub struct Split;
+
+impl NativeFunction for Split {
+    fn name(&self) -> &str {
+        "split"
+    }
+
+    fn signature(&self) -> NativeSignature {
+        NativeSignature::new(
+            vec![Some(ValueType::String), Some(ValueType::String)],
+            Some(ValueType::Array),
+        )
+    }
+
+    fn call<'s, 'v>(
+        &self,
+        _evaluation: &Evaluation<'_, '_, 's, 'v>,
+        arguments: &[Value<'s, 'v>],
+    ) -> Result<Value<'s, 'v>, EvalError<'s, 'v>> {
+        arguments!(
+            arguments,
+            [Value::String(subject), Value::String(separator)]
+        );
+
+        let parts: Vec<&str> = if separator.is_empty() {
+            subject
+                .char_indices()
+                .map(|(i, c)| &subject[i..i + c.len_utf8()])
+                .collect()
+        } else {
+            subject.split(separator.as_ref()).collect()
+        };
+
+        Ok(Value::Array(
+            parts
+                .into_iter()
+                .map(|p| Cow::Owned(string_value(p)))
+                .collect(),
+        ))
+    }
+}
+
+pub struct Join;
+
+impl NativeFunction for Join {
+    fn name(&self) -> &str {
+        "join"
+    }
+
+    fn signature(&self) -> NativeSignature {
+        NativeSignature::new(
+            vec![Some(ValueType::Array), Some(ValueType::String)],
+            Some(ValueType::String),
+        )
+    }
+
+    fn call<'s, 'v>(
+        &self,
+        _evaluation: &Evaluation<'_, '_, 's, 'v>,
+        arguments: &[Value<'s, 'v>],
+    ) -> Result<Value<'s, 'v>, EvalError<'s, 'v>> {
+        arguments!(arguments, [Value::Array(items), Value::String(separator)]);
+
+        let parts = items
+            .iter()
+            .map(|item| match item.as_ref() {
+                Value::String(s) => Ok(s.as_ref()),
+                other => Err(EvalErrorReason::TypeError(ValueType::String, other.clone()).into()),
+            })
+            .collect::<Result<Vec<&str>, EvalError>>()?;
+
+        Ok(string_value(parts.join(separator)))
+    }
+}
+
+pub struct Trim;
+
+impl NativeFunction for Trim {
+    fn name(&self) -> &str {
+        "trim"
+    }
+
+    fn signature(&self) -> NativeSignature {
+        NativeSignature::new(vec![Some(ValueType::String)], Some(ValueType::String))
+    }
+
+    fn call<'s, 'v>(
+        &self,
+        _evaluation: &Evaluation<'_, '_, 's, 'v>,
+        arguments: &[Value<'s, 'v>],
+    ) -> Result<Value<'s, 'v>, EvalError<'s, 'v>> {
+        arguments!(arguments, [Value::String(subject)]);
+
+        Ok(string_value(subject.trim()))
+    }
+}
+
+pub struct Lower;
+
+impl NativeFunction for Lower {
+    fn name(&self) -> &str {
+        "lower"
+    }
+
+    fn signature(&self) -> NativeSignature {
+        NativeSignature::new(vec![Some(ValueType::String)], Some(ValueType::String))
+    }
+
+    fn call<'s, 'v>(
+        &self,
+        _evaluation: &Evaluation<'_, '_, 's, 'v>,
+        arguments: &[Value<'s, 'v>],
+    ) -> Result<Value<'s, 'v>, EvalError<'s, 'v>> {
+        arguments!(arguments, [Value::String(subject)]);
+
+        Ok(string_value(subject.to_lowercase()))
+    }
+}
+
+pub struct Upper;
+
+impl NativeFunction for Upper {
+    fn name(&self) -> &str {
+        "upper"
+    }
+
+    fn signature(&self) -> NativeSignature {
+        NativeSignature::new(vec![Some(ValueType::String)], Some(ValueType::String))
+    }
+
+    fn call<'s, 'v>(
+        &self,
+        _evaluation: &Evaluation<'_, '_, 's, 'v>,
+        arguments: &[Value<'s, 'v>],
+    ) -> Result<Value<'s, 'v>, EvalError<'s, 'v>> {
+        arguments!(arguments, [Value::String(subject)]);
+
+        Ok(string_value(subject.to_uppercase()))
+    }
+}
+
+pub struct StartsWith;
+
+impl NativeFunction for StartsWith {
+    fn name(&self) -> &str {
+        "starts_with"
+    }
+
+    fn signature(&self) -> NativeSignature {
+        NativeSignature::new(
+            vec![Some(ValueType::String), Some(ValueType::String)],
+            Some(ValueType::Boolean),
+        )
+    }
+
+    fn call<'s, 'v>(
+        &self,
+        _evaluation: &Evaluation<'_, '_, 's, 'v>,
+        arguments: &[Value<'s, 'v>],
+    ) -> Result<Value<'s, 'v>, EvalError<'s, 'v>> {
+        arguments!(arguments, [Value::String(subject), Value::String(prefix)]);
+
+        Ok(Value::Boolean(subject.starts_with(prefix.as_ref())))
+    }
+}
+
+pub struct EndsWith;
+
+impl NativeFunction for EndsWith {
+    fn name(&self) -> &str {
+        "ends_with"
+    }
+
+    fn signature(&self) -> NativeSignature {
+        NativeSignature::new(
+            vec![Some(ValueType::String), Some(ValueType::String)],
+            Some(ValueType::Boolean),
+        )
+    }
+
+    fn call<'s, 'v>(
+        &self,
+        _evaluation: &Evaluation<'_, '_, 's, 'v>,
+        arguments: &[Value<'s, 'v>],
+    ) -> Result<Value<'s, 'v>, EvalError<'s, 'v>> {
+        arguments!(arguments, [Value::String(subject), Value::String(suffix)]);
+
+        Ok(Value::Boolean(subject.ends_with(suffix.as_ref())))
+    }
+}
+
+pub struct Contains;
+
+impl NativeFunction for Contains {
+    fn name(&self) -> &str {
+        "contains"
+    }
+
+    fn signature(&self) -> NativeSignature {
+        NativeSignature::new(
+            vec![Some(ValueType::String), Some(ValueType::String)],
+            Some(ValueType::Boolean),
+        )
+    }
+
+    fn call<'s, 'v>(
+        &self,
+        _evaluation: &Evaluation<'_, '_, 's, 'v>,
+        arguments: &[Value<'s, 'v>],
+    ) -> Result<Value<'s, 'v>, EvalError<'s, 'v>> {
+        arguments!(arguments, [Value::String(subject), Value::String(needle)]);
+
+        Ok(Value::Boolean(subject.contains(needle.as_ref())))
+    }
+}
+
+pub struct Replace;
+
+impl NativeFunction for Replace {
+    fn name(&self) -> &str {
+        "replace"
+    }
+
+    fn signature(&self) -> NativeSignature {
+        NativeSignature::new(
+            vec![
+                Some(ValueType::String),
+                Some(ValueType::String),
+                Some(ValueType::String),
+            ],
+            Some(ValueType::String),
+        )
+    }
+
+    fn call<'s, 'v>(
+        &self,
+        _evaluation: &Evaluation<'_, '_, 's, 'v>,
+        arguments: &[Value<'s, 'v>],
+    ) -> Result<Value<'s, 'v>, EvalError<'s, 'v>> {
+        arguments!(
+            arguments,
+            [
+                Value::String(subject),
+                Value::String(pattern),
+                Value::String(replacement),
+            ]
+        );
+
+        if pattern.is_empty() {
+            return Ok(string_value(subject.as_ref()));
+        }
+
+        Ok(string_value(subject.replace(pattern.as_ref(), replacement)))
+    }
+}
+
+pub struct Substring;
+
+impl NativeFunction for Substring {
+    fn name(&self) -> &str {
+        "substring"
+    }
+
+    fn signature(&self) -> NativeSignature {
+        NativeSignature::new(
+            vec![
+                Some(ValueType::String),
+                Some(ValueType::Integer),
+                Some(ValueType::Integer),
+            ],
+            Some(ValueType::String),
+        )
+    }
+
+    fn call<'s, 'v>(
+        &self,
+        _evaluation: &Evaluation<'_, '_, 's, 'v>,
+        arguments: &[Value<'s, 'v>],
+    ) -> Result<Value<'s, 'v>, EvalError<'s, 'v>> {
+        arguments!(
+            arguments,
+            [
+                Value::String(subject),
+                Value::Integer(start),
+                Value::Integer(end)
+            ]
+        );
+        let (start, end) = (*start, *end);
+        let length = subject.chars().count();
+
+        if start < 0 || start as usize > length {
+            return Err(EvalErrorReason::OutOfBound(length, start as isize).into());
+        }
+        if end < start || end as usize > length {
+            return Err(EvalErrorReason::OutOfBound(length, end as isize).into());
+        }
+
+        Ok(string_value(
+            subject
+                .chars()
+                .skip(start as usize)
+                .take((end - start) as usize)
+                .collect::<String>(),
+        ))
+    }
+}
+
+pub struct Chars;
+
+impl NativeFunction for Chars {
+    fn name(&self) -> &str {
+        "chars"
+    }
+
+    fn signature(&self) -> NativeSignature {
+        NativeSignature::new(vec![Some(ValueType::String)], Some(ValueType::Array))
+    }
+
+    fn call<'s, 'v>(
+        &self,
+        _evaluation: &Evaluation<'_, '_, 's, 'v>,
+        arguments: &[Value<'s, 'v>],
+    ) -> Result<Value<'s, 'v>, EvalError<'s, 'v>> {
+        arguments!(arguments, [Value::String(subject)]);
+
+        Ok(Value::Array(
+            subject
+                .chars()
+                .map(|c| Cow::Owned(string_value(c)))
+                .collect(),
+        ))
+    }
+}
@@ -3,3 +3,5 @@ pub mod expression;
 pub mod iterator;
 pub mod location;
 pub mod pattern;
+pub mod pretty;
+pub mod visit;
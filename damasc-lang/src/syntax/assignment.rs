@@ -5,7 +5,7 @@ use crate::{
 
 use super::{expression::Expression, pattern::Pattern};
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Assignment<'a, 'b> {
     pub pattern: Pattern<'a>,
     pub expression: Expression<'b>,
@@ -26,7 +26,7 @@ impl<'a, 'b> Assignment<'a, 'b> {
     }
 }
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct AssignmentSet<'a, 'b> {
     pub assignments: Vec<Assignment<'a, 'b>>,
 }
@@ -6,7 +6,9 @@ use std::borrow::Cow;
 use crate::identifier::Identifier;
 use crate::literal::Literal;
 
+use super::assignment::AssignmentSet;
 use super::pattern::Pattern;
+use super::pretty::PrettyPrinter;
 
 #[derive(Clone, Debug, PartialOrd)]
 pub struct Expression<'s> {
@@ -68,6 +70,8 @@ pub enum ExpressionBody<'s> {
     Literal(Literal<'s>),
     Logical(LogicalExpression<'s>),
     Member(MemberExpression<'s>),
+    Slice(SliceExpression<'s>),
+    Range(RangeExpression<'s>),
     Object(ObjectExpression<'s>),
     Unary(UnaryExpression<'s>),
     Call(CallExpression<'s>),
@@ -78,6 +82,9 @@ pub enum ExpressionBody<'s> {
     ObjectComp(ObjectComprehension<'s>),
     Condition(IfElseExpression<'s>),
     Match(MatchExpression<'s>),
+    TryCatch(TryCatchExpression<'s>),
+    Let(LetExpression<'s>),
+    Pipe(PipeExpression<'s>),
 }
 
 impl<'s> Expression<'s> {
@@ -93,6 +100,8 @@ impl<'s> Expression<'s> {
                 ExpressionBody::Literal(l) => ExpressionBody::Literal(l.deep_clone()),
                 ExpressionBody::Logical(l) => ExpressionBody::Logical(l.deep_clone()),
                 ExpressionBody::Member(x) => ExpressionBody::Member(x.deep_clone()),
+                ExpressionBody::Slice(x) => ExpressionBody::Slice(x.deep_clone()),
+                ExpressionBody::Range(x) => ExpressionBody::Range(x.deep_clone()),
                 ExpressionBody::Object(x) => {
                     ExpressionBody::Object(x.iter().map(|e| e.deep_clone()).collect())
                 }
@@ -105,6 +114,9 @@ impl<'s> Expression<'s> {
                 ExpressionBody::ObjectComp(x) => ExpressionBody::ObjectComp(x.deep_clone()),
                 ExpressionBody::Match(x) => ExpressionBody::Match(x.deep_clone()),
                 ExpressionBody::Condition(x) => ExpressionBody::Condition(x.deep_clone()),
+                ExpressionBody::TryCatch(x) => ExpressionBody::TryCatch(x.deep_clone()),
+                ExpressionBody::Let(x) => ExpressionBody::Let(x.deep_clone()),
+                ExpressionBody::Pipe(x) => ExpressionBody::Pipe(x.deep_clone()),
             },
         }
     }
@@ -112,194 +124,7 @@ impl<'s> Expression<'s> {
 
 impl std::fmt::Display for Expression<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &self.body {
-            ExpressionBody::Literal(l) => write!(f, "{l}"),
-            ExpressionBody::Array(arr) => {
-                write!(f, "[")?;
-                for item in arr {
-                    match item {
-                        ArrayItem::Single(i) => write!(f, "{i},")?,
-                        ArrayItem::Spread(i) => write!(f, "...({i}),")?,
-                    }
-                }
-                write!(f, "]")
-            }
-            ExpressionBody::Binary(BinaryExpression {
-                operator,
-                left,
-                right,
-            }) => {
-                write!(
-                    f,
-                    "({left} {} {right})",
-                    match operator {
-                        BinaryOperator::StrictEqual => "==",
-                        BinaryOperator::StrictNotEqual => "!=",
-                        BinaryOperator::LessThan => "<",
-                        BinaryOperator::GreaterThan => ">",
-                        BinaryOperator::LessThanEqual => "<=",
-                        BinaryOperator::GreaterThanEqual => ">=",
-                        BinaryOperator::Plus => "+",
-                        BinaryOperator::Minus => "-",
-                        BinaryOperator::Times => "*",
-                        BinaryOperator::Over => "/",
-                        BinaryOperator::Mod => "%",
-                        BinaryOperator::In => "in",
-                        BinaryOperator::PowerOf => "^",
-                        BinaryOperator::Is => "is",
-                        BinaryOperator::Cast => "cast",
-                    }
-                )
-            }
-            ExpressionBody::Identifier(id) => write!(f, "{id}"),
-            ExpressionBody::Logical(LogicalExpression {
-                left,
-                right,
-                operator,
-            }) => {
-                write!(
-                    f,
-                    "({left} {} {right})",
-                    match operator {
-                        LogicalOperator::Or => "||",
-                        LogicalOperator::And => "&&",
-                    }
-                )
-            }
-            ExpressionBody::Member(MemberExpression { object, property }) => {
-                write!(f, "{object}[{property}]")
-            }
-            ExpressionBody::Object(props) => {
-                write!(f, "{{")?;
-                for prop in props {
-                    match prop {
-                        ObjectProperty::Single(id) => write!(f, "{id},")?,
-                        ObjectProperty::Property(Property { key, value }) => {
-                            match key {
-                                PropertyKey::Identifier(id) => write!(f, "{id}: {value},"),
-                                PropertyKey::Expression(expr) => write!(f, "[{expr}]: {value},"),
-                            }?;
-                        }
-                        ObjectProperty::Spread(expr) => write!(f, "...({expr}),")?,
-                    }
-                }
-                write!(f, "}}")
-            }
-            ExpressionBody::Unary(UnaryExpression { operator, argument }) => {
-                write!(
-                    f,
-                    "({} {argument})",
-                    match operator {
-                        UnaryOperator::Minus => "-",
-                        UnaryOperator::Plus => "+",
-                        UnaryOperator::Not => "!",
-                    }
-                )
-            }
-            ExpressionBody::Call(CallExpression { function, argument }) => {
-                write!(f, "{function}({argument})")
-            }
-            ExpressionBody::Template(StringTemplate { parts, suffix }) => {
-                write!(f, "$`")?;
-                for p in parts {
-                    write!(f, "{}${{{}}}", p.fixed_start, p.dynamic_end)?;
-                }
-                write!(f, "{suffix}`")
-            }
-            ExpressionBody::Abstraction(LambdaAbstraction { arguments, body }) => {
-                write!(f, "fn ({arguments}) => {body}")
-            }
-            ExpressionBody::Application(LambdaApplication { lambda, parameter }) => {
-                write!(f, "{lambda}({parameter})")
-            }
-            ExpressionBody::Match(MatchExpression { subject, cases }) => {
-                write!(f, "match ({subject}) {{")?;
-                for case in cases {
-                    write!(f, "({0})  => {1}", case.pattern, case.body)?;
-                }
-                write!(f, "}}")
-            }
-            ExpressionBody::Condition(IfElseExpression {
-                condition,
-                true_branch,
-                false_branch,
-            }) => {
-                write!(f, "if ({condition}) {{ {true_branch} }}")?;
-                if let Some(fb) = false_branch {
-                    write!(f, "else {{ {fb} }}")
-                } else {
-                    Ok(())
-                }
-            }
-            ExpressionBody::ArrayComp(ArrayComprehension {
-                sources,
-                projection,
-            }) => {
-                write!(f, "[")?;
-                for item in projection {
-                    match item {
-                        ArrayItem::Single(i) => write!(f, "{i},")?,
-                        ArrayItem::Spread(i) => write!(f, "...({i}),")?,
-                    }
-                }
-
-                for ComprehensionSource {
-                    collection,
-                    pattern,
-                    predicate,
-                    strong_pattern,
-                } in sources
-                {
-                    if *strong_pattern {
-                        write!(f, "for {pattern} in {collection}")?;
-                    } else {
-                        write!(f, "for match {pattern} in {collection}")?;
-                    }
-                    if let Some(p) = predicate {
-                        write!(f, " if {p}")?;
-                    }
-                }
-
-                write!(f, "]")
-            }
-            ExpressionBody::ObjectComp(ObjectComprehension {
-                sources,
-                projection,
-            }) => {
-                write!(f, "{{")?;
-                for prop in projection {
-                    match prop {
-                        ObjectProperty::Single(id) => write!(f, "{id},")?,
-                        ObjectProperty::Property(Property { key, value }) => {
-                            match key {
-                                PropertyKey::Identifier(id) => write!(f, "{id}: {value},"),
-                                PropertyKey::Expression(expr) => write!(f, "[{expr}]: {value},"),
-                            }?;
-                        }
-                        ObjectProperty::Spread(expr) => write!(f, "...({expr}),")?,
-                    }
-                }
-
-                for ComprehensionSource {
-                    collection,
-                    pattern,
-                    predicate,
-                    strong_pattern,
-                } in sources
-                {
-                    if *strong_pattern {
-                        write!(f, "for {pattern} in {collection}")?;
-                    } else {
-                        write!(f, "for match {pattern} in {collection}")?;
-                    }
-                    if let Some(p) = predicate {
-                        write!(f, " if {p}")?;
-                    }
-                }
-
-                write!(f, "}}")
-            }
-        }
+        write!(f, "{}", PrettyPrinter::single_line().print_expression(self))
     }
 }
 
@@ -373,13 +198,13 @@ impl PropertyKey<'_> {
 #[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct CallExpression<'a> {
     pub function: Identifier<'a>,
-    pub argument: Box<Expression<'a>>,
+    pub arguments: Vec<Expression<'a>>,
 }
 impl CallExpression<'_> {
     fn deep_clone<'x>(&self) -> CallExpression<'x> {
         CallExpression {
             function: self.function.deep_clone(),
-            argument: Box::new(self.argument.deep_clone()),
+            arguments: self.arguments.iter().map(|a| a.deep_clone()).collect(),
         }
     }
 }
@@ -414,13 +239,15 @@ impl StringTemplatePart<'_> {
 
 #[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct LambdaAbstraction<'a> {
-    pub arguments: Pattern<'a>,
+    pub name: Option<Identifier<'a>>,
+    pub arguments: Vec<Pattern<'a>>,
     pub body: Box<Expression<'a>>,
 }
 impl LambdaAbstraction<'_> {
     fn deep_clone<'x>(&self) -> LambdaAbstraction<'x> {
         LambdaAbstraction {
-            arguments: self.arguments.deep_clone(),
+            name: self.name.as_ref().map(|n| n.deep_clone()),
+            arguments: self.arguments.iter().map(|a| a.deep_clone()).collect(),
             body: Box::new(self.body.deep_clone()),
         }
     }
@@ -429,13 +256,13 @@ impl LambdaAbstraction<'_> {
 #[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct LambdaApplication<'a> {
     pub lambda: Box<Expression<'a>>,
-    pub parameter: Box<Expression<'a>>,
+    pub parameters: Vec<Expression<'a>>,
 }
 impl LambdaApplication<'_> {
     fn deep_clone<'x>(&self) -> LambdaApplication<'x> {
         LambdaApplication {
             lambda: Box::new(self.lambda.deep_clone()),
-            parameter: Box::new(self.parameter.deep_clone()),
+            parameters: self.parameters.iter().map(|p| p.deep_clone()).collect(),
         }
     }
 }
@@ -492,6 +319,85 @@ impl IfElseExpression<'_> {
     }
 }
 
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SliceExpression<'a> {
+    pub object: Box<Expression<'a>>,
+    pub start: Option<Box<Expression<'a>>>,
+    pub end: Option<Box<Expression<'a>>>,
+}
+
+impl SliceExpression<'_> {
+    fn deep_clone<'x>(&self) -> SliceExpression<'x> {
+        SliceExpression {
+            object: Box::new(self.object.deep_clone()),
+            start: self.start.as_ref().map(|s| Box::new(s.deep_clone())),
+            end: self.end.as_ref().map(|e| Box::new(e.deep_clone())),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RangeExpression<'a> {
+    pub start: Box<Expression<'a>>,
+    pub end: Box<Expression<'a>>,
+}
+
+impl RangeExpression<'_> {
+    fn deep_clone<'x>(&self) -> RangeExpression<'x> {
+        RangeExpression {
+            start: Box::new(self.start.deep_clone()),
+            end: Box::new(self.end.deep_clone()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LetExpression<'a> {
+    pub assignments: AssignmentSet<'a, 'a>,
+    pub body: Box<Expression<'a>>,
+}
+
+impl LetExpression<'_> {
+    fn deep_clone<'x>(&self) -> LetExpression<'x> {
+        LetExpression {
+            assignments: self.assignments.deep_clone(),
+            body: Box::new(self.body.deep_clone()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PipeExpression<'a> {
+    pub value: Box<Expression<'a>>,
+    pub function: Box<Expression<'a>>,
+}
+
+impl PipeExpression<'_> {
+    fn deep_clone<'x>(&self) -> PipeExpression<'x> {
+        PipeExpression {
+            value: Box::new(self.value.deep_clone()),
+            function: Box::new(self.function.deep_clone()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TryCatchExpression<'a> {
+    pub body: Box<Expression<'a>>,
+    pub pattern: Pattern<'a>,
+    pub fallback: Box<Expression<'a>>,
+}
+
+impl TryCatchExpression<'_> {
+    fn deep_clone<'x>(&self) -> TryCatchExpression<'x> {
+        TryCatchExpression {
+            body: Box::new(self.body.deep_clone()),
+            pattern: self.pattern.deep_clone(),
+            fallback: Box::new(self.fallback.deep_clone()),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct ArrayComprehension<'a> {
     pub sources: Vec<ComprehensionSource<'a>>,
@@ -601,6 +507,7 @@ pub enum BinaryOperator {
     PowerOf,
     Is,
     Cast,
+    NullCoalesce,
 }
 
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
@@ -629,12 +536,14 @@ pub enum UnaryOperator {
 pub struct MemberExpression<'a> {
     pub object: Box<Expression<'a>>,
     pub property: Box<Expression<'a>>,
+    pub optional: bool,
 }
 impl MemberExpression<'_> {
     fn deep_clone<'x>(&self) -> MemberExpression<'x> {
         MemberExpression {
             object: Box::new(self.object.deep_clone()),
             property: Box::new(self.property.deep_clone()),
+            optional: self.optional,
         }
     }
 }
@@ -1,6 +1,12 @@
+use crate::syntax::assignment::Assignment;
 use crate::syntax::expression::ExpressionBody;
 use crate::syntax::expression::IfElseExpression;
+use crate::syntax::expression::LetExpression;
 use crate::syntax::expression::MatchExpression;
+use crate::syntax::expression::PipeExpression;
+use crate::syntax::expression::RangeExpression;
+use crate::syntax::expression::SliceExpression;
+use crate::syntax::expression::TryCatchExpression;
 use crate::syntax::pattern::PatternBody;
 use nom::lib::std::collections::HashSet;
 use std::collections::VecDeque;
@@ -8,6 +14,7 @@ use std::collections::VecDeque;
 use either::Either::{self, Left, Right};
 
 use crate::identifier::Identifier;
+use crate::syntax::location::Location;
 
 use super::{
     expression::{
@@ -16,39 +23,83 @@ use super::{
         ObjectComprehension, ObjectProperty, Property, PropertyKey, StringTemplate,
         UnaryExpression,
     },
-    pattern::{ArrayPatternItem, ObjectPropertyPattern, Pattern, PropertyPattern, Rest},
+    pattern::{
+        ArrayPatternItem, ObjectPropertyPattern, Pattern, PropertyPattern, RegexPattern, Rest,
+        StringPattern,
+    },
 };
 
 impl Pattern<'_> {
     pub(crate) fn get_identifiers(&self) -> impl Iterator<Item = &Identifier> {
-        PatternIterator::new(self).flat_map(|p| match &p.body {
+        self.get_bindings().map(|(id, _)| id)
+    }
+
+    /// The bound identifiers together with the location of the innermost
+    /// pattern binding them.
+    pub(crate) fn get_bindings(&self) -> impl Iterator<Item = (&Identifier<'_>, Option<Location>)> {
+        PatternIterator::new(self).flat_map(|p| p.bound_here().map(move |id| (id, p.location)))
+    }
+
+    fn bound_here(&self) -> impl Iterator<Item = &Identifier<'_>> {
+        match &self.body {
             PatternBody::Capture(id, _) => Either::Left(Some(id).into_iter()),
             PatternBody::Identifier(id) => Either::Left(Some(id).into_iter()),
             PatternBody::TypedIdentifier(id, _) => Either::Left(Some(id).into_iter()),
-            PatternBody::Object(props, ..) => Either::Right(props.iter().filter_map(|p| match p {
-                ObjectPropertyPattern::Single(id) => Some(id),
-                ObjectPropertyPattern::Match(PropertyPattern { key, .. }) => match key {
-                    PropertyKey::Identifier(id) => Some(id),
-                    PropertyKey::Expression(_expr) => None,
-                },
-            })),
+            PatternBody::Object(props, ..) => {
+                let singles = props.iter().filter_map(|p| match p {
+                    ObjectPropertyPattern::Single(id) => Some(id),
+                    ObjectPropertyPattern::Default(id, _) => Some(id),
+                    ObjectPropertyPattern::Optional(id) => Some(id),
+                    // the key is only matched against, the value pattern is visited separately
+                    ObjectPropertyPattern::Match(_) => None,
+                });
+
+                Either::Right(Box::new(singles) as Box<dyn Iterator<Item = &Identifier>>)
+            }
+            PatternBody::Regex(RegexPattern { captures, .. }) => {
+                Either::Right(Box::new(captures.iter()) as Box<dyn Iterator<Item = &Identifier>>)
+            }
             PatternBody::Discard => Either::Left(None.into_iter()),
             PatternBody::TypedDiscard(_) => Either::Left(None.into_iter()),
             PatternBody::Literal(_) => Either::Left(None.into_iter()),
-            PatternBody::Array(_, _) => Either::Left(None.into_iter()),
+            PatternBody::Array(..) => Either::Left(None.into_iter()),
+            PatternBody::String(_) => Either::Left(None.into_iter()),
             PatternBody::PinnedExpression(_) => Either::Left(None.into_iter()),
-        })
+            PatternBody::Alternative(_) => Either::Left(None.into_iter()),
+            PatternBody::Range(_) => Either::Left(None.into_iter()),
+            PatternBody::Relational(_, _) => Either::Left(None.into_iter()),
+        }
+    }
+
+    /// Alternatives of an or-pattern are only valid if each of them binds
+    /// exactly the same identifiers.
+    pub fn alternatives_bind_same_identifiers(alternatives: &[Pattern]) -> bool {
+        let mut bindings = alternatives
+            .iter()
+            .map(|p| p.get_identifiers().collect::<HashSet<_>>());
+        let Some(first) = bindings.next() else {
+            return true;
+        };
+
+        bindings.all(|b| b == first)
     }
 
     pub(crate) fn get_expressions(&self) -> impl Iterator<Item = &Expression> {
         PatternIterator::new(self).flat_map(|p| match &p.body {
             PatternBody::Object(props, _) => {
-                Either::Left(Box::new(props.iter().filter_map(|p| match p {
-                    ObjectPropertyPattern::Single(_id) => None,
-                    ObjectPropertyPattern::Match(PropertyPattern { key, .. }) => match key {
-                        PropertyKey::Identifier(_id) => None,
-                        PropertyKey::Expression(expr) => Some(expr),
-                    },
+                Either::Left(Box::new(props.iter().flat_map(|p| match p {
+                    ObjectPropertyPattern::Single(_id) => None.into_iter().chain(None),
+                    ObjectPropertyPattern::Optional(_id) => None.into_iter().chain(None),
+                    ObjectPropertyPattern::Default(_id, default) => {
+                        None.into_iter().chain(Some(default))
+                    }
+                    ObjectPropertyPattern::Match(PropertyPattern { key, default, .. }) => {
+                        let key = match key {
+                            PropertyKey::Identifier(_id) => None,
+                            PropertyKey::Expression(expr) => Some(expr),
+                        };
+                        key.into_iter().chain(default.as_ref())
+                    }
                 })) as Box<dyn Iterator<Item = &Expression>>)
             }
             PatternBody::Discard => Either::Right(None.into_iter()),
@@ -57,7 +108,12 @@ impl Pattern<'_> {
             PatternBody::TypedDiscard(_) => Either::Right(None.into_iter()),
             PatternBody::TypedIdentifier(_, _) => Either::Right(None.into_iter()),
             PatternBody::Literal(_) => Either::Right(None.into_iter()),
-            PatternBody::Array(_, _) => Either::Right(None.into_iter()),
+            PatternBody::Array(..) => Either::Right(None.into_iter()),
+            PatternBody::String(_) => Either::Right(None.into_iter()),
+            PatternBody::Alternative(_) => Either::Right(None.into_iter()),
+            PatternBody::Range(_) => Either::Right(None.into_iter()),
+            PatternBody::Relational(_, _) => Either::Right(None.into_iter()),
+            PatternBody::Regex(_) => Either::Right(None.into_iter()),
             PatternBody::PinnedExpression(e) => Either::Left(
                 Box::new(Some(e.as_ref()).into_iter()) as Box<dyn Iterator<Item = &Expression>>,
             ),
@@ -80,17 +136,24 @@ impl<'e, 's> PatternIterator<'e, 's> {
     fn push_children(&mut self, pattern: &'e Pattern<'s>) {
         match &pattern.body {
             PatternBody::Discard => {}
-            PatternBody::Capture(_, _) => {}
+            PatternBody::Capture(_, p) => {
+                self.pattern_stack.push_front(p);
+            }
             PatternBody::Identifier(_) => {}
             PatternBody::TypedDiscard(_) => {}
             PatternBody::PinnedExpression(_) => {}
             PatternBody::TypedIdentifier(_, _) => {}
             PatternBody::Literal(_) => {}
+            PatternBody::Range(_) => {}
+            PatternBody::Relational(_, _) => {}
+            PatternBody::Regex(_) => {}
             PatternBody::Object(props, rest) => {
                 for p in props {
                     match p {
                         ObjectPropertyPattern::Single(_) => {}
-                        ObjectPropertyPattern::Match(PropertyPattern { key, value }) => {
+                        ObjectPropertyPattern::Default(_, _) => {}
+                        ObjectPropertyPattern::Optional(_) => {}
+                        ObjectPropertyPattern::Match(PropertyPattern { key, value, .. }) => {
                             match key {
                                 PropertyKey::Identifier(_) => {}
                                 PropertyKey::Expression(_expr) => {}
@@ -103,13 +166,24 @@ impl<'e, 's> PatternIterator<'e, 's> {
                     self.pattern_stack.push_front(p);
                 }
             }
-            PatternBody::Array(items, rest) => {
+            PatternBody::Array(items, rest, suffix) => {
                 for ArrayPatternItem::Pattern(p) in items {
                     self.pattern_stack.push_front(p);
                 }
                 if let Rest::Collect(p) = rest {
                     self.pattern_stack.push_front(p);
                 }
+                for ArrayPatternItem::Pattern(p) in suffix.iter() {
+                    self.pattern_stack.push_front(p);
+                }
+            }
+            PatternBody::String(StringPattern::Prefix(_, p) | StringPattern::Suffix(p, _)) => {
+                self.pattern_stack.push_front(p);
+            }
+            PatternBody::Alternative(alternatives) => {
+                for p in alternatives {
+                    self.pattern_stack.push_front(p);
+                }
             }
         }
     }
@@ -167,10 +241,29 @@ impl<'s, 'e: 's> ExpressionIterator<'e, 's> {
                 self.expression_stack.push_front(left);
                 self.expression_stack.push_front(right);
             }
-            ExpressionBody::Member(MemberExpression { object, property }) => {
+            ExpressionBody::Member(MemberExpression {
+                object, property, ..
+            }) => {
                 self.expression_stack.push_front(object);
                 self.expression_stack.push_front(property);
             }
+            ExpressionBody::Slice(SliceExpression { object, start, end }) => {
+                self.expression_stack.push_front(object);
+                if let Some(start) = start {
+                    self.expression_stack.push_front(start);
+                }
+                if let Some(end) = end {
+                    self.expression_stack.push_front(end);
+                }
+            }
+            ExpressionBody::Range(RangeExpression { start, end }) => {
+                self.expression_stack.push_front(start);
+                self.expression_stack.push_front(end);
+            }
+            ExpressionBody::Pipe(PipeExpression { value, function }) => {
+                self.expression_stack.push_front(value);
+                self.expression_stack.push_front(function);
+            }
             ExpressionBody::Object(props) => {
                 for p in props {
                     match p {
@@ -194,25 +287,31 @@ impl<'s, 'e: 's> ExpressionIterator<'e, 's> {
             ExpressionBody::Unary(UnaryExpression { argument, .. }) => {
                 self.expression_stack.push_front(argument);
             }
-            ExpressionBody::Call(CallExpression { argument, .. }) => {
-                self.expression_stack.push_front(argument);
+            ExpressionBody::Call(CallExpression { arguments, .. }) => {
+                for arg in arguments {
+                    self.expression_stack.push_front(arg);
+                }
             }
             ExpressionBody::Template(StringTemplate { parts, .. }) => {
                 for p in parts {
                     self.expression_stack.push_front(&p.dynamic_end);
                 }
             }
-            ExpressionBody::Abstraction(LambdaAbstraction { arguments, body }) => {
-                for expr in arguments.get_expressions() {
+            ExpressionBody::Abstraction(LambdaAbstraction {
+                arguments, body, ..
+            }) => {
+                for expr in arguments.iter().flat_map(|a| a.get_expressions()) {
                     self.expression_stack.push_front(expr)
                 }
                 if self.deep {
                     self.expression_stack.push_front(body)
                 }
             }
-            ExpressionBody::Application(LambdaApplication { lambda, parameter }) => {
+            ExpressionBody::Application(LambdaApplication { lambda, parameters }) => {
                 self.expression_stack.push_front(lambda);
-                self.expression_stack.push_front(parameter);
+                for param in parameters {
+                    self.expression_stack.push_front(param);
+                }
             }
             ExpressionBody::ArrayComp(ArrayComprehension {
                 sources,
@@ -307,6 +406,34 @@ impl<'s, 'e: 's> ExpressionIterator<'e, 's> {
                     self.expression_stack.push_front(fb);
                 }
             }
+            ExpressionBody::Let(LetExpression { assignments, body }) => {
+                if self.deep {
+                    for Assignment {
+                        pattern,
+                        expression,
+                    } in &assignments.assignments
+                    {
+                        for expr in pattern.get_expressions() {
+                            self.expression_stack.push_front(expr)
+                        }
+                        self.expression_stack.push_front(expression);
+                    }
+                    self.expression_stack.push_front(body);
+                }
+            }
+            ExpressionBody::TryCatch(TryCatchExpression {
+                body,
+                pattern,
+                fallback,
+            }) => {
+                self.expression_stack.push_front(body);
+                for expr in pattern.get_expressions() {
+                    self.expression_stack.push_front(expr)
+                }
+                if self.deep {
+                    self.expression_stack.push_front(fallback);
+                }
+            }
         }
     }
 }
@@ -332,8 +459,16 @@ impl Expression<'_> {
             }))
                 as Box<dyn Iterator<Item = &Identifier>>),
             ExpressionBody::Identifier(id) => Right(Some(id).into_iter()),
-            ExpressionBody::Abstraction(LambdaAbstraction { arguments, body }) => {
-                let locally_bound = arguments.get_identifiers().collect::<HashSet<_>>();
+            ExpressionBody::Abstraction(LambdaAbstraction {
+                name,
+                arguments,
+                body,
+            }) => {
+                let locally_bound = arguments
+                    .iter()
+                    .flat_map(|a| a.get_identifiers())
+                    .chain(name)
+                    .collect::<HashSet<_>>();
                 let inner_free = body
                     .get_identifiers()
                     .filter(move |v| !locally_bound.contains(v));
@@ -474,6 +609,32 @@ impl Expression<'_> {
 
                 Left(Box::new(inner_free) as Box<dyn Iterator<Item = &Identifier>>)
             }
+            ExpressionBody::TryCatch(TryCatchExpression {
+                pattern, fallback, ..
+            }) => {
+                let locally_bound = pattern.get_identifiers().collect::<HashSet<_>>();
+                let inner_free = fallback
+                    .get_identifiers()
+                    .filter(move |v| !locally_bound.contains(v));
+
+                Left(Box::new(inner_free) as Box<dyn Iterator<Item = &Identifier>>)
+            }
+            ExpressionBody::Let(LetExpression { assignments, body }) => {
+                let locally_bound = assignments
+                    .assignments
+                    .iter()
+                    .flat_map(|a| a.pattern.get_identifiers())
+                    .collect::<HashSet<_>>();
+                let inner_free = assignments
+                    .assignments
+                    .iter()
+                    .flat_map(|a| a.pattern.get_expressions().chain(Some(&a.expression)))
+                    .chain(Some(body.as_ref()))
+                    .flat_map(|e| e.get_identifiers())
+                    .filter(move |v| !locally_bound.contains(v));
+
+                Left(Box::new(inner_free) as Box<dyn Iterator<Item = &Identifier>>)
+            }
             _ => Right(None.into_iter()),
         })
     }
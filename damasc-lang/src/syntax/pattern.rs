@@ -3,9 +3,13 @@ use crate::literal::Literal;
 use crate::syntax::expression::Expression;
 use crate::syntax::expression::PropertyKey;
 use crate::syntax::location::Location;
+use crate::syntax::pretty::PrettyPrinter;
 use crate::value_type::ValueType;
 use core::hash::Hash;
 use core::hash::Hasher;
+use regex::Regex;
+use std::borrow::Cow;
+use std::sync::Arc;
 
 #[derive(Clone, Debug, PartialOrd)]
 pub struct Pattern<'s> {
@@ -62,7 +66,12 @@ pub enum PatternBody<'s> {
     TypedIdentifier(Identifier<'s>, ValueType),
     Literal(Literal<'s>),
     Object(ObjectPattern<'s>, Rest<'s>),
-    Array(ArrayPattern<'s>, Rest<'s>),
+    Array(ArrayPattern<'s>, Rest<'s>, Box<ArrayPattern<'s>>),
+    Alternative(Vec<Pattern<'s>>),
+    Range(Box<RangePattern<'s>>),
+    Relational(RelationalOperator, Literal<'s>),
+    Regex(RegexPattern<'s>),
+    String(StringPattern<'s>),
 }
 
 impl<'s> Pattern<'s> {
@@ -84,81 +93,29 @@ impl<'s> Pattern<'s> {
                     pat.iter().map(|e| e.deep_clone()).collect(),
                     rst.deep_clone(),
                 ),
-                PatternBody::Array(pat, rst) => PatternBody::Array(
+                PatternBody::Array(pat, rst, suffix) => PatternBody::Array(
                     pat.iter().map(|e| e.deep_clone()).collect(),
                     rst.deep_clone(),
+                    Box::new(suffix.iter().map(|e| e.deep_clone()).collect()),
                 ),
                 PatternBody::PinnedExpression(e) => {
                     PatternBody::PinnedExpression(Box::new(e.deep_clone()))
                 }
+                PatternBody::Alternative(alternatives) => {
+                    PatternBody::Alternative(alternatives.iter().map(|p| p.deep_clone()).collect())
+                }
+                PatternBody::Range(r) => PatternBody::Range(Box::new(r.deep_clone())),
+                PatternBody::Relational(op, l) => PatternBody::Relational(*op, l.deep_clone()),
+                PatternBody::Regex(r) => PatternBody::Regex(r.deep_clone()),
+                PatternBody::String(s) => PatternBody::String(s.deep_clone()),
             },
         }
     }
 }
 
-impl<'a> std::fmt::Display for Pattern<'a> {
+impl std::fmt::Display for Pattern<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let _ = match &self.body {
-            PatternBody::Discard => write!(f, "_"),
-            PatternBody::Literal(l) => write!(f, "{l}"),
-            PatternBody::Capture(id, pat) => write!(f, "{id} @ {pat}"),
-            PatternBody::TypedDiscard(t) => write!(f, "_ is {t}"),
-            PatternBody::Identifier(id) => write!(f, "{id}"),
-            PatternBody::PinnedExpression(expr) => write!(f, "^{expr}"),
-            PatternBody::TypedIdentifier(id, t) => write!(f, "{id} is {t}"),
-            PatternBody::Object(props, rest) => {
-                let _ = write!(f, "{{");
-
-                for prop in props {
-                    let _ = match prop {
-                        ObjectPropertyPattern::Single(p) => write!(f, "{p}"),
-                        ObjectPropertyPattern::Match(PropertyPattern { key, value }) => {
-                            let _ = match key {
-                                PropertyKey::Identifier(id) => {
-                                    write!(f, "{id}")
-                                }
-                                PropertyKey::Expression(e) => {
-                                    write!(f, "{e}")
-                                }
-                            };
-
-                            write!(f, ": {value}")
-                        }
-                    };
-                    let _ = write!(f, ",");
-                }
-
-                match rest {
-                    Rest::Exact => {}
-                    Rest::Discard => {
-                        let _ = write!(f, "...");
-                    }
-                    Rest::Collect(p) => {
-                        let _ = write!(f, "...{p}");
-                    }
-                };
-
-                write!(f, "}}")
-            }
-            PatternBody::Array(items, rest) => {
-                let _ = write!(f, "[");
-                for ArrayPatternItem::Pattern(item) in items {
-                    let _ = write!(f, "{item},");
-                }
-
-                match rest {
-                    Rest::Exact => {}
-                    Rest::Discard => {
-                        let _ = write!(f, "...");
-                    }
-                    Rest::Collect(p) => {
-                        let _ = write!(f, "...{p}");
-                    }
-                };
-                write!(f, "]")
-            }
-        };
-        write!(f, "")
+        write!(f, "{}", PrettyPrinter::single_line().print_pattern(self))
     }
 }
 
@@ -183,6 +140,126 @@ impl Rest<'_> {
     }
 }
 
+#[derive(Clone, Debug, PartialOrd, Ord, Eq, PartialEq, Hash)]
+pub struct RangePattern<'a> {
+    pub start: Literal<'a>,
+    pub end: Literal<'a>,
+    pub inclusive: bool,
+}
+impl RangePattern<'_> {
+    fn deep_clone<'x>(&self) -> RangePattern<'x> {
+        RangePattern {
+            start: self.start.deep_clone(),
+            end: self.end.deep_clone(),
+            inclusive: self.inclusive,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialOrd, Ord, Eq, PartialEq, Hash)]
+pub enum RelationalOperator {
+    LessThan,
+    LessThanEqual,
+    GreaterThan,
+    GreaterThanEqual,
+}
+
+impl std::fmt::Display for RelationalOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RelationalOperator::LessThan => write!(f, "<"),
+            RelationalOperator::LessThanEqual => write!(f, "<="),
+            RelationalOperator::GreaterThan => write!(f, ">"),
+            RelationalOperator::GreaterThanEqual => write!(f, ">="),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct RegexPattern<'a> {
+    pub source: Cow<'a, str>,
+    pub captures: Vec<Identifier<'a>>,
+    /// The compiled `source`, shared between clones of the pattern.
+    pub regex: Arc<Regex>,
+}
+
+impl PartialEq for RegexPattern<'_> {
+    fn eq(&self, other: &RegexPattern<'_>) -> bool {
+        self.source == other.source && self.captures == other.captures
+    }
+}
+
+impl Eq for RegexPattern<'_> {}
+
+impl Hash for RegexPattern<'_> {
+    fn hash<H>(&self, hasher: &mut H)
+    where
+        H: Hasher,
+    {
+        self.source.hash(hasher);
+        self.captures.hash(hasher);
+    }
+}
+
+impl PartialOrd for RegexPattern<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for RegexPattern<'_> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (&self.source, &self.captures).cmp(&(&other.source, &other.captures))
+    }
+}
+
+impl<'a> RegexPattern<'a> {
+    /// Compiles the regular expression and collects its named groups,
+    /// which get bound as identifiers when the pattern matches.
+    pub fn new(source: Cow<'a, str>) -> Result<Self, regex::Error> {
+        let regex = Regex::new(&source)?;
+        let captures = regex
+            .capture_names()
+            .flatten()
+            .map(|name| Identifier::new_owned(name.to_string()))
+            .collect();
+
+        Ok(Self {
+            source,
+            captures,
+            regex: Arc::new(regex),
+        })
+    }
+
+    fn deep_clone<'x>(&self) -> RegexPattern<'x> {
+        RegexPattern {
+            source: Cow::Owned(self.source.to_string()),
+            captures: self.captures.iter().map(|c| c.deep_clone()).collect(),
+            regex: self.regex.clone(),
+        }
+    }
+}
+
+/// Destructures a string by a known prefix or suffix, matching the
+/// remainder against the inner pattern.
+#[derive(Clone, Debug, PartialOrd, Ord, Eq, PartialEq, Hash)]
+pub enum StringPattern<'a> {
+    Prefix(Cow<'a, str>, Box<Pattern<'a>>),
+    Suffix(Box<Pattern<'a>>, Cow<'a, str>),
+}
+impl StringPattern<'_> {
+    fn deep_clone<'x>(&self) -> StringPattern<'x> {
+        match self {
+            StringPattern::Prefix(prefix, rest) => {
+                StringPattern::Prefix(Cow::Owned(prefix.to_string()), Box::new(rest.deep_clone()))
+            }
+            StringPattern::Suffix(rest, suffix) => {
+                StringPattern::Suffix(Box::new(rest.deep_clone()), Cow::Owned(suffix.to_string()))
+            }
+        }
+    }
+}
+
 pub type ObjectPattern<'a> = Vec<ObjectPropertyPattern<'a>>;
 pub type ArrayPattern<'a> = Vec<ArrayPatternItem<'a>>;
 
@@ -203,12 +280,29 @@ impl ArrayPatternItem<'_> {
 pub enum ObjectPropertyPattern<'a> {
     Single(Identifier<'a>),
     Match(PropertyPattern<'a>),
+    /// Binds the value of the key or the default if the key is missing.
+    Default(Identifier<'a>, Expression<'a>),
+    /// Binds the value of the key or `null` if the key is missing.
+    Optional(Identifier<'a>),
 }
 impl ObjectPropertyPattern<'_> {
     fn deep_clone<'x>(&self) -> ObjectPropertyPattern<'x> {
         match self {
             ObjectPropertyPattern::Single(s) => ObjectPropertyPattern::Single(s.deep_clone()),
             ObjectPropertyPattern::Match(m) => ObjectPropertyPattern::Match(m.deep_clone()),
+            ObjectPropertyPattern::Default(s, e) => {
+                ObjectPropertyPattern::Default(s.deep_clone(), e.deep_clone())
+            }
+            ObjectPropertyPattern::Optional(s) => ObjectPropertyPattern::Optional(s.deep_clone()),
+        }
+    }
+
+    pub(crate) fn is_optional(&self) -> bool {
+        match self {
+            ObjectPropertyPattern::Single(_) => false,
+            ObjectPropertyPattern::Match(m) => m.default.is_some(),
+            ObjectPropertyPattern::Default(_, _) => true,
+            ObjectPropertyPattern::Optional(_) => true,
         }
     }
 }
@@ -217,12 +311,14 @@ impl ObjectPropertyPattern<'_> {
 pub struct PropertyPattern<'a> {
     pub key: PropertyKey<'a>,
     pub value: Pattern<'a>,
+    pub default: Option<Expression<'a>>,
 }
 impl PropertyPattern<'_> {
     fn deep_clone<'x>(&self) -> PropertyPattern<'x> {
         PropertyPattern {
             key: self.key.deep_clone(),
             value: self.value.deep_clone(),
+            default: self.default.as_ref().map(|d| d.deep_clone()),
         }
     }
 }
@@ -0,0 +1,1040 @@
+//! A pretty printer producing source code that parses back to the same syntax
+//! tree, with as few parentheses as the grammar allows.
+//!
+//! Expressions are first translated into a document of text, line breaks,
+//! indentation and groups. When rendering, each group is laid out on a single
+//! line if it fits into the configured width, otherwise its line breaks are
+//! taken.
+
+use crate::identifier::Identifier;
+use crate::literal::Literal;
+
+use super::assignment::{Assignment, AssignmentSet};
+use super::expression::{
+    ArrayComprehension, ArrayItem, BinaryExpression, BinaryOperator, CallExpression,
+    ComprehensionSource, Expression, ExpressionBody, ExpressionSet, IfElseExpression,
+    LambdaAbstraction, LambdaApplication, LetExpression, LogicalExpression, LogicalOperator,
+    MatchCase, MatchExpression, MemberExpression, ObjectComprehension, ObjectProperty,
+    PipeExpression, Property, PropertyKey, RangeExpression, SliceExpression, StringTemplate,
+    TryCatchExpression, UnaryExpression, UnaryOperator,
+};
+use super::pattern::{
+    ArrayPatternItem, ObjectPropertyPattern, Pattern, PatternBody, PropertyPattern, RangePattern,
+    Rest, StringPattern,
+};
+
+const KEYWORDS: [&str; 13] = [
+    "where", "into", "limit", "with", "fn", "match", "if", "else", "try", "catch", "let", "for",
+    "in",
+];
+
+// Identifiers starting with a literal are read as that literal.
+const LITERALS: [&str; 12] = [
+    "null", "true", "false", "Type", "Null", "Boolean", "Integer", "Float", "Array", "Object",
+    "String", "Lambda",
+];
+
+// The parameter of the lambda a `fn match` expression desugars to.
+const MATCH_ABSTRACTION_PARAMETER: &str = "___local";
+
+#[derive(Clone, Debug)]
+pub struct PrettyPrinter {
+    width: usize,
+    indent: usize,
+}
+
+impl Default for PrettyPrinter {
+    fn default() -> Self {
+        Self {
+            width: 80,
+            indent: 4,
+        }
+    }
+}
+
+impl PrettyPrinter {
+    /// Prints everything on a single line.
+    pub fn single_line() -> Self {
+        Self::default().with_width(usize::MAX)
+    }
+
+    pub fn with_width(self, width: usize) -> Self {
+        Self { width, ..self }
+    }
+
+    pub fn with_indent(self, indent: usize) -> Self {
+        Self { indent, ..self }
+    }
+
+    pub fn print_expression(&self, expression: &Expression) -> String {
+        self.render(&self.expression(expression, Position::TOP))
+    }
+
+    /// Prints one expression per line, each terminated by a semicolon.
+    pub fn print_expression_set(&self, set: &ExpressionSet) -> String {
+        set.expressions
+            .iter()
+            .map(|e| {
+                let doc = concat([self.expression(e, Position::TOP), text(";")]);
+                format!("{}\n", self.render(&doc))
+            })
+            .collect()
+    }
+
+    pub fn print_pattern(&self, pattern: &Pattern) -> String {
+        self.render(&self.pattern(pattern))
+    }
+
+    pub fn print_assignment(&self, assignment: &Assignment) -> String {
+        self.render(&self.assignment(assignment, Position::TOP))
+    }
+
+    /// Prints one assignment per line, each terminated by a semicolon.
+    pub fn print_assignment_set(&self, set: &AssignmentSet) -> String {
+        set.assignments
+            .iter()
+            .map(|a| {
+                let doc = concat([self.assignment(a, Position::TOP), text(";")]);
+                format!("{}\n", self.render(&doc))
+            })
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Doc {
+    Text(String),
+    /// A space, or a line break when the enclosing group is broken.
+    Line,
+    /// Nothing, or a line break when the enclosing group is broken.
+    SoftLine,
+    /// Text that is only printed when the enclosing group is broken.
+    IfBroken(&'static str),
+    Nest(Box<Doc>),
+    Group(Box<Doc>),
+    Concat(Vec<Doc>),
+}
+
+fn text(text: impl Into<String>) -> Doc {
+    Doc::Text(text.into())
+}
+
+fn concat(docs: impl IntoIterator<Item = Doc>) -> Doc {
+    Doc::Concat(docs.into_iter().collect())
+}
+
+fn nest(doc: Doc) -> Doc {
+    Doc::Nest(Box::new(doc))
+}
+
+fn group(doc: Doc) -> Doc {
+    Doc::Group(Box::new(doc))
+}
+
+fn parenthesized(doc: Doc) -> Doc {
+    concat([text("("), doc, text(")")])
+}
+
+/// Comma separated items between the delimiters, one per line if they do
+/// not fit into a single line.
+fn list(open: &str, items: Vec<Doc>, close: &str, trailing_comma: bool) -> Doc {
+    if items.is_empty() {
+        return text(format!("{open}{close}"));
+    }
+
+    let mut inner = vec![Doc::SoftLine];
+    for (i, item) in items.into_iter().enumerate() {
+        if i > 0 {
+            inner.push(text(","));
+            inner.push(Doc::Line);
+        }
+        inner.push(item);
+    }
+    if trailing_comma {
+        inner.push(Doc::IfBroken(","));
+    }
+
+    group(concat([
+        text(open),
+        nest(concat(inner)),
+        Doc::SoftLine,
+        text(close),
+    ]))
+}
+
+// The first text of the document, if it is not preceded by a line break.
+fn leading_text(doc: &Doc) -> Option<&str> {
+    match doc {
+        Doc::Text(text) if text.is_empty() => None,
+        Doc::Text(text) => Some(text),
+        Doc::Nest(doc) | Doc::Group(doc) => leading_text(doc),
+        Doc::Concat(docs) => docs.iter().find_map(leading_text),
+        Doc::Line | Doc::SoftLine | Doc::IfBroken(_) => None,
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Flat,
+    Broken,
+}
+
+impl PrettyPrinter {
+    fn render(&self, doc: &Doc) -> String {
+        let mut out = String::new();
+        let mut column = 0;
+        let mut stack = vec![(0, Mode::Broken, doc)];
+
+        while let Some((indent, mode, doc)) = stack.pop() {
+            match doc {
+                Doc::Text(text) => {
+                    out.push_str(text);
+                    column += text.chars().count();
+                }
+                Doc::Line if mode == Mode::Flat => {
+                    out.push(' ');
+                    column += 1;
+                }
+                Doc::SoftLine if mode == Mode::Flat => {}
+                Doc::Line | Doc::SoftLine => {
+                    out.push('\n');
+                    out.extend(std::iter::repeat_n(' ', indent));
+                    column = indent;
+                }
+                Doc::IfBroken(text) => {
+                    if mode == Mode::Broken {
+                        out.push_str(text);
+                        column += text.chars().count();
+                    }
+                }
+                Doc::Nest(doc) => stack.push((indent + self.indent, mode, doc)),
+                Doc::Group(doc) => {
+                    let fits = mode == Mode::Flat
+                        || self.fits(self.width.saturating_sub(column), doc, &stack);
+                    stack.push((indent, if fits { Mode::Flat } else { Mode::Broken }, doc));
+                }
+                Doc::Concat(docs) => stack.extend(docs.iter().rev().map(|d| (indent, mode, d))),
+            }
+        }
+
+        out
+    }
+
+    // Checks whether the group fits into the remaining width when laid out
+    // flat, including whatever follows it up to the next line break.
+    fn fits(&self, mut remaining: usize, doc: &Doc, rest: &[(usize, Mode, &Doc)]) -> bool {
+        let mut stack = vec![(Mode::Flat, doc)];
+        let mut rest = rest.iter().rev();
+
+        loop {
+            let (mode, doc) = match stack.pop() {
+                Some(next) => next,
+                None => match rest.next() {
+                    Some((_, mode, doc)) => (*mode, *doc),
+                    None => return true,
+                },
+            };
+
+            let width = match doc {
+                Doc::Text(text) => text.chars().count(),
+                Doc::Line | Doc::SoftLine if mode == Mode::Broken => return true,
+                Doc::Line => 1,
+                Doc::SoftLine => 0,
+                Doc::IfBroken(text) if mode == Mode::Broken => text.len(),
+                Doc::IfBroken(_) => 0,
+                Doc::Nest(doc) | Doc::Group(doc) => {
+                    stack.push((mode, doc));
+                    0
+                }
+                Doc::Concat(docs) => {
+                    stack.extend(docs.iter().rev().map(|d| (mode, d)));
+                    0
+                }
+            };
+
+            match remaining.checked_sub(width) {
+                Some(r) => remaining = r,
+                None => return false,
+            }
+        }
+    }
+}
+
+/// How tightly an expression binds, from loosest to tightest.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Precedence {
+    /// Lambdas, `let`, `match`, `if` and `try`, which can not be operands.
+    Expression,
+    Pipe,
+    NullCoalesce,
+    Or,
+    And,
+    Is,
+    Cast,
+    Comparison,
+    Range,
+    Additive,
+    Multiplicative,
+    Exponential,
+    Path,
+    Primary,
+}
+
+impl Precedence {
+    fn of(expression: &Expression) -> Self {
+        match &expression.body {
+            ExpressionBody::Abstraction(_)
+            | ExpressionBody::Let(_)
+            | ExpressionBody::Match(_)
+            | ExpressionBody::Condition(_)
+            | ExpressionBody::TryCatch(_) => Precedence::Expression,
+            ExpressionBody::Pipe(_) => Precedence::Pipe,
+            ExpressionBody::Logical(LogicalExpression { operator, .. }) => match operator {
+                LogicalOperator::Or => Precedence::Or,
+                LogicalOperator::And => Precedence::And,
+            },
+            ExpressionBody::Binary(BinaryExpression { operator, .. }) => Self::of_binary(*operator),
+            ExpressionBody::Range(_) => Precedence::Range,
+            ExpressionBody::Member(_)
+            | ExpressionBody::Slice(_)
+            | ExpressionBody::Application(_) => Precedence::Path,
+            ExpressionBody::Unary(_)
+            | ExpressionBody::Array(_)
+            | ExpressionBody::Identifier(_)
+            | ExpressionBody::Literal(_)
+            | ExpressionBody::Object(_)
+            | ExpressionBody::Call(_)
+            | ExpressionBody::Template(_)
+            | ExpressionBody::ArrayComp(_)
+            | ExpressionBody::ObjectComp(_) => Precedence::Primary,
+        }
+    }
+
+    fn of_binary(operator: BinaryOperator) -> Self {
+        match operator {
+            BinaryOperator::NullCoalesce => Precedence::NullCoalesce,
+            BinaryOperator::Is => Precedence::Is,
+            BinaryOperator::Cast => Precedence::Cast,
+            BinaryOperator::StrictEqual
+            | BinaryOperator::StrictNotEqual
+            | BinaryOperator::LessThan
+            | BinaryOperator::GreaterThan
+            | BinaryOperator::LessThanEqual
+            | BinaryOperator::GreaterThanEqual
+            | BinaryOperator::In => Precedence::Comparison,
+            BinaryOperator::Plus | BinaryOperator::Minus => Precedence::Additive,
+            BinaryOperator::Times | BinaryOperator::Over | BinaryOperator::Mod => {
+                Precedence::Multiplicative
+            }
+            BinaryOperator::PowerOf => Precedence::Exponential,
+        }
+    }
+
+    fn next(self) -> Self {
+        match self {
+            Precedence::Expression => Precedence::Pipe,
+            Precedence::Pipe => Precedence::NullCoalesce,
+            Precedence::NullCoalesce => Precedence::Or,
+            Precedence::Or => Precedence::And,
+            Precedence::And => Precedence::Is,
+            Precedence::Is => Precedence::Cast,
+            Precedence::Cast => Precedence::Comparison,
+            Precedence::Comparison => Precedence::Range,
+            Precedence::Range => Precedence::Additive,
+            Precedence::Additive => Precedence::Multiplicative,
+            Precedence::Multiplicative => Precedence::Exponential,
+            Precedence::Exponential => Precedence::Path,
+            Precedence::Path | Precedence::Primary => Precedence::Primary,
+        }
+    }
+}
+
+/// Where an expression is printed.
+#[derive(Clone, Copy)]
+struct Position {
+    /// The loosest binding expression allowed without parentheses.
+    precedence: Precedence,
+    /// Whether the expression is followed by a closing delimiter only, so
+    /// that expressions without an end of their own, e.g. a lambda's body,
+    /// can not swallow anything.
+    trailing: bool,
+    /// Binding values of `let` end at the `in` keyword.
+    allow_in: bool,
+}
+
+impl Position {
+    const TOP: Position = Position {
+        precedence: Precedence::Expression,
+        trailing: true,
+        allow_in: true,
+    };
+
+    fn operand(self, precedence: Precedence, trailing: bool) -> Self {
+        Self {
+            precedence,
+            trailing: self.trailing && trailing,
+            allow_in: self.allow_in,
+        }
+    }
+}
+
+fn binary_operator(operator: BinaryOperator) -> &'static str {
+    match operator {
+        BinaryOperator::StrictEqual => "==",
+        BinaryOperator::StrictNotEqual => "!=",
+        BinaryOperator::LessThan => "<",
+        BinaryOperator::GreaterThan => ">",
+        BinaryOperator::LessThanEqual => "<=",
+        BinaryOperator::GreaterThanEqual => ">=",
+        BinaryOperator::Plus => "+",
+        BinaryOperator::Minus => "-",
+        BinaryOperator::Times => "*",
+        BinaryOperator::Over => "/",
+        BinaryOperator::Mod => "%",
+        BinaryOperator::In => "in",
+        BinaryOperator::PowerOf => "^",
+        BinaryOperator::Is => "is",
+        BinaryOperator::Cast => "as",
+        BinaryOperator::NullCoalesce => "??",
+    }
+}
+
+// Whether the name can be written as is, ignoring keywords.
+fn is_plain_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    let rest = |c: char| c.is_ascii_alphanumeric() || c == '_';
+    match chars.next() {
+        Some('_') => name.len() > 1 && chars.all(rest),
+        Some(c) if c.is_ascii_alphabetic() => chars.all(rest),
+        _ => false,
+    }
+}
+
+fn is_reserved_name(name: &str) -> bool {
+    KEYWORDS.contains(&name) || LITERALS.iter().any(|l| name.starts_with(l))
+}
+
+fn identifier(id: &Identifier) -> Doc {
+    let name = id.name.as_ref();
+    if !is_plain_name(name) {
+        text(format!("#\"{name}\""))
+    } else if is_reserved_name(name) {
+        text(format!("#{name}"))
+    } else {
+        text(name)
+    }
+}
+
+// Names that can not be written as identifiers are quoted rather than
+// escaped, since patterns only accept quoted keys.
+fn property_key_name(id: &Identifier) -> Doc {
+    if is_plain_name(&id.name) {
+        identifier(id)
+    } else {
+        text(format!("\"{}\"", id.name))
+    }
+}
+
+fn is_signed_number(literal: &Literal) -> bool {
+    match literal {
+        Literal::Number(n) | Literal::Float(n) => n.starts_with(['-', '+']),
+        _ => false,
+    }
+}
+
+fn literal(literal: &Literal) -> Doc {
+    text(literal.to_string())
+}
+
+fn match_abstraction<'e, 's>(lambda: &'e LambdaAbstraction<'s>) -> Option<&'e [MatchCase<'s>]> {
+    let LambdaAbstraction {
+        name: None,
+        arguments,
+        body,
+    } = lambda
+    else {
+        return None;
+    };
+    let [Pattern {
+        body: PatternBody::Identifier(parameter),
+        ..
+    }] = arguments.as_slice()
+    else {
+        return None;
+    };
+    let ExpressionBody::Match(MatchExpression { subject, cases }) = &body.body else {
+        return None;
+    };
+    let ExpressionBody::Identifier(subject) = &subject.body else {
+        return None;
+    };
+
+    (parameter.name == MATCH_ABSTRACTION_PARAMETER && subject == parameter).then_some(cases)
+}
+
+impl PrettyPrinter {
+    fn expression(&self, expression: &Expression, position: Position) -> Doc {
+        let open_ended = match &expression.body {
+            ExpressionBody::Abstraction(lambda) => match_abstraction(lambda).is_none(),
+            ExpressionBody::Let(_) | ExpressionBody::Unary(_) => true,
+            // Read as a sign applied to everything that follows by some parsers.
+            ExpressionBody::Literal(l) => is_signed_number(l),
+            _ => false,
+        };
+        let unwanted_in = matches!(
+            expression.body,
+            ExpressionBody::Binary(BinaryExpression {
+                operator: BinaryOperator::In,
+                ..
+            })
+        ) && !position.allow_in;
+
+        if Precedence::of(expression) < position.precedence
+            || (open_ended && !position.trailing)
+            || unwanted_in
+        {
+            parenthesized(self.expression_body(expression, Position::TOP))
+        } else {
+            self.expression_body(expression, position)
+        }
+    }
+
+    fn expression_body(&self, expression: &Expression, position: Position) -> Doc {
+        match &expression.body {
+            ExpressionBody::Literal(l) => literal(l),
+            ExpressionBody::Identifier(id) => identifier(id),
+            ExpressionBody::Array(items) => list("[", self.array_items(items), "]", true),
+            ExpressionBody::Object(properties) => {
+                list("{", self.object_properties(properties), "}", true)
+            }
+            ExpressionBody::ArrayComp(ArrayComprehension {
+                sources,
+                projection,
+            }) => self.comprehension("[", self.array_items(projection), sources, "]"),
+            ExpressionBody::ObjectComp(ObjectComprehension {
+                sources,
+                projection,
+            }) => self.comprehension("{", self.object_properties(projection), sources, "}"),
+            ExpressionBody::Call(CallExpression {
+                function,
+                arguments,
+            }) => concat([identifier(function), self.arguments(arguments)]),
+            ExpressionBody::Template(template) => self.template(template),
+            ExpressionBody::Binary(BinaryExpression {
+                operator,
+                left,
+                right,
+            }) => {
+                let precedence = Precedence::of_binary(*operator);
+                let (left_precedence, right_precedence) = match operator {
+                    BinaryOperator::Is => (Precedence::Cast, Precedence::Comparison),
+                    _ => (precedence, precedence.next()),
+                };
+                self.infix(
+                    self.expression(left, position.operand(left_precedence, false)),
+                    binary_operator(*operator),
+                    self.expression(right, position.operand(right_precedence, true)),
+                )
+            }
+            ExpressionBody::Logical(LogicalExpression {
+                operator,
+                left,
+                right,
+            }) => {
+                let (precedence, operator) = match operator {
+                    LogicalOperator::Or => (Precedence::Or, "||"),
+                    LogicalOperator::And => (Precedence::And, "&&"),
+                };
+                self.infix(
+                    self.expression(left, position.operand(precedence, false)),
+                    operator,
+                    self.expression(right, position.operand(precedence.next(), true)),
+                )
+            }
+            ExpressionBody::Pipe(PipeExpression { value, function }) => self.infix(
+                self.expression(value, position.operand(Precedence::Pipe, false)),
+                "|>",
+                self.expression(function, position.operand(Precedence::NullCoalesce, true)),
+            ),
+            ExpressionBody::Range(RangeExpression { start, end }) => concat([
+                self.expression(start, position.operand(Precedence::Additive, false)),
+                text(".."),
+                self.expression(end, position.operand(Precedence::Additive, true)),
+            ]),
+            ExpressionBody::Unary(UnaryExpression { operator, argument }) => {
+                let (operator, precedence) = match operator {
+                    UnaryOperator::Not => ("!", Precedence::Primary),
+                    UnaryOperator::Minus => ("-", Precedence::Path),
+                    UnaryOperator::Plus => ("+", Precedence::Path),
+                };
+                let argument_doc = self.expression(argument, position.operand(precedence, true));
+                // A sign followed by a digit is read as a signed number literal,
+                // which only keeps the meaning if the digits are the whole operand.
+                let argument_doc = match (&argument.body, leading_text(&argument_doc)) {
+                    (ExpressionBody::Literal(Literal::Number(_) | Literal::Float(_)), _) => {
+                        argument_doc
+                    }
+                    (_, Some(t))
+                        if operator != "!" && t.starts_with(|c: char| c.is_ascii_digit()) =>
+                    {
+                        parenthesized(argument_doc)
+                    }
+                    _ => argument_doc,
+                };
+                concat([text(operator), argument_doc])
+            }
+            ExpressionBody::Member(MemberExpression {
+                object,
+                property,
+                optional,
+            }) => {
+                let object = self.expression(object, position.operand(Precedence::Path, false));
+                let optional = if *optional { "?." } else { "" };
+                match &property.body {
+                    ExpressionBody::Literal(Literal::String(name))
+                        if is_plain_name(name) && !is_reserved_name(name) =>
+                    {
+                        let dot = if optional.is_empty() { "." } else { optional };
+                        concat([object, text(format!("{dot}{name}"))])
+                    }
+                    _ => concat([
+                        object,
+                        text(format!("{optional}[")),
+                        self.expression(property, Position::TOP),
+                        text("]"),
+                    ]),
+                }
+            }
+            ExpressionBody::Slice(SliceExpression { object, start, end }) => {
+                let mut docs = vec![
+                    self.expression(object, position.operand(Precedence::Path, false)),
+                    text("["),
+                ];
+                docs.extend(start.iter().map(|s| self.expression(s, Position::TOP)));
+                docs.push(text(":"));
+                docs.extend(end.iter().map(|e| self.expression(e, Position::TOP)));
+                docs.push(text("]"));
+                concat(docs)
+            }
+            ExpressionBody::Application(LambdaApplication { lambda, parameters }) => concat([
+                self.expression(lambda, position.operand(Precedence::Path, false)),
+                text("."),
+                self.arguments(parameters),
+            ]),
+            ExpressionBody::Abstraction(lambda) => self.abstraction(lambda, position),
+            ExpressionBody::Match(MatchExpression { subject, cases }) => concat([
+                text("match "),
+                parenthesized(self.expression(subject, Position::TOP)),
+                text(" "),
+                self.match_cases(cases),
+            ]),
+            ExpressionBody::Condition(IfElseExpression {
+                condition,
+                true_branch,
+                false_branch,
+            }) => {
+                let mut docs = vec![
+                    text("if "),
+                    parenthesized(self.expression(condition, Position::TOP)),
+                    text(" "),
+                    self.block(true_branch),
+                ];
+                if let Some(false_branch) = false_branch {
+                    docs.push(text(" else "));
+                    docs.push(self.block(false_branch));
+                }
+                group(concat(docs))
+            }
+            ExpressionBody::TryCatch(TryCatchExpression {
+                body,
+                pattern,
+                fallback,
+            }) => group(concat([
+                text("try "),
+                self.block(body),
+                text(" catch "),
+                parenthesized(self.pattern(pattern)),
+                text(" "),
+                self.block(fallback),
+            ])),
+            ExpressionBody::Let(LetExpression { assignments, body }) => {
+                let value = Position {
+                    precedence: Precedence::Expression,
+                    trailing: false,
+                    allow_in: false,
+                };
+                let mut bindings = vec![];
+                for (i, assignment) in assignments.assignments.iter().enumerate() {
+                    if i > 0 {
+                        bindings.push(text(";"));
+                        bindings.push(Doc::Line);
+                    }
+                    bindings.push(self.assignment(assignment, value));
+                }
+
+                group(concat([
+                    text("let "),
+                    nest(concat(bindings)),
+                    Doc::Line,
+                    text("in "),
+                    self.expression(body, position.operand(Precedence::Expression, true)),
+                ]))
+            }
+        }
+    }
+
+    fn infix(&self, left: Doc, operator: &str, right: Doc) -> Doc {
+        group(concat([
+            left,
+            nest(concat([Doc::Line, text(format!("{operator} ")), right])),
+        ]))
+    }
+
+    // Braced branches of `if` and `try`, broken together with the group of
+    // the whole expression.
+    fn block(&self, expression: &Expression) -> Doc {
+        concat([
+            text("{"),
+            nest(concat([
+                Doc::Line,
+                self.expression(expression, Position::TOP),
+            ])),
+            Doc::Line,
+            text("}"),
+        ])
+    }
+
+    fn arguments(&self, arguments: &[Expression]) -> Doc {
+        let arguments = arguments
+            .iter()
+            .map(|a| self.expression(a, Position::TOP))
+            .collect();
+        list("(", arguments, ")", false)
+    }
+
+    fn array_items(&self, items: &[ArrayItem]) -> Vec<Doc> {
+        items
+            .iter()
+            .map(|item| match item {
+                ArrayItem::Single(e) => self.expression(e, Position::TOP),
+                ArrayItem::Spread(e) => concat([text("..."), self.expression(e, Position::TOP)]),
+            })
+            .collect()
+    }
+
+    fn object_properties(&self, properties: &[ObjectProperty]) -> Vec<Doc> {
+        properties
+            .iter()
+            .map(|property| match property {
+                ObjectProperty::Single(id) => identifier(id),
+                ObjectProperty::Property(Property { key, value }) => concat([
+                    self.property_key(key),
+                    text(": "),
+                    self.expression(value, Position::TOP),
+                ]),
+                ObjectProperty::Spread(e) => {
+                    concat([text("..."), self.expression(e, Position::TOP)])
+                }
+            })
+            .collect()
+    }
+
+    fn property_key(&self, key: &PropertyKey) -> Doc {
+        match key {
+            PropertyKey::Identifier(id) => property_key_name(id),
+            PropertyKey::Expression(e) => {
+                concat([text("["), self.expression(e, Position::TOP), text("]")])
+            }
+        }
+    }
+
+    fn comprehension(
+        &self,
+        open: &str,
+        projection: Vec<Doc>,
+        sources: &[ComprehensionSource],
+        close: &str,
+    ) -> Doc {
+        let mut inner = vec![Doc::SoftLine];
+        for (i, item) in projection.into_iter().enumerate() {
+            if i > 0 {
+                inner.push(text(","));
+                inner.push(Doc::Line);
+            }
+            inner.push(item);
+        }
+
+        for ComprehensionSource {
+            collection,
+            pattern,
+            strong_pattern,
+            predicate,
+        } in sources
+        {
+            let mut pattern = self.pattern(pattern);
+            // Keeps the pattern from being read as the `match` keyword.
+            if leading_text(&pattern).is_some_and(|t| t.starts_with("match")) {
+                pattern = parenthesized(pattern);
+            }
+
+            inner.push(Doc::Line);
+            inner.push(text(if *strong_pattern {
+                "for "
+            } else {
+                "for match "
+            }));
+            inner.push(pattern);
+            inner.push(text(" in "));
+            inner.push(self.expression(collection, Position::TOP));
+            if let Some(predicate) = predicate {
+                inner.push(Doc::Line);
+                inner.push(text("if "));
+                inner.push(self.expression(predicate, Position::TOP));
+            }
+        }
+
+        group(concat([
+            text(open),
+            nest(concat(inner)),
+            Doc::SoftLine,
+            text(close),
+        ]))
+    }
+
+    fn template(&self, StringTemplate { parts, suffix }: &StringTemplate) -> Doc {
+        let mut docs = vec![text("`")];
+        for part in parts {
+            docs.push(text(format!("{}${{", part.fixed_start)));
+            // Interpolations are kept on the line of the surrounding text.
+            let dynamic = self.expression(&part.dynamic_end, Position::TOP);
+            docs.push(text(PrettyPrinter::single_line().render(&dynamic)));
+            docs.push(text("}"));
+        }
+        docs.push(text(format!("{suffix}`")));
+        concat(docs)
+    }
+
+    fn abstraction(&self, lambda: &LambdaAbstraction, position: Position) -> Doc {
+        if let Some(cases) = match_abstraction(lambda) {
+            return concat([text("fn match "), self.match_cases(cases)]);
+        }
+
+        let LambdaAbstraction {
+            name,
+            arguments,
+            body,
+        } = lambda;
+        let arguments = arguments.iter().map(|a| self.pattern(a)).collect();
+
+        group(concat([
+            text("fn "),
+            match name {
+                Some(name) => identifier(name),
+                None => text(""),
+            },
+            list("(", arguments, ")", false),
+            text(" =>"),
+            nest(concat([
+                Doc::Line,
+                self.expression(body, position.operand(Precedence::Expression, true)),
+            ])),
+        ]))
+    }
+
+    fn match_cases(&self, cases: &[MatchCase]) -> Doc {
+        let cases = cases
+            .iter()
+            .map(
+                |MatchCase {
+                     pattern,
+                     guard,
+                     body,
+                 }| {
+                    let mut pattern = self.pattern(pattern);
+                    // A leading parenthesis would be taken to enclose the whole
+                    // pattern.
+                    if leading_text(&pattern).is_some_and(|t| t.starts_with('(')) {
+                        pattern = parenthesized(pattern);
+                    }
+
+                    let mut docs = vec![pattern];
+                    if let Some(guard) = guard {
+                        docs.push(text(" if "));
+                        docs.push(self.expression(guard, Position::TOP));
+                    }
+                    docs.push(text(" =>"));
+                    docs.push(nest(concat([
+                        Doc::Line,
+                        self.expression(body, Position::TOP),
+                    ])));
+                    group(concat(docs))
+                },
+            )
+            .collect::<Vec<_>>();
+
+        if cases.is_empty() {
+            return text("{}");
+        }
+
+        let mut inner = vec![];
+        for (i, case) in cases.into_iter().enumerate() {
+            if i > 0 {
+                inner.push(text(","));
+            }
+            inner.push(Doc::Line);
+            inner.push(case);
+        }
+        inner.push(Doc::IfBroken(","));
+
+        group(concat([
+            text("{"),
+            nest(concat(inner)),
+            Doc::Line,
+            text("}"),
+        ]))
+    }
+
+    fn assignment(&self, assignment: &Assignment, value: Position) -> Doc {
+        group(concat([
+            self.pattern(&assignment.pattern),
+            text(" ="),
+            nest(concat([
+                Doc::Line,
+                self.expression(&assignment.expression, value),
+            ])),
+        ]))
+    }
+
+    fn pattern(&self, pattern: &Pattern) -> Doc {
+        match &pattern.body {
+            PatternBody::Discard => text("_"),
+            PatternBody::Literal(l) => literal(l),
+            PatternBody::Identifier(id) => identifier(id),
+            PatternBody::TypedDiscard(t) => text(format!("_ is {t}")),
+            PatternBody::TypedIdentifier(id, t) => {
+                concat([identifier(id), text(format!(" is {t}"))])
+            }
+            PatternBody::Capture(id, inner) => {
+                let inner_doc = self.pattern(inner);
+                let inner_doc = match inner.body {
+                    PatternBody::Literal(_)
+                    | PatternBody::Array(..)
+                    | PatternBody::Object(..)
+                    | PatternBody::PinnedExpression(_) => inner_doc,
+                    _ => parenthesized(inner_doc),
+                };
+                concat([identifier(id), text(" @ "), inner_doc])
+            }
+            PatternBody::PinnedExpression(expression) => match &expression.body {
+                ExpressionBody::Identifier(id) => concat([text("^"), identifier(id)]),
+                _ => concat([
+                    text("^"),
+                    parenthesized(self.expression(expression, Position::TOP)),
+                ]),
+            },
+            PatternBody::Object(properties, rest) => {
+                let mut items: Vec<Doc> = properties
+                    .iter()
+                    .map(|property| self.object_property_pattern(property))
+                    .collect();
+                items.extend(self.rest(rest));
+                list("{", items, "}", false)
+            }
+            PatternBody::Array(items, rest, suffix) => {
+                let mut docs: Vec<Doc> = items
+                    .iter()
+                    .map(|ArrayPatternItem::Pattern(p)| self.pattern(p))
+                    .collect();
+                if let Some(rest) = self.rest(rest) {
+                    docs.push(rest);
+                    docs.extend(
+                        suffix
+                            .iter()
+                            .map(|ArrayPatternItem::Pattern(p)| self.pattern(p)),
+                    );
+                }
+                list("[", docs, "]", false)
+            }
+            PatternBody::Alternative(alternatives) => {
+                let mut docs = vec![];
+                for (i, alternative) in alternatives.iter().enumerate() {
+                    if i > 0 {
+                        docs.push(Doc::Line);
+                        docs.push(text("| "));
+                    }
+                    docs.push(self.concatenated_pattern(alternative));
+                }
+                group(concat(docs))
+            }
+            PatternBody::Range(range) => {
+                let RangePattern {
+                    start,
+                    end,
+                    inclusive,
+                } = range.as_ref();
+                let operator = if *inclusive { "..=" } else { ".." };
+                text(format!("{start}{operator}{end}"))
+            }
+            PatternBody::Relational(operator, bound) => text(format!("{operator} {bound}")),
+            PatternBody::Regex(regex) => text(format!("/{}/", regex.source)),
+            PatternBody::String(StringPattern::Prefix(prefix, rest)) => concat([
+                text(format!("\"{prefix}\" ++ ")),
+                self.concatenated_pattern(rest),
+            ]),
+            PatternBody::String(StringPattern::Suffix(rest, suffix)) => {
+                let rest_doc = self.concatenated_pattern(rest);
+                let rest_doc = match rest.body {
+                    PatternBody::String(StringPattern::Suffix(..)) => rest_doc,
+                    // A leading string literal would be read as a prefix.
+                    PatternBody::String(StringPattern::Prefix(..))
+                    | PatternBody::Literal(Literal::String(_)) => parenthesized(rest_doc),
+                    _ => rest_doc,
+                };
+                concat([rest_doc, text(format!(" ++ \"{suffix}\""))])
+            }
+        }
+    }
+
+    // Prints a pattern within an alternative or a string pattern, which can
+    // neither contain alternatives nor captures without parentheses.
+    fn concatenated_pattern(&self, pattern: &Pattern) -> Doc {
+        match pattern.body {
+            PatternBody::Alternative(_) | PatternBody::Capture(..) => {
+                parenthesized(self.pattern(pattern))
+            }
+            _ => self.pattern(pattern),
+        }
+    }
+
+    fn rest(&self, rest: &Rest) -> Option<Doc> {
+        match rest {
+            Rest::Exact => None,
+            Rest::Discard => Some(text("...")),
+            Rest::Collect(pattern) => Some(concat([text("..."), self.pattern(pattern)])),
+        }
+    }
+
+    fn object_property_pattern(&self, property: &ObjectPropertyPattern) -> Doc {
+        match property {
+            ObjectPropertyPattern::Single(id) => identifier(id),
+            ObjectPropertyPattern::Optional(id) => concat([identifier(id), text("?")]),
+            ObjectPropertyPattern::Default(id, default) => concat([
+                identifier(id),
+                text(" = "),
+                self.expression(default, Position::TOP),
+            ]),
+            ObjectPropertyPattern::Match(PropertyPattern {
+                key,
+                value,
+                default,
+            }) => {
+                let mut docs = vec![self.property_key(key), text(": "), self.pattern(value)];
+                if let Some(default) = default {
+                    docs.push(text(" = "));
+                    docs.push(self.expression(default, Position::TOP));
+                }
+                concat(docs)
+            }
+        }
+    }
+}
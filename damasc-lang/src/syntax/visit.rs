@@ -0,0 +1,962 @@
+//! Traversals of the syntax tree.
+//!
+//! Each trait method defaults to the free function of the same name, which
+//! walks the children of the node in evaluation order. Implementors override
+//! the methods for the nodes they care about and call the free function to
+//! continue the walk into the children.
+//!
+//! Identifiers are reported either as `identifier` if they are read by an
+//! expression or as `binding` if they are bound by a pattern or name a
+//! lambda. Property keys and the names of called functions are neither.
+
+use crate::identifier::Identifier;
+use crate::literal::Literal;
+
+use super::assignment::Assignment;
+use super::expression::{
+    ArrayComprehension, ArrayItem, BinaryExpression, CallExpression, ComprehensionSource,
+    Expression, ExpressionBody, IfElseExpression, LambdaAbstraction, LambdaApplication,
+    LetExpression, LogicalExpression, MatchCase, MatchExpression, MemberExpression,
+    ObjectComprehension, ObjectProperty, PipeExpression, Property, PropertyKey, RangeExpression,
+    SliceExpression, StringTemplate, StringTemplatePart, TryCatchExpression, UnaryExpression,
+};
+use super::pattern::{
+    ArrayPatternItem, ObjectPropertyPattern, Pattern, PatternBody, PropertyPattern, RangePattern,
+    RegexPattern, Rest, StringPattern,
+};
+
+pub trait Visitor<'a> {
+    fn visit_expression(&mut self, expression: &'a Expression<'a>) {
+        visit_expression(self, expression)
+    }
+
+    fn visit_pattern(&mut self, pattern: &'a Pattern<'a>) {
+        visit_pattern(self, pattern)
+    }
+
+    fn visit_assignment(&mut self, assignment: &'a Assignment<'a, 'a>) {
+        visit_assignment(self, assignment)
+    }
+
+    fn visit_match_case(&mut self, case: &'a MatchCase<'a>) {
+        visit_match_case(self, case)
+    }
+
+    fn visit_comprehension_source(&mut self, source: &'a ComprehensionSource<'a>) {
+        visit_comprehension_source(self, source)
+    }
+
+    fn visit_identifier(&mut self, _identifier: &'a Identifier<'a>) {}
+
+    fn visit_binding(&mut self, _identifier: &'a Identifier<'a>) {}
+
+    fn visit_literal(&mut self, _literal: &'a Literal<'a>) {}
+}
+
+pub fn visit_expression<'a, V: Visitor<'a> + ?Sized>(v: &mut V, expression: &'a Expression<'a>) {
+    match &expression.body {
+        ExpressionBody::Array(items) => visit_array_items(v, items),
+        ExpressionBody::Binary(BinaryExpression { left, right, .. })
+        | ExpressionBody::Logical(LogicalExpression { left, right, .. }) => {
+            v.visit_expression(left);
+            v.visit_expression(right);
+        }
+        ExpressionBody::Identifier(id) => v.visit_identifier(id),
+        ExpressionBody::Literal(l) => v.visit_literal(l),
+        ExpressionBody::Member(MemberExpression {
+            object, property, ..
+        }) => {
+            v.visit_expression(object);
+            v.visit_expression(property);
+        }
+        ExpressionBody::Slice(SliceExpression { object, start, end }) => {
+            v.visit_expression(object);
+            if let Some(start) = start {
+                v.visit_expression(start);
+            }
+            if let Some(end) = end {
+                v.visit_expression(end);
+            }
+        }
+        ExpressionBody::Range(RangeExpression { start, end }) => {
+            v.visit_expression(start);
+            v.visit_expression(end);
+        }
+        ExpressionBody::Object(props) => visit_object_properties(v, props),
+        ExpressionBody::Unary(UnaryExpression { argument, .. }) => v.visit_expression(argument),
+        ExpressionBody::Call(CallExpression { arguments, .. }) => {
+            for argument in arguments {
+                v.visit_expression(argument);
+            }
+        }
+        ExpressionBody::Template(StringTemplate { parts, .. }) => {
+            for part in parts {
+                v.visit_expression(&part.dynamic_end);
+            }
+        }
+        ExpressionBody::Abstraction(LambdaAbstraction {
+            name,
+            arguments,
+            body,
+        }) => {
+            if let Some(name) = name {
+                v.visit_binding(name);
+            }
+            for argument in arguments {
+                v.visit_pattern(argument);
+            }
+            v.visit_expression(body);
+        }
+        ExpressionBody::Application(LambdaApplication { lambda, parameters }) => {
+            v.visit_expression(lambda);
+            for parameter in parameters {
+                v.visit_expression(parameter);
+            }
+        }
+        ExpressionBody::ArrayComp(ArrayComprehension {
+            sources,
+            projection,
+        }) => {
+            for source in sources {
+                v.visit_comprehension_source(source);
+            }
+            visit_array_items(v, projection);
+        }
+        ExpressionBody::ObjectComp(ObjectComprehension {
+            sources,
+            projection,
+        }) => {
+            for source in sources {
+                v.visit_comprehension_source(source);
+            }
+            visit_object_properties(v, projection);
+        }
+        ExpressionBody::Condition(IfElseExpression {
+            condition,
+            true_branch,
+            false_branch,
+        }) => {
+            v.visit_expression(condition);
+            v.visit_expression(true_branch);
+            if let Some(false_branch) = false_branch {
+                v.visit_expression(false_branch);
+            }
+        }
+        ExpressionBody::Match(MatchExpression { subject, cases }) => {
+            v.visit_expression(subject);
+            for case in cases {
+                v.visit_match_case(case);
+            }
+        }
+        ExpressionBody::TryCatch(TryCatchExpression {
+            body,
+            pattern,
+            fallback,
+        }) => {
+            v.visit_expression(body);
+            v.visit_pattern(pattern);
+            v.visit_expression(fallback);
+        }
+        ExpressionBody::Let(LetExpression { assignments, body }) => {
+            for assignment in &assignments.assignments {
+                v.visit_assignment(assignment);
+            }
+            v.visit_expression(body);
+        }
+        ExpressionBody::Pipe(PipeExpression { value, function }) => {
+            v.visit_expression(value);
+            v.visit_expression(function);
+        }
+    }
+}
+
+fn visit_array_items<'a, V: Visitor<'a> + ?Sized>(v: &mut V, items: &'a [ArrayItem<'a>]) {
+    for item in items {
+        match item {
+            ArrayItem::Single(e) | ArrayItem::Spread(e) => v.visit_expression(e),
+        }
+    }
+}
+
+fn visit_object_properties<'a, V: Visitor<'a> + ?Sized>(
+    v: &mut V,
+    props: &'a [ObjectProperty<'a>],
+) {
+    for prop in props {
+        match prop {
+            ObjectProperty::Single(id) => v.visit_identifier(id),
+            ObjectProperty::Property(Property { key, value }) => {
+                if let PropertyKey::Expression(key) = key {
+                    v.visit_expression(key);
+                }
+                v.visit_expression(value);
+            }
+            ObjectProperty::Spread(e) => v.visit_expression(e),
+        }
+    }
+}
+
+pub fn visit_pattern<'a, V: Visitor<'a> + ?Sized>(v: &mut V, pattern: &'a Pattern<'a>) {
+    match &pattern.body {
+        PatternBody::Discard | PatternBody::TypedDiscard(_) => {}
+        PatternBody::Capture(id, inner) => {
+            v.visit_binding(id);
+            v.visit_pattern(inner);
+        }
+        PatternBody::Identifier(id) | PatternBody::TypedIdentifier(id, _) => v.visit_binding(id),
+        PatternBody::PinnedExpression(e) => v.visit_expression(e),
+        PatternBody::Literal(l) | PatternBody::Relational(_, l) => v.visit_literal(l),
+        PatternBody::Object(props, rest) => {
+            for prop in props {
+                match prop {
+                    ObjectPropertyPattern::Single(id) | ObjectPropertyPattern::Optional(id) => {
+                        v.visit_binding(id)
+                    }
+                    ObjectPropertyPattern::Default(id, default) => {
+                        v.visit_binding(id);
+                        v.visit_expression(default);
+                    }
+                    ObjectPropertyPattern::Match(PropertyPattern {
+                        key,
+                        value,
+                        default,
+                    }) => {
+                        if let PropertyKey::Expression(key) = key {
+                            v.visit_expression(key);
+                        }
+                        v.visit_pattern(value);
+                        if let Some(default) = default {
+                            v.visit_expression(default);
+                        }
+                    }
+                }
+            }
+            if let Rest::Collect(rest) = rest {
+                v.visit_pattern(rest);
+            }
+        }
+        PatternBody::Array(items, rest, suffix) => {
+            for ArrayPatternItem::Pattern(item) in items {
+                v.visit_pattern(item);
+            }
+            if let Rest::Collect(rest) = rest {
+                v.visit_pattern(rest);
+            }
+            for ArrayPatternItem::Pattern(item) in suffix.iter() {
+                v.visit_pattern(item);
+            }
+        }
+        PatternBody::Alternative(alternatives) => {
+            for alternative in alternatives {
+                v.visit_pattern(alternative);
+            }
+        }
+        PatternBody::Range(range) => {
+            v.visit_literal(&range.start);
+            v.visit_literal(&range.end);
+        }
+        PatternBody::Regex(RegexPattern { captures, .. }) => {
+            for capture in captures {
+                v.visit_binding(capture);
+            }
+        }
+        PatternBody::String(StringPattern::Prefix(_, rest))
+        | PatternBody::String(StringPattern::Suffix(rest, _)) => v.visit_pattern(rest),
+    }
+}
+
+pub fn visit_assignment<'a, V: Visitor<'a> + ?Sized>(
+    v: &mut V,
+    assignment: &'a Assignment<'a, 'a>,
+) {
+    v.visit_pattern(&assignment.pattern);
+    v.visit_expression(&assignment.expression);
+}
+
+pub fn visit_match_case<'a, V: Visitor<'a> + ?Sized>(v: &mut V, case: &'a MatchCase<'a>) {
+    v.visit_pattern(&case.pattern);
+    if let Some(guard) = &case.guard {
+        v.visit_expression(guard);
+    }
+    v.visit_expression(&case.body);
+}
+
+pub fn visit_comprehension_source<'a, V: Visitor<'a> + ?Sized>(
+    v: &mut V,
+    source: &'a ComprehensionSource<'a>,
+) {
+    v.visit_expression(&source.collection);
+    v.visit_pattern(&source.pattern);
+    if let Some(predicate) = &source.predicate {
+        v.visit_expression(predicate);
+    }
+}
+
+pub trait VisitorMut<'s> {
+    fn visit_expression_mut(&mut self, expression: &mut Expression<'s>) {
+        visit_expression_mut(self, expression)
+    }
+
+    fn visit_pattern_mut(&mut self, pattern: &mut Pattern<'s>) {
+        visit_pattern_mut(self, pattern)
+    }
+
+    fn visit_assignment_mut(&mut self, assignment: &mut Assignment<'s, 's>) {
+        visit_assignment_mut(self, assignment)
+    }
+
+    fn visit_match_case_mut(&mut self, case: &mut MatchCase<'s>) {
+        visit_match_case_mut(self, case)
+    }
+
+    fn visit_comprehension_source_mut(&mut self, source: &mut ComprehensionSource<'s>) {
+        visit_comprehension_source_mut(self, source)
+    }
+
+    fn visit_identifier_mut(&mut self, _identifier: &mut Identifier<'s>) {}
+
+    fn visit_binding_mut(&mut self, _identifier: &mut Identifier<'s>) {}
+
+    fn visit_literal_mut(&mut self, _literal: &mut Literal<'s>) {}
+}
+
+pub fn visit_expression_mut<'s, V: VisitorMut<'s> + ?Sized>(
+    v: &mut V,
+    expression: &mut Expression<'s>,
+) {
+    match &mut expression.body {
+        ExpressionBody::Array(items) => visit_array_items_mut(v, items),
+        ExpressionBody::Binary(BinaryExpression { left, right, .. })
+        | ExpressionBody::Logical(LogicalExpression { left, right, .. }) => {
+            v.visit_expression_mut(left);
+            v.visit_expression_mut(right);
+        }
+        ExpressionBody::Identifier(id) => v.visit_identifier_mut(id),
+        ExpressionBody::Literal(l) => v.visit_literal_mut(l),
+        ExpressionBody::Member(MemberExpression {
+            object, property, ..
+        }) => {
+            v.visit_expression_mut(object);
+            v.visit_expression_mut(property);
+        }
+        ExpressionBody::Slice(SliceExpression { object, start, end }) => {
+            v.visit_expression_mut(object);
+            if let Some(start) = start {
+                v.visit_expression_mut(start);
+            }
+            if let Some(end) = end {
+                v.visit_expression_mut(end);
+            }
+        }
+        ExpressionBody::Range(RangeExpression { start, end }) => {
+            v.visit_expression_mut(start);
+            v.visit_expression_mut(end);
+        }
+        ExpressionBody::Object(props) => visit_object_properties_mut(v, props),
+        ExpressionBody::Unary(UnaryExpression { argument, .. }) => v.visit_expression_mut(argument),
+        ExpressionBody::Call(CallExpression { arguments, .. }) => {
+            for argument in arguments {
+                v.visit_expression_mut(argument);
+            }
+        }
+        ExpressionBody::Template(StringTemplate { parts, .. }) => {
+            for part in parts {
+                v.visit_expression_mut(&mut part.dynamic_end);
+            }
+        }
+        ExpressionBody::Abstraction(LambdaAbstraction {
+            name,
+            arguments,
+            body,
+        }) => {
+            if let Some(name) = name {
+                v.visit_binding_mut(name);
+            }
+            for argument in arguments {
+                v.visit_pattern_mut(argument);
+            }
+            v.visit_expression_mut(body);
+        }
+        ExpressionBody::Application(LambdaApplication { lambda, parameters }) => {
+            v.visit_expression_mut(lambda);
+            for parameter in parameters {
+                v.visit_expression_mut(parameter);
+            }
+        }
+        ExpressionBody::ArrayComp(ArrayComprehension {
+            sources,
+            projection,
+        }) => {
+            for source in sources {
+                v.visit_comprehension_source_mut(source);
+            }
+            visit_array_items_mut(v, projection);
+        }
+        ExpressionBody::ObjectComp(ObjectComprehension {
+            sources,
+            projection,
+        }) => {
+            for source in sources {
+                v.visit_comprehension_source_mut(source);
+            }
+            visit_object_properties_mut(v, projection);
+        }
+        ExpressionBody::Condition(IfElseExpression {
+            condition,
+            true_branch,
+            false_branch,
+        }) => {
+            v.visit_expression_mut(condition);
+            v.visit_expression_mut(true_branch);
+            if let Some(false_branch) = false_branch {
+                v.visit_expression_mut(false_branch);
+            }
+        }
+        ExpressionBody::Match(MatchExpression { subject, cases }) => {
+            v.visit_expression_mut(subject);
+            for case in cases {
+                v.visit_match_case_mut(case);
+            }
+        }
+        ExpressionBody::TryCatch(TryCatchExpression {
+            body,
+            pattern,
+            fallback,
+        }) => {
+            v.visit_expression_mut(body);
+            v.visit_pattern_mut(pattern);
+            v.visit_expression_mut(fallback);
+        }
+        ExpressionBody::Let(LetExpression { assignments, body }) => {
+            for assignment in &mut assignments.assignments {
+                v.visit_assignment_mut(assignment);
+            }
+            v.visit_expression_mut(body);
+        }
+        ExpressionBody::Pipe(PipeExpression { value, function }) => {
+            v.visit_expression_mut(value);
+            v.visit_expression_mut(function);
+        }
+    }
+}
+
+fn visit_array_items_mut<'s, V: VisitorMut<'s> + ?Sized>(v: &mut V, items: &mut [ArrayItem<'s>]) {
+    for item in items {
+        match item {
+            ArrayItem::Single(e) | ArrayItem::Spread(e) => v.visit_expression_mut(e),
+        }
+    }
+}
+
+fn visit_object_properties_mut<'s, V: VisitorMut<'s> + ?Sized>(
+    v: &mut V,
+    props: &mut [ObjectProperty<'s>],
+) {
+    for prop in props {
+        match prop {
+            ObjectProperty::Single(id) => v.visit_identifier_mut(id),
+            ObjectProperty::Property(Property { key, value }) => {
+                if let PropertyKey::Expression(key) = key {
+                    v.visit_expression_mut(key);
+                }
+                v.visit_expression_mut(value);
+            }
+            ObjectProperty::Spread(e) => v.visit_expression_mut(e),
+        }
+    }
+}
+
+pub fn visit_pattern_mut<'s, V: VisitorMut<'s> + ?Sized>(v: &mut V, pattern: &mut Pattern<'s>) {
+    match &mut pattern.body {
+        PatternBody::Discard | PatternBody::TypedDiscard(_) => {}
+        PatternBody::Capture(id, inner) => {
+            v.visit_binding_mut(id);
+            v.visit_pattern_mut(inner);
+        }
+        PatternBody::Identifier(id) | PatternBody::TypedIdentifier(id, _) => {
+            v.visit_binding_mut(id)
+        }
+        PatternBody::PinnedExpression(e) => v.visit_expression_mut(e),
+        PatternBody::Literal(l) | PatternBody::Relational(_, l) => v.visit_literal_mut(l),
+        PatternBody::Object(props, rest) => {
+            for prop in props {
+                match prop {
+                    ObjectPropertyPattern::Single(id) | ObjectPropertyPattern::Optional(id) => {
+                        v.visit_binding_mut(id)
+                    }
+                    ObjectPropertyPattern::Default(id, default) => {
+                        v.visit_binding_mut(id);
+                        v.visit_expression_mut(default);
+                    }
+                    ObjectPropertyPattern::Match(PropertyPattern {
+                        key,
+                        value,
+                        default,
+                    }) => {
+                        if let PropertyKey::Expression(key) = key {
+                            v.visit_expression_mut(key);
+                        }
+                        v.visit_pattern_mut(value);
+                        if let Some(default) = default {
+                            v.visit_expression_mut(default);
+                        }
+                    }
+                }
+            }
+            if let Rest::Collect(rest) = rest {
+                v.visit_pattern_mut(rest);
+            }
+        }
+        PatternBody::Array(items, rest, suffix) => {
+            for ArrayPatternItem::Pattern(item) in items {
+                v.visit_pattern_mut(item);
+            }
+            if let Rest::Collect(rest) = rest {
+                v.visit_pattern_mut(rest);
+            }
+            for ArrayPatternItem::Pattern(item) in suffix.iter_mut() {
+                v.visit_pattern_mut(item);
+            }
+        }
+        PatternBody::Alternative(alternatives) => {
+            for alternative in alternatives {
+                v.visit_pattern_mut(alternative);
+            }
+        }
+        PatternBody::Range(range) => {
+            v.visit_literal_mut(&mut range.start);
+            v.visit_literal_mut(&mut range.end);
+        }
+        PatternBody::Regex(RegexPattern { captures, .. }) => {
+            for capture in captures {
+                v.visit_binding_mut(capture);
+            }
+        }
+        PatternBody::String(StringPattern::Prefix(_, rest))
+        | PatternBody::String(StringPattern::Suffix(rest, _)) => v.visit_pattern_mut(rest),
+    }
+}
+
+pub fn visit_assignment_mut<'s, V: VisitorMut<'s> + ?Sized>(
+    v: &mut V,
+    assignment: &mut Assignment<'s, 's>,
+) {
+    v.visit_pattern_mut(&mut assignment.pattern);
+    v.visit_expression_mut(&mut assignment.expression);
+}
+
+pub fn visit_match_case_mut<'s, V: VisitorMut<'s> + ?Sized>(v: &mut V, case: &mut MatchCase<'s>) {
+    v.visit_pattern_mut(&mut case.pattern);
+    if let Some(guard) = &mut case.guard {
+        v.visit_expression_mut(guard);
+    }
+    v.visit_expression_mut(&mut case.body);
+}
+
+pub fn visit_comprehension_source_mut<'s, V: VisitorMut<'s> + ?Sized>(
+    v: &mut V,
+    source: &mut ComprehensionSource<'s>,
+) {
+    v.visit_expression_mut(&mut source.collection);
+    v.visit_pattern_mut(&mut source.pattern);
+    if let Some(predicate) = &mut source.predicate {
+        v.visit_expression_mut(predicate);
+    }
+}
+
+/// Rebuilds the syntax tree bottom up. Locations are kept as they are.
+pub trait Fold<'s> {
+    fn fold_expression(&mut self, expression: Expression<'s>) -> Expression<'s> {
+        fold_expression(self, expression)
+    }
+
+    fn fold_pattern(&mut self, pattern: Pattern<'s>) -> Pattern<'s> {
+        fold_pattern(self, pattern)
+    }
+
+    fn fold_assignment(&mut self, assignment: Assignment<'s, 's>) -> Assignment<'s, 's> {
+        fold_assignment(self, assignment)
+    }
+
+    fn fold_match_case(&mut self, case: MatchCase<'s>) -> MatchCase<'s> {
+        fold_match_case(self, case)
+    }
+
+    fn fold_comprehension_source(
+        &mut self,
+        source: ComprehensionSource<'s>,
+    ) -> ComprehensionSource<'s> {
+        fold_comprehension_source(self, source)
+    }
+
+    fn fold_identifier(&mut self, identifier: Identifier<'s>) -> Identifier<'s> {
+        identifier
+    }
+
+    fn fold_binding(&mut self, identifier: Identifier<'s>) -> Identifier<'s> {
+        identifier
+    }
+
+    fn fold_literal(&mut self, literal: Literal<'s>) -> Literal<'s> {
+        literal
+    }
+}
+
+pub fn fold_expression<'s, F: Fold<'s> + ?Sized>(
+    f: &mut F,
+    expression: Expression<'s>,
+) -> Expression<'s> {
+    let body = match expression.body {
+        ExpressionBody::Array(items) => ExpressionBody::Array(fold_array_items(f, items)),
+        ExpressionBody::Binary(BinaryExpression {
+            operator,
+            left,
+            right,
+        }) => ExpressionBody::Binary(BinaryExpression {
+            operator,
+            left: fold_boxed(f, left),
+            right: fold_boxed(f, right),
+        }),
+        ExpressionBody::Identifier(id) => ExpressionBody::Identifier(f.fold_identifier(id)),
+        ExpressionBody::Literal(l) => ExpressionBody::Literal(f.fold_literal(l)),
+        ExpressionBody::Logical(LogicalExpression {
+            operator,
+            left,
+            right,
+        }) => ExpressionBody::Logical(LogicalExpression {
+            operator,
+            left: fold_boxed(f, left),
+            right: fold_boxed(f, right),
+        }),
+        ExpressionBody::Member(MemberExpression {
+            object,
+            property,
+            optional,
+        }) => ExpressionBody::Member(MemberExpression {
+            object: fold_boxed(f, object),
+            property: fold_boxed(f, property),
+            optional,
+        }),
+        ExpressionBody::Slice(SliceExpression { object, start, end }) => {
+            ExpressionBody::Slice(SliceExpression {
+                object: fold_boxed(f, object),
+                start: start.map(|s| fold_boxed(f, s)),
+                end: end.map(|e| fold_boxed(f, e)),
+            })
+        }
+        ExpressionBody::Range(RangeExpression { start, end }) => {
+            ExpressionBody::Range(RangeExpression {
+                start: fold_boxed(f, start),
+                end: fold_boxed(f, end),
+            })
+        }
+        ExpressionBody::Object(props) => ExpressionBody::Object(fold_object_properties(f, props)),
+        ExpressionBody::Unary(UnaryExpression { operator, argument }) => {
+            ExpressionBody::Unary(UnaryExpression {
+                operator,
+                argument: fold_boxed(f, argument),
+            })
+        }
+        ExpressionBody::Call(CallExpression {
+            function,
+            arguments,
+        }) => ExpressionBody::Call(CallExpression {
+            function,
+            arguments: arguments
+                .into_iter()
+                .map(|a| f.fold_expression(a))
+                .collect(),
+        }),
+        ExpressionBody::Template(StringTemplate { parts, suffix }) => {
+            ExpressionBody::Template(StringTemplate {
+                parts: parts
+                    .into_iter()
+                    .map(|p| StringTemplatePart {
+                        fixed_start: p.fixed_start,
+                        dynamic_end: fold_boxed(f, p.dynamic_end),
+                    })
+                    .collect(),
+                suffix,
+            })
+        }
+        ExpressionBody::Abstraction(LambdaAbstraction {
+            name,
+            arguments,
+            body,
+        }) => ExpressionBody::Abstraction(LambdaAbstraction {
+            name: name.map(|n| f.fold_binding(n)),
+            arguments: arguments.into_iter().map(|a| f.fold_pattern(a)).collect(),
+            body: fold_boxed(f, body),
+        }),
+        ExpressionBody::Application(LambdaApplication { lambda, parameters }) => {
+            ExpressionBody::Application(LambdaApplication {
+                lambda: fold_boxed(f, lambda),
+                parameters: parameters
+                    .into_iter()
+                    .map(|p| f.fold_expression(p))
+                    .collect(),
+            })
+        }
+        ExpressionBody::ArrayComp(ArrayComprehension {
+            sources,
+            projection,
+        }) => {
+            let sources = sources
+                .into_iter()
+                .map(|s| f.fold_comprehension_source(s))
+                .collect();
+            ExpressionBody::ArrayComp(ArrayComprehension {
+                sources,
+                projection: fold_array_items(f, projection),
+            })
+        }
+        ExpressionBody::ObjectComp(ObjectComprehension {
+            sources,
+            projection,
+        }) => {
+            let sources = sources
+                .into_iter()
+                .map(|s| f.fold_comprehension_source(s))
+                .collect();
+            ExpressionBody::ObjectComp(ObjectComprehension {
+                sources,
+                projection: fold_object_properties(f, projection),
+            })
+        }
+        ExpressionBody::Condition(IfElseExpression {
+            condition,
+            true_branch,
+            false_branch,
+        }) => ExpressionBody::Condition(IfElseExpression {
+            condition: fold_boxed(f, condition),
+            true_branch: fold_boxed(f, true_branch),
+            false_branch: false_branch.map(|b| fold_boxed(f, b)),
+        }),
+        ExpressionBody::Match(MatchExpression { subject, cases }) => {
+            ExpressionBody::Match(MatchExpression {
+                subject: fold_boxed(f, subject),
+                cases: cases.into_iter().map(|c| f.fold_match_case(c)).collect(),
+            })
+        }
+        ExpressionBody::TryCatch(TryCatchExpression {
+            body,
+            pattern,
+            fallback,
+        }) => ExpressionBody::TryCatch(TryCatchExpression {
+            body: fold_boxed(f, body),
+            pattern: f.fold_pattern(pattern),
+            fallback: fold_boxed(f, fallback),
+        }),
+        ExpressionBody::Let(LetExpression {
+            mut assignments,
+            body,
+        }) => {
+            assignments.assignments = assignments
+                .assignments
+                .into_iter()
+                .map(|a| f.fold_assignment(a))
+                .collect();
+            ExpressionBody::Let(LetExpression {
+                assignments,
+                body: fold_boxed(f, body),
+            })
+        }
+        ExpressionBody::Pipe(PipeExpression { value, function }) => {
+            ExpressionBody::Pipe(PipeExpression {
+                value: fold_boxed(f, value),
+                function: fold_boxed(f, function),
+            })
+        }
+    };
+
+    Expression::new_with_optional_location(body, expression.location)
+}
+
+fn fold_boxed<'s, F: Fold<'s> + ?Sized>(
+    f: &mut F,
+    mut expression: Box<Expression<'s>>,
+) -> Box<Expression<'s>> {
+    *expression = f.fold_expression(*expression);
+    expression
+}
+
+fn fold_array_items<'s, F: Fold<'s> + ?Sized>(
+    f: &mut F,
+    items: Vec<ArrayItem<'s>>,
+) -> Vec<ArrayItem<'s>> {
+    items
+        .into_iter()
+        .map(|item| match item {
+            ArrayItem::Single(e) => ArrayItem::Single(f.fold_expression(e)),
+            ArrayItem::Spread(e) => ArrayItem::Spread(f.fold_expression(e)),
+        })
+        .collect()
+}
+
+fn fold_object_properties<'s, F: Fold<'s> + ?Sized>(
+    f: &mut F,
+    props: Vec<ObjectProperty<'s>>,
+) -> Vec<ObjectProperty<'s>> {
+    props
+        .into_iter()
+        .map(|prop| match prop {
+            ObjectProperty::Single(id) => ObjectProperty::Single(f.fold_identifier(id)),
+            ObjectProperty::Property(Property { key, value }) => {
+                ObjectProperty::Property(Property {
+                    key: fold_property_key(f, key),
+                    value: f.fold_expression(value),
+                })
+            }
+            ObjectProperty::Spread(e) => ObjectProperty::Spread(f.fold_expression(e)),
+        })
+        .collect()
+}
+
+fn fold_property_key<'s, F: Fold<'s> + ?Sized>(f: &mut F, key: PropertyKey<'s>) -> PropertyKey<'s> {
+    match key {
+        PropertyKey::Identifier(id) => PropertyKey::Identifier(id),
+        PropertyKey::Expression(e) => PropertyKey::Expression(f.fold_expression(e)),
+    }
+}
+
+pub fn fold_pattern<'s, F: Fold<'s> + ?Sized>(f: &mut F, pattern: Pattern<'s>) -> Pattern<'s> {
+    let body = match pattern.body {
+        PatternBody::Discard => PatternBody::Discard,
+        PatternBody::TypedDiscard(t) => PatternBody::TypedDiscard(t),
+        PatternBody::Capture(id, inner) => {
+            PatternBody::Capture(f.fold_binding(id), Box::new(f.fold_pattern(*inner)))
+        }
+        PatternBody::Identifier(id) => PatternBody::Identifier(f.fold_binding(id)),
+        PatternBody::TypedIdentifier(id, t) => PatternBody::TypedIdentifier(f.fold_binding(id), t),
+        PatternBody::PinnedExpression(e) => PatternBody::PinnedExpression(fold_boxed(f, e)),
+        PatternBody::Literal(l) => PatternBody::Literal(f.fold_literal(l)),
+        PatternBody::Relational(op, l) => PatternBody::Relational(op, f.fold_literal(l)),
+        PatternBody::Object(props, rest) => {
+            let props = props
+                .into_iter()
+                .map(|prop| match prop {
+                    ObjectPropertyPattern::Single(id) => {
+                        ObjectPropertyPattern::Single(f.fold_binding(id))
+                    }
+                    ObjectPropertyPattern::Optional(id) => {
+                        ObjectPropertyPattern::Optional(f.fold_binding(id))
+                    }
+                    ObjectPropertyPattern::Default(id, default) => {
+                        let id = f.fold_binding(id);
+                        ObjectPropertyPattern::Default(id, f.fold_expression(default))
+                    }
+                    ObjectPropertyPattern::Match(PropertyPattern {
+                        key,
+                        value,
+                        default,
+                    }) => {
+                        let key = fold_property_key(f, key);
+                        let value = f.fold_pattern(value);
+                        ObjectPropertyPattern::Match(PropertyPattern {
+                            key,
+                            value,
+                            default: default.map(|d| f.fold_expression(d)),
+                        })
+                    }
+                })
+                .collect();
+            PatternBody::Object(props, fold_rest(f, rest))
+        }
+        PatternBody::Array(items, rest, suffix) => {
+            let items = fold_array_pattern_items(f, items);
+            let rest = fold_rest(f, rest);
+            PatternBody::Array(items, rest, Box::new(fold_array_pattern_items(f, *suffix)))
+        }
+        PatternBody::Alternative(alternatives) => PatternBody::Alternative(
+            alternatives
+                .into_iter()
+                .map(|p| f.fold_pattern(p))
+                .collect(),
+        ),
+        PatternBody::Range(range) => {
+            let RangePattern {
+                start,
+                end,
+                inclusive,
+            } = *range;
+            let start = f.fold_literal(start);
+            PatternBody::Range(Box::new(RangePattern {
+                start,
+                end: f.fold_literal(end),
+                inclusive,
+            }))
+        }
+        PatternBody::Regex(RegexPattern {
+            source,
+            captures,
+            regex,
+        }) => PatternBody::Regex(RegexPattern {
+            source,
+            captures: captures.into_iter().map(|c| f.fold_binding(c)).collect(),
+            regex,
+        }),
+        PatternBody::String(StringPattern::Prefix(prefix, rest)) => PatternBody::String(
+            StringPattern::Prefix(prefix, Box::new(f.fold_pattern(*rest))),
+        ),
+        PatternBody::String(StringPattern::Suffix(rest, suffix)) => PatternBody::String(
+            StringPattern::Suffix(Box::new(f.fold_pattern(*rest)), suffix),
+        ),
+    };
+
+    Pattern {
+        body,
+        location: pattern.location,
+    }
+}
+
+fn fold_array_pattern_items<'s, F: Fold<'s> + ?Sized>(
+    f: &mut F,
+    items: Vec<ArrayPatternItem<'s>>,
+) -> Vec<ArrayPatternItem<'s>> {
+    items
+        .into_iter()
+        .map(|ArrayPatternItem::Pattern(p)| ArrayPatternItem::Pattern(f.fold_pattern(p)))
+        .collect()
+}
+
+fn fold_rest<'s, F: Fold<'s> + ?Sized>(f: &mut F, rest: Rest<'s>) -> Rest<'s> {
+    match rest {
+        Rest::Collect(p) => Rest::Collect(Box::new(f.fold_pattern(*p))),
+        other => other,
+    }
+}
+
+pub fn fold_assignment<'s, F: Fold<'s> + ?Sized>(
+    f: &mut F,
+    assignment: Assignment<'s, 's>,
+) -> Assignment<'s, 's> {
+    let pattern = f.fold_pattern(assignment.pattern);
+    Assignment {
+        pattern,
+        expression: f.fold_expression(assignment.expression),
+    }
+}
+
+pub fn fold_match_case<'s, F: Fold<'s> + ?Sized>(f: &mut F, case: MatchCase<'s>) -> MatchCase<'s> {
+    let pattern = f.fold_pattern(case.pattern);
+    let guard = case.guard.map(|g| fold_boxed(f, g));
+    MatchCase {
+        pattern,
+        guard,
+        body: fold_boxed(f, case.body),
+    }
+}
+
+pub fn fold_comprehension_source<'s, F: Fold<'s> + ?Sized>(
+    f: &mut F,
+    source: ComprehensionSource<'s>,
+) -> ComprehensionSource<'s> {
+    let collection = fold_boxed(f, source.collection);
+    let pattern = f.fold_pattern(source.pattern);
+    ComprehensionSource {
+        collection,
+        pattern,
+        strong_pattern: source.strong_pattern,
+        predicate: source.predicate.map(|p| fold_boxed(f, p)),
+    }
+}
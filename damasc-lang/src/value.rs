@@ -8,16 +8,61 @@ use std::collections::HashSet;
 use crate::syntax::expression::Expression;
 use crate::syntax::pattern::Pattern;
 
+pub mod owned;
+#[cfg(feature = "serde")]
+pub mod serialization;
+
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub enum Value<'s, 'v> {
     Null,
     String(Cow<'s, str>),
     Integer(i64),
+    Float(Float),
     Boolean(bool),
     Array(ValueArray<'s, 'v>),
     Object(ValueObjectMap<'s, 'v>),
     Type(ValueType),
-    Lambda(LambdaBinding<'s, 'v>, Pattern<'s>, Expression<'s>),
+    Lambda(
+        LambdaBinding<'s, 'v>,
+        Option<Identifier<'s>>,
+        Vec<Pattern<'s>>,
+        Expression<'s>,
+    ),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Float(pub f64);
+
+impl PartialEq for Float {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl Eq for Float {}
+
+impl PartialOrd for Float {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Float {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl std::hash::Hash for Float {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.0.to_bits().hash(state);
+    }
+}
+
+impl std::fmt::Display for Float {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.0)
+    }
 }
 
 pub(crate) type ValueArray<'s, 'v> = Vec<Cow<'v, Value<'s, 'v>>>;
@@ -30,6 +75,7 @@ impl<'s, 'v> Value<'s, 'v> {
             Value::Null => ValueType::Null,
             Value::String(..) => ValueType::String,
             Value::Integer(..) => ValueType::Integer,
+            Value::Float(..) => ValueType::Float,
             Value::Boolean(..) => ValueType::Boolean,
             Value::Array(..) => ValueType::Array,
             Value::Object(..) => ValueType::Object,
@@ -46,6 +92,7 @@ impl<'s, 'v> Value<'s, 'v> {
         Some(match (&self, specified_type) {
             (Value::Null, ValueType::String) => Value::String(Cow::Borrowed("null")),
             (Value::Null, ValueType::Integer) => Value::Integer(0),
+            (Value::Null, ValueType::Float) => Value::Float(Float(0.0)),
             (Value::Null, ValueType::Boolean) => Value::Boolean(false),
             (Value::Null, ValueType::Array) => Value::Array(vec![]),
             (Value::Null, ValueType::Object) => Value::Object(BTreeMap::new()),
@@ -62,8 +109,21 @@ impl<'s, 'v> Value<'s, 'v> {
             ),
             (Value::Integer(i), ValueType::String) => Value::String(Cow::Owned(i.to_string())),
             (Value::Integer(i), ValueType::Boolean) => Value::Boolean(i != &0),
+            (Value::Integer(i), ValueType::Float) => Value::Float(Float(*i as f64)),
+            (Value::Float(x), ValueType::String) => Value::String(Cow::Owned(x.to_string())),
+            (Value::Float(x), ValueType::Boolean) => Value::Boolean(x.0 != 0.0),
+            (Value::Float(x), ValueType::Integer) => {
+                let t = x.0.trunc();
+                if !t.is_finite() || t < i64::MIN as f64 || t >= i64::MAX as f64 {
+                    return None;
+                }
+                Value::Integer(t as i64)
+            }
             (Value::Boolean(b), ValueType::String) => Value::String(Cow::Owned(b.to_string())),
             (Value::Boolean(b), ValueType::Integer) => Value::Integer(if *b { 1 } else { 0 }),
+            (Value::Boolean(b), ValueType::Float) => {
+                Value::Float(Float(if *b { 1.0 } else { 0.0 }))
+            }
             (Value::Array(a), ValueType::Integer) => Value::Integer(a.len() as i64),
             (Value::Object(o), ValueType::Integer) => Value::Integer(o.len() as i64),
             _ => return None,
@@ -75,6 +135,7 @@ impl<'s, 'v> Value<'s, 'v> {
             Value::Null => Value::Null,
             Value::String(s) => Value::String(Cow::Owned(s.to_string())),
             Value::Integer(i) => Value::Integer(*i),
+            Value::Float(x) => Value::Float(*x),
             Value::Boolean(b) => Value::Boolean(*b),
             Value::Array(a) => Value::Array(a.iter().map(|v| Cow::Owned(v.deep_clone())).collect()),
             Value::Object(o) => Value::Object(
@@ -83,7 +144,12 @@ impl<'s, 'v> Value<'s, 'v> {
                     .collect(),
             ),
             Value::Type(t) => Value::Type(*t),
-            Value::Lambda(e, p, b) => Value::Lambda(e.deep_clone(), p.deep_clone(), b.deep_clone()),
+            Value::Lambda(e, n, p, b) => Value::Lambda(
+                e.deep_clone(),
+                n.as_ref().map(|n| n.deep_clone()),
+                p.iter().map(|p| p.deep_clone()).collect(),
+                b.deep_clone(),
+            ),
         }
     }
 }
@@ -94,6 +160,7 @@ impl<'s, 'v> std::fmt::Display for Value<'s, 'v> {
             Value::Null => write!(f, "null"),
             Value::String(s) => write!(f, "\"{s}\""),
             Value::Integer(i) => write!(f, "{i}"),
+            Value::Float(x) => write!(f, "{x}"),
             Value::Boolean(b) => write!(f, "{b}"),
             Value::Array(a) => {
                 write!(f, "[")?;
@@ -116,8 +183,18 @@ impl<'s, 'v> std::fmt::Display for Value<'s, 'v> {
                 write!(f, "}}")
             }
             Value::Type(t) => write!(f, "{t}"),
-            Value::Lambda(env, pat, expr) => {
-                write!(f, "fn ({pat}) => {expr}")?;
+            Value::Lambda(env, name, pat, expr) => {
+                match name {
+                    Some(name) => write!(f, "fn {name}(")?,
+                    None => write!(f, "fn (")?,
+                }
+                for (i, p) in pat.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{p}")?;
+                }
+                write!(f, ") => {expr}")?;
 
                 if !env.bindings.is_empty() {
                     write!(f, " with ")?;
@@ -0,0 +1,117 @@
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use crate::identifier::Identifier;
+use crate::syntax::expression::Expression;
+use crate::syntax::pattern::Pattern;
+use crate::value_type::ValueType;
+
+use super::{Float, LambdaBinding, Value};
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum OwnedValue {
+    Null,
+    String(Arc<str>),
+    Integer(i64),
+    Float(Float),
+    Boolean(bool),
+    Array(Arc<[OwnedValue]>),
+    Object(Arc<BTreeMap<Arc<str>, OwnedValue>>),
+    Type(ValueType),
+    Lambda(Arc<OwnedLambda>),
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct OwnedLambda {
+    pub bindings: LambdaBinding<'static, 'static>,
+    pub name: Option<Identifier<'static>>,
+    pub arguments: Vec<Pattern<'static>>,
+    pub body: Expression<'static>,
+}
+
+impl OwnedValue {
+    pub fn get_type(&self) -> ValueType {
+        match self {
+            OwnedValue::Null => ValueType::Null,
+            OwnedValue::String(_) => ValueType::String,
+            OwnedValue::Integer(_) => ValueType::Integer,
+            OwnedValue::Float(_) => ValueType::Float,
+            OwnedValue::Boolean(_) => ValueType::Boolean,
+            OwnedValue::Array(_) => ValueType::Array,
+            OwnedValue::Object(_) => ValueType::Object,
+            OwnedValue::Type(_) => ValueType::Type,
+            OwnedValue::Lambda(_) => ValueType::Lambda,
+        }
+    }
+
+    pub fn to_value<'s, 'v>(&self) -> Value<'s, 'v> {
+        match self {
+            OwnedValue::Null => Value::Null,
+            OwnedValue::String(s) => Value::String(Cow::Owned(s.to_string())),
+            OwnedValue::Integer(i) => Value::Integer(*i),
+            OwnedValue::Float(f) => Value::Float(*f),
+            OwnedValue::Boolean(b) => Value::Boolean(*b),
+            OwnedValue::Array(a) => {
+                Value::Array(a.iter().map(|v| Cow::Owned(v.to_value())).collect())
+            }
+            OwnedValue::Object(o) => Value::Object(
+                o.iter()
+                    .map(|(k, v)| (Cow::Owned(k.to_string()), Cow::Owned(v.to_value())))
+                    .collect(),
+            ),
+            OwnedValue::Type(t) => Value::Type(*t),
+            OwnedValue::Lambda(l) => Value::Lambda(
+                l.bindings.deep_clone(),
+                l.name.as_ref().map(|n| n.deep_clone()),
+                l.arguments.iter().map(|p| p.deep_clone()).collect(),
+                l.body.deep_clone(),
+            ),
+        }
+    }
+}
+
+impl From<&Value<'_, '_>> for OwnedValue {
+    fn from(value: &Value<'_, '_>) -> Self {
+        match value {
+            Value::Null => OwnedValue::Null,
+            Value::String(s) => OwnedValue::String(Arc::from(s.as_ref())),
+            Value::Integer(i) => OwnedValue::Integer(*i),
+            Value::Float(f) => OwnedValue::Float(*f),
+            Value::Boolean(b) => OwnedValue::Boolean(*b),
+            Value::Array(a) => OwnedValue::Array(a.iter().map(|v| v.as_ref().into()).collect()),
+            Value::Object(o) => OwnedValue::Object(Arc::new(
+                o.iter()
+                    .map(|(k, v)| (Arc::from(k.as_ref()), v.as_ref().into()))
+                    .collect(),
+            )),
+            Value::Type(t) => OwnedValue::Type(*t),
+            Value::Lambda(bindings, name, arguments, body) => {
+                OwnedValue::Lambda(Arc::new(OwnedLambda {
+                    bindings: bindings.deep_clone(),
+                    name: name.as_ref().map(|n| n.deep_clone()),
+                    arguments: arguments.iter().map(|p| p.deep_clone()).collect(),
+                    body: body.deep_clone(),
+                }))
+            }
+        }
+    }
+}
+
+impl From<Value<'_, '_>> for OwnedValue {
+    fn from(value: Value<'_, '_>) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl<'s, 'v> From<&OwnedValue> for Value<'s, 'v> {
+    fn from(value: &OwnedValue) -> Self {
+        value.to_value()
+    }
+}
+
+impl std::fmt::Display for OwnedValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_value())
+    }
+}
@@ -0,0 +1,217 @@
+//! JSON compatible (de)serialization of [`Value`].
+//!
+//! `Null`, `Boolean`, `Integer`, `Float`, `String`, `Array` and `Object`
+//! map directly onto their serde data model counterparts.
+//!
+//! Values without a JSON equivalent are mapped as follows:
+//!
+//! * `Type` values are written as an object with the single key `"$type"`,
+//!   eg. `Integer` becomes `{"$type": "Integer"}`. When deserializing, an
+//!   object consisting of exactly that key and a known type name is read
+//!   back as a `Type` value.
+//! * `Lambda` values can not be serialized, because their body and captured
+//!   environment have no data representation. Serializing them fails with
+//!   an error.
+//!
+//! Unsigned integers that do not fit into an `i64` are rejected when
+//! deserializing.
+//!
+//! Besides the `Serialize` and `Deserialize` impls, [`to_value`] and
+//! [`from_value`] convert between Rust data structures and [`Value`]
+//! directly, using the same mapping. Enums use the externally tagged
+//! representation: unit variants become strings, all other variants an
+//! object with the variant name as single key.
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde::de::{self, MapAccess, SeqAccess, Visitor};
+use serde::ser::{self, SerializeMap, SerializeSeq};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::value_type::ValueType;
+
+use super::{Float, Value, ValueArray, ValueObjectMap};
+
+mod deserializer;
+mod serializer;
+
+pub use deserializer::from_value;
+pub use serializer::{to_value, ValueSerializer};
+
+const TYPE_KEY: &str = "$type";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SerializationError(String);
+
+impl fmt::Display for SerializationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for SerializationError {}
+
+impl ser::Error for SerializationError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self(msg.to_string())
+    }
+}
+
+impl de::Error for SerializationError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self(msg.to_string())
+    }
+}
+
+impl Serialize for Value<'_, '_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Value::Null => serializer.serialize_unit(),
+            Value::String(s) => serializer.serialize_str(s),
+            Value::Integer(i) => serializer.serialize_i64(*i),
+            Value::Float(Float(f)) => serializer.serialize_f64(*f),
+            Value::Boolean(b) => serializer.serialize_bool(*b),
+            Value::Array(a) => {
+                let mut seq = serializer.serialize_seq(Some(a.len()))?;
+                for v in a {
+                    seq.serialize_element(v.as_ref())?;
+                }
+                seq.end()
+            }
+            Value::Object(o) => {
+                let mut map = serializer.serialize_map(Some(o.len()))?;
+                for (k, v) in o {
+                    map.serialize_entry(k.as_ref(), v.as_ref())?;
+                }
+                map.end()
+            }
+            Value::Type(t) => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry(TYPE_KEY, &t.to_string())?;
+                map.end()
+            }
+            Value::Lambda(..) => Err(ser::Error::custom("lambda values can not be serialized")),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Value<'_, '_> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ValueVisitor::default())
+    }
+}
+
+#[derive(Default)]
+struct ValueVisitor<'s, 'v> {
+    marker: std::marker::PhantomData<Value<'s, 'v>>,
+}
+
+impl<'de, 's, 'v> Visitor<'de> for ValueVisitor<'s, 'v> {
+    type Value = Value<'s, 'v>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a damasc value")
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E> {
+        Ok(Value::Null)
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E> {
+        Ok(Value::Null)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Deserialize::deserialize(deserializer)
+    }
+
+    fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E> {
+        Ok(Value::Boolean(v))
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E> {
+        Ok(Value::Integer(v))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        i64::try_from(v)
+            .map(Value::Integer)
+            .map_err(|_| E::invalid_value(de::Unexpected::Unsigned(v), &self))
+    }
+
+    fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E> {
+        Ok(Value::Float(Float(v)))
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(Value::String(Cow::Owned(v.to_string())))
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<Self::Value, E> {
+        Ok(Value::String(Cow::Owned(v)))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut values: ValueArray<'s, 'v> = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(v) = seq.next_element::<Value<'s, 'v>>()? {
+            values.push(Cow::Owned(v));
+        }
+        Ok(Value::Array(values))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut values: ValueObjectMap<'s, 'v> = BTreeMap::new();
+        while let Some((k, v)) = map.next_entry::<String, Value<'s, 'v>>()? {
+            values.insert(Cow::Owned(k), Cow::Owned(v));
+        }
+
+        Ok(object_or_type(values))
+    }
+}
+
+fn object_or_type<'s, 'v>(values: ValueObjectMap<'s, 'v>) -> Value<'s, 'v> {
+    if values.len() == 1 {
+        if let Some(Value::String(name)) = values.get(TYPE_KEY).map(|v| v.as_ref()) {
+            if let Some(t) = type_from_name(name.as_ref()) {
+                return Value::Type(t);
+            }
+        }
+    }
+
+    Value::Object(values)
+}
+
+fn type_from_name(name: &str) -> Option<ValueType> {
+    Some(match name {
+        "Null" => ValueType::Null,
+        "String" => ValueType::String,
+        "Integer" => ValueType::Integer,
+        "Float" => ValueType::Float,
+        "Boolean" => ValueType::Boolean,
+        "Array" => ValueType::Array,
+        "Object" => ValueType::Object,
+        "Type" => ValueType::Type,
+        "Lambda" => ValueType::Lambda,
+        _ => return None,
+    })
+}
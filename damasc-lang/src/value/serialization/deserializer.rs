@@ -0,0 +1,190 @@
+use serde::de::value::{MapDeserializer, SeqDeserializer};
+use serde::de::{
+    self, Deserialize, DeserializeSeed, EnumAccess, IntoDeserializer, VariantAccess, Visitor,
+};
+use serde::forward_to_deserialize_any;
+
+use crate::value::{Float, Value};
+
+use super::{SerializationError, TYPE_KEY};
+
+pub fn from_value<'de, T>(value: &'de Value<'_, '_>) -> Result<T, SerializationError>
+where
+    T: Deserialize<'de>,
+{
+    T::deserialize(value)
+}
+
+impl<'de, 's, 'v> de::Deserializer<'de> for &'de Value<'s, 'v> {
+    type Error = SerializationError;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self {
+            Value::Null => visitor.visit_unit(),
+            Value::String(s) => visitor.visit_borrowed_str(s),
+            Value::Integer(i) => visitor.visit_i64(*i),
+            Value::Float(Float(f)) => visitor.visit_f64(*f),
+            Value::Boolean(b) => visitor.visit_bool(*b),
+            Value::Array(a) => {
+                let mut seq = SeqDeserializer::new(a.iter().map(|v| v.as_ref()));
+                let result = visitor.visit_seq(&mut seq)?;
+                seq.end()?;
+                Ok(result)
+            }
+            Value::Object(o) => {
+                let mut map = MapDeserializer::new(o.iter().map(|(k, v)| (k.as_ref(), v.as_ref())));
+                let result = visitor.visit_map(&mut map)?;
+                map.end()?;
+                Ok(result)
+            }
+            Value::Type(t) => {
+                let mut map = MapDeserializer::new(std::iter::once((TYPE_KEY, t.to_string())));
+                let result = visitor.visit_map(&mut map)?;
+                map.end()?;
+                Ok(result)
+            }
+            Value::Lambda(..) => Err(de::Error::custom("lambda values can not be deserialized")),
+        }
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self {
+            Value::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self {
+            Value::String(s) => visitor.visit_enum(VariantDeserializer {
+                variant: s,
+                value: None,
+            }),
+            Value::Object(o) if o.len() == 1 => {
+                let Some((variant, value)) = o.iter().next() else {
+                    unreachable!("object has exactly one entry");
+                };
+                visitor.visit_enum(VariantDeserializer {
+                    variant,
+                    value: Some(value),
+                })
+            }
+            other => Err(de::Error::custom(format!(
+                "expected a string or an object with a single key, but found {other}"
+            ))),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+impl<'de, 's, 'v> IntoDeserializer<'de, SerializationError> for &'de Value<'s, 'v> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        self
+    }
+}
+
+struct VariantDeserializer<'de, 's, 'v> {
+    variant: &'de str,
+    value: Option<&'de Value<'s, 'v>>,
+}
+
+impl<'de, 's, 'v> EnumAccess<'de> for VariantDeserializer<'de, 's, 'v> {
+    type Error = SerializationError;
+    type Variant = Self;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self::Variant), Self::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let variant = seed.deserialize(
+            IntoDeserializer::<SerializationError>::into_deserializer(self.variant),
+        )?;
+        Ok((variant, self))
+    }
+}
+
+impl<'de, 's, 'v> VariantAccess<'de> for VariantDeserializer<'de, 's, 'v> {
+    type Error = SerializationError;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        match self.value {
+            None | Some(Value::Null) => Ok(()),
+            Some(other) => Err(de::Error::custom(format!(
+                "expected unit variant {}, but found {other}",
+                self.variant
+            ))),
+        }
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        match self.value {
+            Some(value) => seed.deserialize(value),
+            None => seed.deserialize(&Value::Null),
+        }
+    }
+
+    fn tuple_variant<V>(self, _len: usize, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            Some(value @ Value::Array(_)) => de::Deserializer::deserialize_seq(value, visitor),
+            _ => Err(de::Error::custom(format!(
+                "expected tuple variant {} to contain an array",
+                self.variant
+            ))),
+        }
+    }
+
+    fn struct_variant<V>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            Some(value @ Value::Object(_)) => de::Deserializer::deserialize_map(value, visitor),
+            _ => Err(de::Error::custom(format!(
+                "expected struct variant {} to contain an object",
+                self.variant
+            ))),
+        }
+    }
+}
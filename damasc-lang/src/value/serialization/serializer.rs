@@ -0,0 +1,381 @@
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::marker::PhantomData;
+
+use serde::ser::{self, Serialize};
+
+use crate::value::{Float, Value, ValueArray, ValueObjectMap};
+
+use super::{object_or_type, SerializationError};
+
+pub fn to_value<'s, 'v, T>(value: &T) -> Result<Value<'s, 'v>, SerializationError>
+where
+    T: Serialize + ?Sized,
+{
+    value.serialize(ValueSerializer::default())
+}
+
+pub struct ValueSerializer<'s, 'v> {
+    marker: PhantomData<Value<'s, 'v>>,
+}
+
+impl Default for ValueSerializer<'_, '_> {
+    fn default() -> Self {
+        Self {
+            marker: PhantomData,
+        }
+    }
+}
+
+pub struct SerializeArray<'s, 'v> {
+    items: ValueArray<'s, 'v>,
+}
+
+pub struct SerializeArrayVariant<'s, 'v> {
+    variant: &'static str,
+    items: ValueArray<'s, 'v>,
+}
+
+pub struct SerializeObject<'s, 'v> {
+    entries: ValueObjectMap<'s, 'v>,
+    next_key: Option<Cow<'s, str>>,
+}
+
+pub struct SerializeObjectVariant<'s, 'v> {
+    variant: &'static str,
+    entries: ValueObjectMap<'s, 'v>,
+}
+
+fn variant_object<'s, 'v>(variant: &'static str, value: Value<'s, 'v>) -> Value<'s, 'v> {
+    Value::Object(BTreeMap::from([(
+        Cow::Owned(variant.to_string()),
+        Cow::Owned(value),
+    )]))
+}
+
+impl<'s, 'v> ser::Serializer for ValueSerializer<'s, 'v> {
+    type Ok = Value<'s, 'v>;
+    type Error = SerializationError;
+
+    type SerializeSeq = SerializeArray<'s, 'v>;
+    type SerializeTuple = SerializeArray<'s, 'v>;
+    type SerializeTupleStruct = SerializeArray<'s, 'v>;
+    type SerializeTupleVariant = SerializeArrayVariant<'s, 'v>;
+    type SerializeMap = SerializeObject<'s, 'v>;
+    type SerializeStruct = SerializeObject<'s, 'v>;
+    type SerializeStructVariant = SerializeObjectVariant<'s, 'v>;
+
+    fn serialize_bool(self, v: bool) -> Result<Self::Ok, Self::Error> {
+        Ok(Value::Boolean(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Self::Ok, Self::Error> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Self::Ok, Self::Error> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Self::Ok, Self::Error> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Self::Ok, Self::Error> {
+        Ok(Value::Integer(v))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Self::Ok, Self::Error> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Self::Ok, Self::Error> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Self::Ok, Self::Error> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Self::Ok, Self::Error> {
+        i64::try_from(v)
+            .map(Value::Integer)
+            .map_err(|_| SerializationError(format!("integer {v} is out of range")))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Self::Ok, Self::Error> {
+        self.serialize_f64(v as f64)
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Self::Ok, Self::Error> {
+        Ok(Value::Float(Float(v)))
+    }
+
+    fn serialize_char(self, v: char) -> Result<Self::Ok, Self::Error> {
+        Ok(Value::String(Cow::Owned(v.to_string())))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
+        Ok(Value::String(Cow::Owned(v.to_string())))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        Ok(Value::Array(
+            v.iter()
+                .map(|b| Cow::Owned(Value::Integer(*b as i64)))
+                .collect(),
+        ))
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<Self::Ok, Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        Ok(variant_object(variant, to_value(value)?))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Ok(SerializeArray {
+            items: Vec::with_capacity(len.unwrap_or(0)),
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Ok(SerializeArrayVariant {
+            variant,
+            items: Vec::with_capacity(len),
+        })
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Ok(SerializeObject {
+            entries: BTreeMap::new(),
+            next_key: None,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Ok(SerializeObjectVariant {
+            variant,
+            entries: BTreeMap::new(),
+        })
+    }
+}
+
+impl<'s, 'v> ser::SerializeSeq for SerializeArray<'s, 'v> {
+    type Ok = Value<'s, 'v>;
+    type Error = SerializationError;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.items.push(Cow::Owned(to_value(value)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(Value::Array(self.items))
+    }
+}
+
+impl<'s, 'v> ser::SerializeTuple for SerializeArray<'s, 'v> {
+    type Ok = Value<'s, 'v>;
+    type Error = SerializationError;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl<'s, 'v> ser::SerializeTupleStruct for SerializeArray<'s, 'v> {
+    type Ok = Value<'s, 'v>;
+    type Error = SerializationError;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl<'s, 'v> ser::SerializeTupleVariant for SerializeArrayVariant<'s, 'v> {
+    type Ok = Value<'s, 'v>;
+    type Error = SerializationError;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.items.push(Cow::Owned(to_value(value)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(variant_object(self.variant, Value::Array(self.items)))
+    }
+}
+
+impl<'s, 'v> ser::SerializeMap for SerializeObject<'s, 'v> {
+    type Ok = Value<'s, 'v>;
+    type Error = SerializationError;
+
+    fn serialize_key<T>(&mut self, key: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.next_key = Some(match to_value(key)? {
+            Value::String(s) => s,
+            Value::Integer(i) => Cow::Owned(i.to_string()),
+            Value::Boolean(b) => Cow::Owned(b.to_string()),
+            other => {
+                return Err(SerializationError(format!(
+                    "object keys must be strings, but got {other}"
+                )))
+            }
+        });
+        Ok(())
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        let Some(key) = self.next_key.take() else {
+            return Err(SerializationError(
+                "object value serialized before its key".to_string(),
+            ));
+        };
+        self.entries.insert(key, Cow::Owned(to_value(value)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(object_or_type(self.entries))
+    }
+}
+
+impl<'s, 'v> ser::SerializeStruct for SerializeObject<'s, 'v> {
+    type Ok = Value<'s, 'v>;
+    type Error = SerializationError;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.entries
+            .insert(Cow::Owned(key.to_string()), Cow::Owned(to_value(value)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(Value::Object(self.entries))
+    }
+}
+
+impl<'s, 'v> ser::SerializeStructVariant for SerializeObjectVariant<'s, 'v> {
+    type Ok = Value<'s, 'v>;
+    type Error = SerializationError;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.entries
+            .insert(Cow::Owned(key.to_string()), Cow::Owned(to_value(value)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(variant_object(self.variant, Value::Object(self.entries)))
+    }
+}
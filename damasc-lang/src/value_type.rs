@@ -3,6 +3,7 @@ pub enum ValueType {
     Null,
     String,
     Integer,
+    Float,
     Boolean,
     Array,
     Object,
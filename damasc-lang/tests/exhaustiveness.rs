@@ -0,0 +1,29 @@
+use damasc_lang::{analysis::typing::TypeChecker, parser, runtime::env::Environment};
+
+#[test]
+fn test_match_warnings() {
+    let mut lines = include_str!("./examples_match_warnings.txt")
+        .lines()
+        .enumerate();
+    let env = Environment::default();
+    let checker = TypeChecker::new(&env);
+
+    while let Some((line_number, source)) = lines.next() {
+        let expected: Vec<&str> = lines
+            .by_ref()
+            .map(|(_, line)| line)
+            .take_while(|line| *line != "---")
+            .collect();
+        let Some(expression) = parser::expression::expression_all_consuming(source) else {
+            unreachable!("Parse error");
+        };
+
+        let actual: Vec<String> = checker
+            .check_matches(&expression)
+            .iter()
+            .map(|w| w.reason.to_string())
+            .collect();
+
+        assert_eq!(actual, expected, "on line {}", line_number + 1);
+    }
+}
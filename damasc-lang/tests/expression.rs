@@ -1,6 +1,16 @@
 #![feature(iter_array_chunks)]
 
-use damasc_lang::{parser, runtime::evaluation::Evaluation};
+use damasc_lang::{
+    parser,
+    runtime::{
+        budget::{BudgetLimit, EvalBudget, EvalLimits},
+        env::Environment,
+        evaluation::{EvalError, EvalErrorReason, Evaluation},
+        native::{NativeFunction, NativeRegistry, NativeSignature},
+    },
+    value::Value,
+    value_type::ValueType,
+};
 
 #[test]
 fn test_expression_parsing() {
@@ -43,3 +53,136 @@ fn test_expression_evaluation() {
         }
     }
 }
+
+struct Double;
+
+impl NativeFunction for Double {
+    fn name(&self) -> &str {
+        "double"
+    }
+
+    fn signature(&self) -> NativeSignature {
+        NativeSignature::new(vec![Some(ValueType::Integer)], Some(ValueType::Integer))
+    }
+
+    fn call<'s, 'v>(
+        &self,
+        _evaluation: &Evaluation<'_, '_, 's, 'v>,
+        arguments: &[Value<'s, 'v>],
+    ) -> Result<Value<'s, 'v>, EvalError<'s, 'v>> {
+        let Value::Integer(i) = arguments[0] else {
+            unreachable!("signature is checked before call");
+        };
+
+        Ok(Value::Integer(i * 2))
+    }
+}
+
+#[test]
+fn test_custom_native_function() {
+    let mut natives = NativeRegistry::default();
+    natives.register(Double);
+
+    let env = Environment::default();
+    let eval = Evaluation::new(&env).with_natives(&natives);
+
+    let Some(set) = parser::expression::expression_many1_all_consuming("double(21); double(\"x\")")
+    else {
+        unreachable!("Parse error");
+    };
+
+    assert_eq!(
+        eval.eval_expr(&set.expressions[0]).ok(),
+        Some(Value::Integer(42))
+    );
+    assert!(matches!(
+        eval.eval_expr(&set.expressions[1]).map_err(|e| e.reason),
+        Err(EvalErrorReason::TypeError(ValueType::Integer, _))
+    ));
+
+    let default_eval = Evaluation::new(&env);
+    assert!(matches!(
+        default_eval
+            .eval_expr(&set.expressions[0])
+            .map_err(|e| e.reason),
+        Err(EvalErrorReason::UnknownFunction(_))
+    ));
+}
+
+#[test]
+fn test_evaluation_budget() {
+    let env = Environment::default();
+
+    let Some(set) = parser::expression::expression_many1_all_consuming(
+        "(fn loop(x) => loop.(x)).(1); range(0, 100000); [...range(0, 600), ...range(0, 600)]; (fn count(n) => if n > 0 { count.(n - 1) } else { n }).(40)",
+    ) else {
+        unreachable!("Parse error");
+    };
+
+    let budget = EvalBudget::new(EvalLimits::new(100_000, 50, 1000));
+    let eval = Evaluation::new(&env).with_budget(Some(&budget));
+
+    assert!(matches!(
+        eval.eval_expr(&set.expressions[0]).map_err(|e| e.reason),
+        Err(EvalErrorReason::BudgetExhausted(BudgetLimit::CallDepth(50)))
+    ));
+    assert!(matches!(
+        eval.eval_expr(&set.expressions[1]).map_err(|e| e.reason),
+        Err(EvalErrorReason::BudgetExhausted(
+            BudgetLimit::CollectionSize(1000)
+        ))
+    ));
+    assert!(matches!(
+        eval.eval_expr(&set.expressions[2]).map_err(|e| e.reason),
+        Err(EvalErrorReason::BudgetExhausted(
+            BudgetLimit::CollectionSize(1000)
+        ))
+    ));
+    assert_eq!(
+        eval.eval_expr(&set.expressions[3]).ok(),
+        Some(Value::Integer(0))
+    );
+
+    let step_budget = EvalBudget::new(EvalLimits {
+        max_steps: Some(100),
+        ..EvalLimits::UNLIMITED
+    });
+    let step_eval = Evaluation::new(&env).with_budget(Some(&step_budget));
+
+    assert!(matches!(
+        step_eval
+            .eval_expr(&set.expressions[3])
+            .map_err(|e| e.reason),
+        Err(EvalErrorReason::BudgetExhausted(BudgetLimit::Steps(100)))
+    ));
+
+    step_budget.reset();
+    assert_eq!(step_budget.steps(), 0);
+}
+
+#[test]
+fn test_try_catch_rethrow() {
+    let env = Environment::default();
+
+    let Some(set) = parser::expression::expression_many1_all_consuming(
+        "try { 1 / 0 } catch ({kind: \"TypeError\", ...}) { 0 }; try { range(0, 5000) } catch (_) { [] }",
+    ) else {
+        unreachable!("Parse error");
+    };
+
+    let budget = EvalBudget::new(EvalLimits::new(100_000, 50, 1000));
+    let eval = Evaluation::new(&env).with_budget(Some(&budget));
+
+    let Err(error) = eval.eval_expr(&set.expressions[0]) else {
+        unreachable!("Error must not be caught");
+    };
+    assert!(matches!(error.reason, EvalErrorReason::MathDivisionByZero));
+    assert!(error.location.is_some());
+
+    assert!(matches!(
+        eval.eval_expr(&set.expressions[1]).map_err(|e| e.reason),
+        Err(EvalErrorReason::BudgetExhausted(
+            BudgetLimit::CollectionSize(1000)
+        ))
+    ));
+}
@@ -0,0 +1,38 @@
+use damasc_lang::{analysis::lint::Linter, parser, runtime::env::Environment};
+
+#[test]
+fn test_lint_warnings() {
+    let mut lines = include_str!("./examples_lint_warnings.txt")
+        .lines()
+        .enumerate();
+    let env = Environment::default();
+    let linter = Linter::new(&env);
+
+    while let Some((line_number, source)) = lines.next() {
+        let expected: Vec<&str> = lines
+            .by_ref()
+            .map(|(_, line)| line)
+            .take_while(|line| *line != "---")
+            .collect();
+        let warnings = if let Some(expression) =
+            parser::expression::expression_all_consuming(source)
+        {
+            linter.lint_expression(&expression)
+        } else if let Some(assignments) = parser::assignment::assignment_set1_all_consuming(source)
+        {
+            linter.lint(&assignments, &[])
+        } else {
+            unreachable!("Parse error");
+        };
+
+        let actual: Vec<String> = warnings
+            .iter()
+            .map(|w| match w.location {
+                Some(l) => format!("{} ({}..{})", w.reason, l.start, l.end),
+                None => w.reason.to_string(),
+            })
+            .collect();
+
+        assert_eq!(actual, expected, "on line {}", line_number + 1);
+    }
+}
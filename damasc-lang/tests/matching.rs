@@ -2,8 +2,9 @@
 
 use core::assert_matches::assert_matches;
 use damasc_lang::parser;
-use damasc_lang::runtime::assignment::AssignmentEvaluation;
+use damasc_lang::runtime::assignment::{AssignmentError, AssignmentEvaluation};
 use damasc_lang::runtime::env::Environment;
+use damasc_lang::runtime::matching::{PatternFail, PatternFailReason};
 
 #[test]
 fn test_matching_fail() {
@@ -19,3 +20,27 @@ fn test_matching_fail() {
         assert_matches!(assignment_eval.eval_assigment_set(assignment), Err(_));
     }
 }
+
+#[test]
+fn test_matching_alternatives() {
+    let Some(assignment) = parser::assignment::assignment_set1_all_consuming("1 | [_] = [1, 2]")
+    else {
+        unreachable!("Can not parse assignments");
+    };
+
+    let env = Environment::default();
+    let assignment_eval = AssignmentEvaluation::new(&env);
+
+    assert_matches!(
+        assignment_eval.eval_assigment_set(assignment),
+        Err(AssignmentError::MatchError(PatternFail {
+            reason: PatternFailReason::AlternativesMismatch(failures),
+            ..
+        })) if failures.len() == 2
+    );
+
+    assert_matches!(
+        parser::assignment::assignment_set1_all_consuming("[a] | [b] = [1]"),
+        None
+    );
+}
@@ -0,0 +1,122 @@
+use std::collections::HashSet;
+
+use damasc_lang::{
+    parser,
+    syntax::{
+        expression::{BinaryExpression, BinaryOperator, Expression, ExpressionBody},
+        pretty::PrettyPrinter,
+    },
+};
+
+#[test]
+fn test_pretty_round_trip() {
+    let lines = include_str!("./examples_expression_pairs.txt").lines();
+
+    for (line_number, line) in lines.enumerate().filter(|(_, l)| l != &"---") {
+        let Some(expressions) = parser::expression::expression_many1_all_consuming(line) else {
+            unreachable!("Parse error on line {}", line_number + 1);
+        };
+
+        // Lines the printer can not break any further, which may exceed any width.
+        let unbreakable: HashSet<String> = PrettyPrinter::default()
+            .with_width(1)
+            .print_expression_set(&expressions)
+            .lines()
+            .map(|l| l.trim().to_string())
+            .collect();
+
+        for width in [usize::MAX, 80, 40, 30, 1] {
+            let printer = PrettyPrinter::default().with_width(width);
+            let printed = printer.print_expression_set(&expressions);
+            let Some(reparsed) = parser::expression::expression_many1_all_consuming(&printed)
+            else {
+                unreachable!(
+                    "Printed line {} with width {width} unparsable:\n{printed}",
+                    line_number + 1
+                );
+            };
+
+            assert_eq!(expressions, reparsed, "on line {}", line_number + 1);
+            for printed_line in printed.lines() {
+                assert!(
+                    printed_line.len() <= width || unbreakable.contains(printed_line.trim()),
+                    "Line {} exceeds the width {width}:\n{printed}",
+                    line_number + 1
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_pretty_minimal_parentheses() {
+    let cases = [
+        ("(1 + 2) * 3", "(1 + 2) * 3"),
+        ("1 + (2 * 3)", "1 + 2 * 3"),
+        ("(1 - 2) - 3", "1 - 2 - 3"),
+        ("1 - (2 - 3)", "1 - (2 - 3)"),
+        ("((a.b).c)[0]", "a.b.c[0]"),
+        ("-(x.y)", "-x.y"),
+        ("(-x).y", "(-x).y"),
+        ("(fn (x) => x).(1)", "(fn (x) => x).(1)"),
+        ("[fn (x) => (x + 1)]", "[fn (x) => x + 1]"),
+        ("(fn (x) => x) |> f", "(fn (x) => x) |> f"),
+        ("f |> (fn (x) => x)", "f |> (fn (x) => x)"),
+        ("let x = (1 in y) in x", "let x = (1 in y) in x"),
+        ("(a ?? b) || c", "(a ?? b) || c"),
+        ("(x is Integer) && y", "x is Integer && y"),
+        ("{\"foo bar\": 1, #if: 2}", "{\"foo bar\": 1, #if: 2}"),
+        ("`a${b}c`", "`a${b}c`"),
+        ("-1", "-1"),
+        ("-(1)", "-1"),
+        ("-(1.5)", "-1.5"),
+        ("2 ^ -1", "2 ^ -1"),
+        ("(-2) ^ 2", "(-2) ^ 2"),
+        ("-(2 ^ 2)", "-(2 ^ 2)"),
+        ("-(x ^ 2)", "-(x ^ 2)"),
+        ("-(1).x", "-(1.x)"),
+    ];
+
+    let printer = PrettyPrinter::single_line();
+    for (input, expected) in cases {
+        let Some(expression) = parser::expression::expression_all_consuming(input) else {
+            unreachable!("Parse error: {input}");
+        };
+        assert_eq!(printer.print_expression(&expression), expected);
+    }
+}
+
+#[test]
+fn test_pretty_layout() {
+    let Some(expression) = parser::expression::expression_all_consuming(
+        "match (x) { [a, b] if a > b => {min: b, max: a}, [a, b] => {min: a, max: b} }",
+    ) else {
+        unreachable!("Parse error");
+    };
+
+    assert_eq!(
+        PrettyPrinter::default()
+            .with_width(40)
+            .with_indent(2)
+            .print_expression(&expression),
+        "match (x) {\n  [a, b] if a > b => {min: b, max: a},\n  [a, b] => {min: a, max: b},\n}"
+    );
+}
+
+#[test]
+fn test_pretty_escaped_identifiers() {
+    let expression = Expression::new(ExpressionBody::Binary(BinaryExpression {
+        operator: BinaryOperator::Plus,
+        left: Box::new(Expression::new(ExpressionBody::Identifier(
+            damasc_lang::identifier::Identifier::new("match"),
+        ))),
+        right: Box::new(Expression::new(ExpressionBody::Identifier(
+            damasc_lang::identifier::Identifier::new("a b"),
+        ))),
+    }));
+
+    assert_eq!(
+        PrettyPrinter::default().print_expression(&expression),
+        "#match + #\"a b\""
+    );
+}
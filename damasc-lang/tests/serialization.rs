@@ -0,0 +1,158 @@
+use damasc_lang::{
+    identifier::Identifier,
+    parser,
+    runtime::{env::Environment, evaluation::Evaluation},
+    value::{
+        serialization::{from_value, to_value},
+        Value,
+    },
+    value_type::ValueType,
+};
+use serde::{Deserialize, Serialize};
+
+#[test]
+fn test_json_round_trip() {
+    let lines = include_str!("./examples_values.txt").lines();
+
+    for (number, line) in lines.filter(|l| !l.is_empty()).enumerate() {
+        let Some(value) = parser::value::single_value(line) else {
+            unreachable!("could not parse line {}", number + 1);
+        };
+
+        let json = serde_json::to_string(&value).expect("value should serialize");
+        let parsed: Value = serde_json::from_str(&json).expect("json should deserialize");
+
+        assert_eq!(value, parsed, "round trip failed on line {}", number + 1);
+    }
+}
+
+#[test]
+fn test_json_mapping() {
+    let Some(value) =
+        parser::value::single_value(r#"{"foo bar": [1, 2.5, null, true, "x"], t: Integer}"#)
+    else {
+        unreachable!("Parse error");
+    };
+
+    assert_eq!(
+        serde_json::to_string(&value).ok(),
+        Some(r#"{"foo bar":[1,2.5,null,true,"x"],"t":{"$type":"Integer"}}"#.to_string())
+    );
+
+    let Ok(Value::Object(o)) = serde_json::from_str::<Value>(r#"{"$type": "Unknown"}"#) else {
+        unreachable!("unknown type names stay objects");
+    };
+    assert_eq!(o.len(), 1);
+
+    assert_eq!(
+        serde_json::from_str::<Value>(r#"{"$type": "Float"}"#).ok(),
+        Some(Value::Type(ValueType::Float))
+    );
+
+    assert!(serde_json::from_str::<Value>("18446744073709551615").is_err());
+}
+
+#[test]
+fn test_lambda_serialization_fails() {
+    let Some(set) = parser::expression::expression_many1_all_consuming("fn x => x") else {
+        unreachable!("Parse error");
+    };
+    let Ok(lambda) =
+        damasc_lang::runtime::evaluation::Evaluation::default().eval_expr(&set.expressions[0])
+    else {
+        unreachable!("Eval error");
+    };
+
+    assert!(serde_json::to_string(&lambda).is_err());
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+enum Role {
+    Admin,
+    Guest(String),
+    Member { since: u32 },
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct User {
+    name: String,
+    age: u8,
+    score: f64,
+    tags: Vec<String>,
+    manager: Option<Box<User>>,
+    roles: Vec<Role>,
+}
+
+#[test]
+fn test_value_serializer_round_trip() {
+    let user = User {
+        name: "Alice".to_string(),
+        age: 42,
+        score: 0.5,
+        tags: vec!["a".to_string(), "b".to_string()],
+        manager: None,
+        roles: vec![
+            Role::Admin,
+            Role::Guest("x".to_string()),
+            Role::Member { since: 2020 },
+        ],
+    };
+
+    let Ok(value) = to_value(&user) else {
+        unreachable!("Serialization error");
+    };
+
+    let Some(expected) = parser::value::single_value(
+        r#"{name: "Alice", age: 42, score: 0.5, tags: ["a", "b"], manager: null, roles: ["Admin", {Guest: "x"}, {Member: {since: 2020}}]}"#,
+    ) else {
+        unreachable!("Parse error");
+    };
+    assert_eq!(value, expected);
+
+    assert_eq!(from_value::<User>(&value).ok(), Some(user));
+
+    let Some(types) = parser::value::single_value("[Integer, {x: String}]") else {
+        unreachable!("Parse error");
+    };
+    assert_eq!(to_value(&types).ok(), Some(types));
+}
+
+#[test]
+fn test_value_serializer_environment() {
+    let user = User {
+        name: "Bob".to_string(),
+        age: 30,
+        score: 1.0,
+        tags: vec![],
+        manager: None,
+        roles: vec![],
+    };
+
+    let mut env = Environment::default();
+    let Ok(value) = to_value(&user) else {
+        unreachable!("Serialization error");
+    };
+    env.bindings.insert(Identifier::new("user"), value);
+
+    let Some(set) = parser::expression::expression_many1_all_consuming(
+        "{...user, age: user.age + 1, tags: [\"new\"], roles: [\"Admin\"]}",
+    ) else {
+        unreachable!("Parse error");
+    };
+    let Ok(result) = Evaluation::new(&env).eval_expr(&set.expressions[0]) else {
+        unreachable!("Eval error");
+    };
+
+    assert_eq!(
+        from_value::<User>(&result).ok(),
+        Some(User {
+            age: 31,
+            tags: vec!["new".to_string()],
+            roles: vec![Role::Admin],
+            ..user
+        })
+    );
+
+    assert!(from_value::<u8>(&Value::Integer(300)).is_err());
+    assert!(from_value::<User>(&Value::Null).is_err());
+}
@@ -0,0 +1,95 @@
+#![feature(iter_array_chunks)]
+
+use damasc_lang::{analysis::typing::TypeChecker, parser, runtime::env::Environment};
+
+#[test]
+fn test_typing_of_evaluating_expressions() {
+    let lines = include_str!("./examples_expression_pairs.txt")
+        .lines()
+        .enumerate();
+    let env = Environment::default();
+    let checker = TypeChecker::new(&env);
+
+    for [(line_a, a), (line_b, b), _] in lines.array_chunks() {
+        for (line, source) in [(line_a, a), (line_b, b)] {
+            let Some(set) = parser::expression::expression_many1_all_consuming(source) else {
+                unreachable!("Parse error");
+            };
+
+            for expression in &set.expressions {
+                let result = checker.check_expression(expression);
+                assert!(
+                    result.is_ok(),
+                    "Unexpected type error on line {}: {} {:?}",
+                    line + 1,
+                    source,
+                    result
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_typing_of_matching_assignments() {
+    let lines = include_str!("./examples_assignments.txt").lines();
+    let env = Environment::default();
+    let checker = TypeChecker::new(&env);
+
+    for (line_number, line) in lines.enumerate() {
+        let Some(assignments) = parser::assignment::assignment_set1_all_consuming(line) else {
+            unreachable!("Parse error");
+        };
+
+        let result = checker.check_assignment_set(&assignments);
+        assert!(
+            result.is_ok(),
+            "Unexpected type error on line {}: {} {:?}",
+            line_number + 1,
+            line,
+            result
+        );
+    }
+}
+
+#[test]
+fn test_type_inference() {
+    let lines = include_str!("./examples_type_pairs.txt")
+        .lines()
+        .enumerate();
+    let env = Environment::default();
+    let checker = TypeChecker::new(&env);
+
+    for [(line_number, source), (_, expected), (_, sep)] in lines.array_chunks() {
+        assert_eq!(sep, "---");
+        let Some(expression) = parser::expression::expression_all_consuming(source) else {
+            unreachable!("Parse error");
+        };
+
+        let Ok(actual) = checker.check_expression(&expression) else {
+            unreachable!("Unexpected type error on line {}", line_number + 1);
+        };
+
+        assert_eq!(actual.to_string(), expected, "on line {}", line_number + 1);
+    }
+}
+
+#[test]
+fn test_type_errors() {
+    let lines = include_str!("./examples_type_errors.txt").lines();
+    let env = Environment::default();
+    let checker = TypeChecker::new(&env);
+
+    for (line_number, line) in lines.enumerate() {
+        let Some(expression) = parser::expression::expression_all_consuming(line) else {
+            unreachable!("Parse error");
+        };
+
+        assert!(
+            checker.check_expression(&expression).is_err(),
+            "Missing type error on line {}: {}",
+            line_number + 1,
+            line
+        );
+    }
+}
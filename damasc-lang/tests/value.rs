@@ -1,6 +1,8 @@
 #![feature(iter_array_chunks)]
 
-use damasc_lang::parser;
+use damasc_lang::{
+    parser, runtime::evaluation::Evaluation, value::owned::OwnedValue, value::Value,
+};
 
 #[test]
 fn test_value_parsing() {
@@ -14,3 +16,44 @@ fn test_value_parsing() {
         );
     }
 }
+
+#[test]
+fn test_owned_value_round_trip() {
+    let lines = include_str!("./examples_values.txt").lines();
+
+    for (number, line) in lines.filter(|l| !l.is_empty()).enumerate() {
+        let Some(value) = parser::value::single_value(line) else {
+            unreachable!("could not parse line {}", number + 1);
+        };
+
+        let owned = OwnedValue::from(&value);
+
+        assert_eq!(owned.get_type(), value.get_type());
+        assert_eq!(
+            Value::from(&owned),
+            value,
+            "round trip failed on line {}",
+            number + 1
+        );
+    }
+}
+
+#[test]
+fn test_owned_value_across_threads() {
+    let Some(set) = parser::expression::expression_many1_all_consuming(
+        "[1, {x: \"y\"}, fn f(n) => if n > 0 { f.(n - 1) } else { n }]",
+    ) else {
+        unreachable!("Parse error");
+    };
+    let Ok(value) = Evaluation::default().eval_expr(&set.expressions[0]) else {
+        unreachable!("Eval error");
+    };
+
+    let owned = OwnedValue::from(&value);
+    let shared = owned.clone();
+
+    let handle = std::thread::spawn(move || shared.to_string());
+
+    assert_eq!(handle.join().ok(), Some(owned.to_string()));
+    assert_eq!(Value::from(&owned), value);
+}
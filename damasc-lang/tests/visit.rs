@@ -0,0 +1,171 @@
+use std::borrow::Cow;
+
+use damasc_lang::{
+    identifier::Identifier,
+    literal::Literal,
+    parser,
+    runtime::evaluation::Evaluation,
+    syntax::{
+        expression::{BinaryExpression, BinaryOperator, Expression, ExpressionBody},
+        visit::{fold_expression, Fold, Visitor, VisitorMut},
+    },
+};
+
+#[derive(Default)]
+struct Names {
+    read: Vec<String>,
+    bound: Vec<String>,
+    literals: usize,
+}
+
+impl<'a> Visitor<'a> for Names {
+    fn visit_identifier(&mut self, identifier: &'a Identifier<'a>) {
+        self.read.push(identifier.name.to_string());
+    }
+
+    fn visit_binding(&mut self, identifier: &'a Identifier<'a>) {
+        self.bound.push(identifier.name.to_string());
+    }
+
+    fn visit_literal(&mut self, _literal: &'a Literal<'a>) {
+        self.literals += 1;
+    }
+}
+
+#[test]
+fn test_visitor() {
+    let Some(expression) = parser::expression::expression_all_consuming(
+        "let [a, ...r] = xs; {b = c, [k]: 5 | 6} = y in match (a) { z if z > 1 => [b, ...r], _ => fn f(w) => w }",
+    ) else {
+        unreachable!("Parse error");
+    };
+
+    let mut names = Names::default();
+    names.visit_expression(&expression);
+
+    assert_eq!(names.read, ["xs", "c", "k", "y", "a", "z", "b", "r", "w"]);
+    assert_eq!(names.bound, ["a", "r", "b", "z", "f", "w"]);
+    assert_eq!(names.literals, 3);
+}
+
+struct Rename<'n> {
+    from: &'n str,
+    to: &'n str,
+}
+
+impl<'s> VisitorMut<'s> for Rename<'_> {
+    fn visit_identifier_mut(&mut self, identifier: &mut Identifier<'s>) {
+        if identifier.name == self.from {
+            identifier.name = Cow::Owned(self.to.to_string());
+        }
+    }
+
+    fn visit_binding_mut(&mut self, identifier: &mut Identifier<'s>) {
+        self.visit_identifier_mut(identifier)
+    }
+}
+
+#[test]
+fn test_visitor_mut() {
+    let pairs = [
+        ("x + 1", "y + 1"),
+        ("[x for x in [x, z]]", "[y for y in [y, z]]"),
+        (
+            "let [x, [^x]] = [1, [x]] in x",
+            "let [y, [^y]] = [1, [y]] in y",
+        ),
+        (
+            "match (x) { x @ [_] => x, _ => z }",
+            "match (y) { y @ [_] => y, _ => z }",
+        ),
+    ];
+
+    for (source, expected) in pairs {
+        let (Some(mut actual), Some(expected)) = (
+            parser::expression::expression_all_consuming(source),
+            parser::expression::expression_all_consuming(expected),
+        ) else {
+            unreachable!("Parse error");
+        };
+
+        Rename { from: "x", to: "y" }.visit_expression_mut(&mut actual);
+        assert_eq!(actual, expected, "for {source}");
+    }
+}
+
+/// Replaces additions of integer literals by their sum.
+struct AddLiterals;
+
+impl<'s> Fold<'s> for AddLiterals {
+    fn fold_expression(&mut self, expression: Expression<'s>) -> Expression<'s> {
+        let expression = fold_expression(self, expression);
+        let ExpressionBody::Binary(BinaryExpression {
+            operator: BinaryOperator::Plus,
+            left,
+            right,
+        }) = &expression.body
+        else {
+            return expression;
+        };
+        let (
+            ExpressionBody::Literal(Literal::Number(l)),
+            ExpressionBody::Literal(Literal::Number(r)),
+        ) = (&left.body, &right.body)
+        else {
+            return expression;
+        };
+        let (Ok(l), Ok(r)) = (l.parse::<i64>(), r.parse::<i64>()) else {
+            return expression;
+        };
+        let Some(sum) = l.checked_add(r) else {
+            return expression;
+        };
+
+        Expression::new_with_optional_location(
+            ExpressionBody::Literal(Literal::Number(Cow::Owned(sum.to_string()))),
+            expression.location,
+        )
+    }
+}
+
+#[test]
+fn test_fold() {
+    let Some(folded) = parser::expression::expression_all_consuming("[1 + 2 + x, fn (y) => 3 + 4]")
+        .map(|e| AddLiterals.fold_expression(e))
+    else {
+        unreachable!("Parse error");
+    };
+    let Some(expected) = parser::expression::expression_all_consuming("[3 + x, fn (y) => 7]")
+    else {
+        unreachable!("Parse error");
+    };
+
+    assert_eq!(folded, expected);
+}
+
+#[test]
+fn test_fold_preserves_evaluation() {
+    let lines = include_str!("./examples_expression_pairs.txt")
+        .lines()
+        .enumerate()
+        .filter(|(_, l)| l != &"---");
+
+    for (line_number, line) in lines {
+        let Some(set) = parser::expression::expression_many1_all_consuming(line) else {
+            unreachable!("Parse error");
+        };
+
+        for expression in set.expressions {
+            let eval = Evaluation::default();
+            let expected = eval.eval_expr(&expression).ok();
+            let folded = AddLiterals.fold_expression(expression);
+
+            assert_eq!(
+                eval.eval_expr(&folded).ok(),
+                expected,
+                "on line {}",
+                line_number + 1
+            );
+        }
+    }
+}
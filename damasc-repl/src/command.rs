@@ -12,4 +12,6 @@ pub enum Command<'a, 'b> {
     Assign(AssignmentSet<'a, 'b>, Option<AssignmentSet<'a, 'b>>),
     Match(AssignmentSet<'a, 'b>),
     Eval(AssignmentSet<'a, 'b>, ExpressionSet<'a>),
+    Check(AssignmentSet<'a, 'b>, ExpressionSet<'a>),
+    Lint(AssignmentSet<'a, 'b>, ExpressionSet<'a>),
 }
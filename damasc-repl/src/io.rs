@@ -1,6 +1,11 @@
+use damasc_lang::analysis::exhaustiveness::MatchWarning;
+use damasc_lang::analysis::lint::LintWarning;
+use damasc_lang::analysis::types::Type;
+use damasc_lang::analysis::typing::{TypeBindings, TypeError};
 use damasc_lang::identifier::Identifier;
 use damasc_lang::runtime::evaluation::EvalError;
 use damasc_lang::runtime::matching::PatternFail;
+use damasc_lang::syntax::location::Location;
 use damasc_lang::{runtime::env::Environment, value::ValueBag};
 use nom::lib::std::collections::HashSet;
 
@@ -10,6 +15,8 @@ pub enum ReplOutput<'i, 's> {
     Write(String),
     Values(ValueBag<'s, 's>),
     Bindings(Environment<'i, 's, 's>),
+    Types(TypeBindings<'s>, Vec<Type>, Vec<MatchWarning<'s>>),
+    Lints(Vec<LintWarning<'s>>),
     Exit,
 }
 
@@ -20,16 +27,66 @@ impl std::fmt::Display for ReplOutput<'_, '_> {
             ReplOutput::Write(msg) => writeln!(f, "{msg}"),
             ReplOutput::Values(vals) => writeln!(f, "{vals}"),
             ReplOutput::Bindings(env) => writeln!(f, "{env}"),
+            ReplOutput::Types(bindings, types, _) => {
+                for (id, t) in bindings {
+                    writeln!(f, "{id}: {t};")?;
+                }
+                for t in types {
+                    writeln!(f, "{t};")?;
+                }
+                Ok(())
+            }
+            ReplOutput::Lints(warnings) if warnings.is_empty() => writeln!(f, "No warnings."),
+            ReplOutput::Lints(warnings) => {
+                for warning in warnings {
+                    write_warning(f, &warning.reason, warning.location)?;
+                }
+                Ok(())
+            }
             ReplOutput::Exit => Ok(()),
         }
     }
 }
 
+impl<'i, 's> ReplOutput<'i, 's> {
+    /// The match warnings of a type check. They are not part of the
+    /// `Display` of the output, so each frontend can report them its own way.
+    pub fn match_warnings(&self) -> MatchWarnings<'_, 's> {
+        match self {
+            ReplOutput::Types(_, _, warnings) => MatchWarnings(warnings),
+            _ => MatchWarnings(&[]),
+        }
+    }
+}
+
+pub struct MatchWarnings<'a, 's>(pub &'a [MatchWarning<'s>]);
+
+impl std::fmt::Display for MatchWarnings<'_, '_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for warning in self.0 {
+            write_warning(f, &warning.reason, warning.location)?;
+        }
+        Ok(())
+    }
+}
+
+fn write_warning(
+    f: &mut std::fmt::Formatter<'_>,
+    reason: &dyn std::fmt::Display,
+    location: Option<Location>,
+) -> std::fmt::Result {
+    match location {
+        Some(Location { start, end }) => writeln!(f, "Warning at {start}..{end}: {reason}"),
+        None => writeln!(f, "Warning: {reason}"),
+    }
+}
+
 #[derive(Debug)]
 pub enum ReplError<'s, 'v> {
     ParseError,
     EvalError(EvalError<'s, 'v>),
     MatchError(PatternFail<'s, 'v>),
     TopologyError(HashSet<Identifier<'s>>),
+    TypeErrors(Vec<TypeError<'s>>),
     TransformError,
 }
@@ -5,10 +5,10 @@ use damasc_lang::{
         io::{ParserError, ParserInput, ParserResult},
         util::ws,
     },
-    syntax::assignment::AssignmentSet,
+    syntax::{assignment::AssignmentSet, expression::ExpressionSet},
 };
 use damasc_query::parser::transformation;
-use nom::combinator::opt;
+use nom::combinator::{opt, peek};
 use nom::sequence::tuple;
 use nom::{
     branch::alt,
@@ -48,11 +48,75 @@ pub(crate) fn command<'a, 'b, 's, E: ParserError<'s>>(
                 Command::ClearEnv,
                 all_consuming(context("cmd_clearenv", alt((tag(".clearenv"), tag(".ce"))))),
             ),
+            context(
+                "cmd_check",
+                preceded(
+                    ws(tag(".check")),
+                    cut(alt((
+                        map(all_consuming(assignment_set1), |assignments| {
+                            Command::Check(
+                                assignments,
+                                ExpressionSet {
+                                    expressions: vec![],
+                                },
+                            )
+                        }),
+                        map(
+                            all_consuming(pair(
+                                expression_many1,
+                                opt(preceded(ws(tag("with ")), assignment_set1)),
+                            )),
+                            |(expressions, assignments)| {
+                                Command::Check(assignments.unwrap_or_default(), expressions)
+                            },
+                        ),
+                    ))),
+                ),
+            ),
+            context(
+                "cmd_lint",
+                preceded(
+                    ws(tag(".lint")),
+                    cut(alt((
+                        map(all_consuming(assignment_set1), |assignments| {
+                            Command::Lint(
+                                assignments,
+                                ExpressionSet {
+                                    expressions: vec![],
+                                },
+                            )
+                        }),
+                        map(
+                            all_consuming(pair(
+                                expression_many1,
+                                opt(preceded(ws(tag("with ")), assignment_set1)),
+                            )),
+                            |(expressions, assignments)| {
+                                Command::Lint(assignments.unwrap_or_default(), expressions)
+                            },
+                        ),
+                    ))),
+                ),
+            ),
             cut(alt((
                 context(
                     "cmd_transform",
                     map(all_consuming(transformation), Command::Transform),
                 ),
+                // `let x = a in b` is a let expression rather than an assignment
+                // of `a in b`, which has to be written as `let x = (a in b)`.
+                context(
+                    "let_expression",
+                    map(
+                        all_consuming(pair(
+                            preceded(peek(ws(tag("let "))), expression_many1),
+                            opt(preceded(ws(tag("with ")), assignment_set1)),
+                        )),
+                        |(expressions, assignments)| {
+                            Command::Eval(assignments.unwrap_or_default(), expressions)
+                        },
+                    ),
+                ),
                 context(
                     "cmd_let",
                     map(
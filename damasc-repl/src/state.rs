@@ -1,5 +1,8 @@
+use damasc_lang::analysis::lint::Linter;
+use damasc_lang::analysis::typing::TypeChecker;
 use damasc_lang::runtime::assignment::AssignmentError;
 use damasc_lang::runtime::assignment::AssignmentEvaluation;
+use damasc_lang::runtime::budget::{EvalBudget, EvalLimits};
 use damasc_query::predicate::PredicateError;
 use damasc_query::projection::ProjectionError;
 use itertools::Itertools;
@@ -19,6 +22,7 @@ use crate::io::{ReplError, ReplOutput};
 #[derive(Default)]
 pub struct State<'i: 's, 's> {
     environment: Environment<'i, 's, 's>,
+    limits: EvalLimits,
 }
 
 impl<'i, 's> State<'i, 's> {
@@ -26,6 +30,10 @@ impl<'i, 's> State<'i, 's> {
         Self::default()
     }
 
+    pub fn with_limits(self, limits: EvalLimits) -> Self {
+        Self { limits, ..self }
+    }
+
     pub fn vars<'x>(&'x self) -> BTreeSet<&'x Identifier<'i>> {
         self.environment.bindings.keys().collect()
     }
@@ -34,6 +42,8 @@ impl<'i, 's> State<'i, 's> {
         &mut self,
         command: Command<'s, 's>,
     ) -> Result<ReplOutput<'i, 's>, ReplError<'s, 's>> {
+        let budget = EvalBudget::new(self.limits);
+
         match command {
             Command::Exit => Ok(ReplOutput::Exit),
             Command::Help => Ok(ReplOutput::Ok),
@@ -44,7 +54,7 @@ impl<'i, 's> State<'i, 's> {
                 Ok(ReplOutput::Ok)
             }
             Command::Transform(transformation) => {
-                let evaluation = Evaluation::new(&self.environment);
+                let evaluation = Evaluation::new(&self.environment).with_budget(Some(&budget));
                 let iter = transformation
                     .bag
                     .expressions
@@ -72,7 +82,8 @@ impl<'i, 's> State<'i, 's> {
             }
             Command::Assign(assignments, locals) => {
                 let local_env = if let Some(loc) = locals {
-                    let assign_eval = AssignmentEvaluation::new(&self.environment);
+                    let assign_eval =
+                        AssignmentEvaluation::new(&self.environment).with_budget(Some(&budget));
                     match assign_eval.eval_assigment_set(loc) {
                         Ok(mut new_bindings) => {
                             let mut local_env = self.environment.clone();
@@ -91,7 +102,7 @@ impl<'i, 's> State<'i, 's> {
                     self.environment.clone()
                 };
 
-                let assign_eval = AssignmentEvaluation::new(&local_env);
+                let assign_eval = AssignmentEvaluation::new(&local_env).with_budget(Some(&budget));
                 match assign_eval.eval_assigment_set(assignments) {
                     Ok(new_bindings) => {
                         self.environment
@@ -105,7 +116,8 @@ impl<'i, 's> State<'i, 's> {
                 }
             }
             Command::Match(assignments) => {
-                let assign_eval = AssignmentEvaluation::new(&self.environment);
+                let assign_eval =
+                    AssignmentEvaluation::new(&self.environment).with_budget(Some(&budget));
                 match assign_eval.eval_assigment_set(assignments) {
                     Ok(new_bindings) => Ok(ReplOutput::Bindings(new_bindings)),
                     Err(AssignmentError::EvalError(e)) => Err(ReplError::EvalError(e)),
@@ -114,7 +126,8 @@ impl<'i, 's> State<'i, 's> {
                 }
             }
             Command::Eval(assignments, expresions) => {
-                let assign_eval = AssignmentEvaluation::new(&self.environment);
+                let assign_eval =
+                    AssignmentEvaluation::new(&self.environment).with_budget(Some(&budget));
                 let mut new_bindings = match assign_eval.eval_assigment_set(assignments) {
                     Ok(new_env) => new_env,
                     Err(AssignmentError::EvalError(e)) => return Err(ReplError::EvalError(e)),
@@ -126,7 +139,7 @@ impl<'i, 's> State<'i, 's> {
                 let mut local_env = self.environment.clone();
                 local_env.bindings.append(&mut new_bindings.bindings);
 
-                let evaluation = Evaluation::new(&local_env);
+                let evaluation = Evaluation::new(&local_env).with_budget(Some(&budget));
 
                 let values = expresions
                     .expressions
@@ -138,6 +151,33 @@ impl<'i, 's> State<'i, 's> {
                     Err(e) => Err(ReplError::EvalError(e)),
                 }
             }
+            Command::Check(assignments, expressions) => {
+                let checker = TypeChecker::new(&self.environment);
+                let bindings = checker
+                    .check_assignment_set(&assignments)
+                    .map_err(ReplError::TypeErrors)?;
+                let mut warnings = checker.check_assignment_matches(&assignments);
+                let checker = checker.with_bindings(bindings.clone());
+
+                let mut types = Vec::new();
+                let mut errors = Vec::new();
+                for expression in &expressions.expressions {
+                    match checker.check_expression(expression) {
+                        Ok(t) => types.push(t),
+                        Err(mut e) => errors.append(&mut e),
+                    }
+                    warnings.append(&mut checker.check_matches(expression));
+                }
+
+                if errors.is_empty() {
+                    Ok(ReplOutput::Types(bindings, types, warnings))
+                } else {
+                    Err(ReplError::TypeErrors(errors))
+                }
+            }
+            Command::Lint(assignments, expressions) => Ok(ReplOutput::Lints(
+                Linter::new(&self.environment).lint(&assignments, &expressions.expressions),
+            )),
         }
     }
 }
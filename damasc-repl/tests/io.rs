@@ -0,0 +1,25 @@
+use damasc_lang::{
+    analysis::lint::{LintWarning, LintWarningReason},
+    identifier::Identifier,
+    syntax::location::Location,
+};
+use damasc_repl::io::ReplOutput;
+
+#[test]
+fn test_lint_warnings_show_location() {
+    let output = ReplOutput::Lints(vec![
+        LintWarning {
+            reason: LintWarningReason::UnusedBinding(Identifier::new("x")),
+            location: Some(Location::new(4, 5)),
+        },
+        LintWarning {
+            reason: LintWarningReason::Shadowing(Identifier::new("y")),
+            location: None,
+        },
+    ]);
+
+    assert_eq!(
+        output.to_string(),
+        "Warning at 4..5: x is bound but never used.\nWarning: y shadows a binding of an enclosing scope.\n"
+    );
+}
@@ -0,0 +1,45 @@
+use damasc_repl::{command::Command, parser::command_all_consuming};
+
+#[test]
+fn test_let_command() {
+    assert!(matches!(
+        command_all_consuming("let x = 1"),
+        Ok(Command::Assign(_, None))
+    ));
+    assert!(matches!(
+        command_all_consuming("let x = 1 with y = 2"),
+        Ok(Command::Assign(_, Some(_)))
+    ));
+}
+
+#[test]
+fn test_let_expression() {
+    let Ok(Command::Eval(assignments, expressions)) = command_all_consuming("let x = 1 in x")
+    else {
+        unreachable!("Expected an expression");
+    };
+
+    assert!(assignments.assignments.is_empty());
+    assert_eq!(expressions.expressions.len(), 1);
+}
+
+#[test]
+fn test_let_membership_needs_parentheses() {
+    assert!(matches!(
+        command_all_consuming("let x = a in b"),
+        Ok(Command::Eval(_, _))
+    ));
+    assert!(matches!(
+        command_all_consuming("let x = (a in b)"),
+        Ok(Command::Assign(_, None))
+    ));
+}
+
+#[test]
+fn test_errors_name_the_failing_parser() {
+    let Err(error) = command_all_consuming("match [1,2] { [x] | [_, y] => 0, _ => 1 }") else {
+        unreachable!("Expected a parse error");
+    };
+
+    assert!(!error.contains("cmd_let"), "{error}");
+}
@@ -1,7 +1,6 @@
 use ariadne::Config;
 use ariadne::ReportBuilder;
 use ariadne::{Label, Report, ReportKind, Source};
-use damasc_lang::runtime::evaluation::EvalErrorReason;
 use damasc_lang::runtime::matching::PatternFailReason;
 use damasc_repl::io::ReplError;
 use std::io::Write;
@@ -21,52 +20,7 @@ pub(crate) fn print_error(input: &str, e: &ReplError, out_buffer: &mut Vec<u8>)
                 .with_code("Evaluation")
                 .with_config(Config::default().with_color(false));
 
-            let builder = builder.with_message(match &eval_error.reason {
-                EvalErrorReason::KindError(actual) => {
-                    format!("Expected a type, but found {}.", actual)
-                }
-                EvalErrorReason::TypeError(expected_type, value) => format!(
-                    "Expected a value of type {} but found {} of type {}.",
-                    expected_type,
-                    value,
-                    value.get_type()
-                ),
-                EvalErrorReason::CollectionTypeError(val) => format!(
-                    "Value must be an Array, Object or String, but was {} of type {}.",
-                    val,
-                    val.get_type()
-                ),
-                EvalErrorReason::CastError(expected_type, val) => format!(
-                    "Value {} of type {} can not be converted into {}..",
-                    val,
-                    val.get_type(),
-                    expected_type
-                ),
-                EvalErrorReason::UnknownIdentifier(identifier) => {
-                    format!("Unknown identifier {}.", identifier)
-                }
-                EvalErrorReason::InvalidNumber(lit) => {
-                    format!("Literal {} is not a valid number.", lit)
-                }
-                EvalErrorReason::MathDivisionByZero => "Division By Zero".to_string(),
-                EvalErrorReason::KeyNotDefined(key, val) => {
-                    format!("Object {} has no key {}.", val, key)
-                }
-                EvalErrorReason::OutOfBound(expected_lengnth, actual_length) => format!(
-                    "Tried to access index {} of value that has a length of {}.",
-                    actual_length, expected_lengnth,
-                ),
-                EvalErrorReason::IntegerOverflow => "Integer overflow".to_string(),
-                EvalErrorReason::UnknownFunction(fun) => {
-                    format!("Function of name {} does not exist.", fun)
-                }
-                EvalErrorReason::PatternError(_e) => {
-                    "A pattern failed to match during evaluation.".to_string()
-                }
-                EvalErrorReason::PatternExhaustionError(val) => {
-                    format!("None of the provided cases was a match for value {}.", val)
-                }
-            });
+            let builder = builder.with_message(eval_error.reason.to_string());
 
             let builder = builder.with_label(
                 Label::new(("REPL", source_location.start..(source_location.end)))
@@ -98,6 +52,11 @@ pub(crate) fn print_error(input: &str, e: &ReplError, out_buffer: &mut Vec<u8>)
                 PatternFailReason::LiteralMismatch => "The value does not match the expected literal.".to_string(),
                 PatternFailReason::ExpressionMissmatch { expected, actual } => format!("The value is expected to be {} but actually was {}.", expected, actual),
                 PatternFailReason::EvalError(_eval_error) => "During the pattern matching an evaulation error occured.".to_string(),
+                PatternFailReason::AlternativesMismatch(failures) => format!("The value matches none of the {} alternatives.", failures.len()),
+                PatternFailReason::OutOfRange { actual } => format!("The value {} is not within the expected range.", actual),
+                PatternFailReason::RegexMismatch { actual } => format!("The value {} does not match the regular expression.", actual),
+                PatternFailReason::PrefixMismatch { expected, actual } => format!("The value {} does not start with \"{}\".", actual, expected),
+                PatternFailReason::SuffixMismatch { expected, actual } => format!("The value {} does not end with \"{}\".", actual, expected),
             });
 
             let builder = builder.with_label(
@@ -131,6 +90,29 @@ pub(crate) fn print_error(input: &str, e: &ReplError, out_buffer: &mut Vec<u8>)
                 .write(("REPL", Source::from(input)), out_buffer)
                 .is_ok()
         }
+        ReplError::TypeErrors(type_errors) => type_errors.iter().all(|type_error| {
+            let Some(source_location) = type_error.location else {
+                return writeln!(out_buffer, "Type Error: {}", type_error.reason).is_ok();
+            };
+
+            let builder = Report::build(ReportKind::Error, "REPL", source_location.start);
+
+            let builder = builder
+                .with_code("Typing")
+                .with_config(Config::default().with_color(false));
+
+            let builder = builder.with_message(type_error.reason.to_string());
+
+            let builder = builder.with_label(
+                Label::new(("REPL", source_location.start..(source_location.end)))
+                    .with_message("This is ill-typed."),
+            );
+
+            builder
+                .finish()
+                .write(("REPL", Source::from(input)), &mut *out_buffer)
+                .is_ok()
+        }),
         ReplError::TransformError => write!(out_buffer, "Error During Transformation").is_ok(),
     }
 }
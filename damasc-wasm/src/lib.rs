@@ -8,11 +8,14 @@ use ariadne::ReportKind;
 use ariadne::Source;
 use chumsky::Parser;
 use damasc_grammar::repl::single_command;
+use damasc_lang::runtime::budget::EvalLimits;
 use damasc_repl::state::State;
 use wasm_bindgen::prelude::*;
 
 mod error;
 
+const LIMITS: EvalLimits = EvalLimits::new(100_000, 100, 10_000);
+
 #[wasm_bindgen(module = "/js/damasc.js")]
 extern "C" {
     fn show_error(stmt: &str, error: &str);
@@ -27,7 +30,7 @@ pub struct WasmRepl {
 impl Default for WasmRepl {
     fn default() -> Self {
         Self {
-            state: Box::new(State::new()),
+            state: Box::new(State::new().with_limits(LIMITS)),
         }
     }
 }
@@ -37,7 +40,7 @@ impl WasmRepl {
     #[wasm_bindgen(constructor)]
     pub fn new() -> Self {
         Self {
-            state: Box::new(State::new()),
+            state: Box::new(State::new().with_limits(LIMITS)),
         }
     }
 
@@ -76,7 +79,7 @@ impl WasmRepl {
 
         let mut out_buffer = Vec::new();
         match self.state.eval(cmd) {
-            Ok(r) => show_result(input, &format!("{r}")),
+            Ok(r) => show_result(input, &format!("{r}{}", r.match_warnings())),
             Err(eval_err) => {
                 print_error(input, &eval_err, &mut out_buffer);
                 match std::str::from_utf8(&out_buffer) {